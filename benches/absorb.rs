@@ -0,0 +1,64 @@
+//! Cost of absorbing a message, against that of the permutation alone
+//!
+//! Every block of 168 bytes costs one permutation, so absorbing runs close to
+//! `168 B / permutation time` when XORing the bytes into the lanes is cheap next to it.
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use TD1::{
+    convert::{bytes_to_chunks, iter_chunks, xor_block},
+    keccak,
+    sponge::{Shake128, RATE_BYTES},
+    STATE_SIZE,
+};
+
+const MESSAGE_BYTES: usize = 64 * 1024;
+
+fn absorb(c: &mut Criterion) {
+    let message = vec![0xA3; MESSAGE_BYTES];
+    let mut group = c.benchmark_group("absorb");
+
+    group.throughput(Throughput::Bytes(RATE_BYTES as u64));
+    group.bench_function("permutation", |b| {
+        let mut state = [0u64; STATE_SIZE];
+        b.iter(|| state = keccak::shake128(black_box(state)))
+    });
+    group.bench_function("xor_block", |b| {
+        let mut state = [0u64; STATE_SIZE];
+        b.iter(|| xor_block(&mut state, black_box(&message[..RATE_BYTES])))
+    });
+    group.bench_function("xor_block_and_permutation", |b| {
+        let mut state = [0u64; STATE_SIZE];
+        b.iter(|| {
+            xor_block(&mut state, black_box(&message[..RATE_BYTES]));
+            state = keccak::shake128(state);
+        })
+    });
+
+    group.throughput(Throughput::Bytes(MESSAGE_BYTES as u64));
+    group.bench_with_input(
+        BenchmarkId::new("update", MESSAGE_BYTES),
+        &message,
+        |b, message| {
+            b.iter(|| {
+                let mut hasher = Shake128::new();
+                hasher.update(black_box(message));
+                hasher
+            })
+        },
+    );
+    group.bench_with_input(
+        BenchmarkId::new("iter_chunks", MESSAGE_BYTES),
+        &message,
+        |b, message| b.iter(|| iter_chunks(black_box(message)).count()),
+    );
+    group.bench_with_input(
+        BenchmarkId::new("bytes_to_chunks", MESSAGE_BYTES),
+        &message,
+        |b, message| b.iter(|| bytes_to_chunks(black_box(message))),
+    );
+    group.finish();
+}
+
+criterion_group!(benches, absorb);
+criterion_main!(benches);
@@ -0,0 +1,50 @@
+//! Cost of squeezing, and of hashing whole messages from 0 B to 1 GiB
+//!
+//! Messages larger than 1 MiB are absorbed as the same 1 MiB buffer again and again, so that
+//! hashing 1 GiB does not need as much memory.
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use TD1::sponge::Shake128;
+
+const BUFFER_BYTES: usize = 1 << 20;
+const SQUEEZED_BYTES: usize = 64 * 1024;
+
+fn squeeze(c: &mut Criterion) {
+    let mut group = c.benchmark_group("squeeze");
+    group.throughput(Throughput::Bytes(SQUEEZED_BYTES as u64));
+    group.bench_function(BenchmarkId::new("squeeze", SQUEEZED_BYTES), |b| {
+        let mut out = vec![0; SQUEEZED_BYTES];
+        b.iter(|| Shake128::new().finalize().squeeze(black_box(&mut out)))
+    });
+    group.finish();
+}
+
+fn hash(c: &mut Criterion) {
+    let buffer = (0..BUFFER_BYTES)
+        .map(|i| (i % 251) as u8)
+        .collect::<Vec<_>>();
+    let mut group = c.benchmark_group("hash");
+    for size in [0, 64, 1 << 10, 64 << 10, 1 << 20, 64 << 20, 1 << 30] {
+        if size >= 64 << 20 {
+            group.sample_size(10);
+        }
+        group.throughput(Throughput::Bytes(size as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, &size| {
+            b.iter(|| {
+                let mut hasher = Shake128::new();
+                let mut remaining = size;
+                while remaining > 0 {
+                    let length = remaining.min(BUFFER_BYTES);
+                    hasher.update(black_box(&buffer[..length]));
+                    remaining -= length;
+                }
+                hasher.finalize().squeeze_vec(32)
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, squeeze, hash);
+criterion_main!(benches);
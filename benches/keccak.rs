@@ -0,0 +1,32 @@
+//! Cost of the permutation, and of each of its step functions, on `u64` lanes
+//!
+//! One permutation is 24 rounds of θ, ρ, π, χ and ι, so the sum of the steps times 24 is close to
+//! the permutation when the compiler does not fuse them.
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use TD1::{keccak, STATE_SIZE};
+
+fn state() -> [u64; STATE_SIZE] {
+    std::array::from_fn(|i| (i as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15))
+}
+
+fn keccak(c: &mut Criterion) {
+    let input = state();
+    let mut group = c.benchmark_group("keccak");
+    group.bench_function("permutation", |b| {
+        let mut state = state();
+        b.iter(|| state = keccak::shake128(black_box(state)))
+    });
+    group.bench_function("theta", |b| b.iter(|| keccak::theta(black_box(input))));
+    group.bench_function("rho", |b| b.iter(|| keccak::rho(black_box(input))));
+    group.bench_function("pi", |b| b.iter(|| keccak::pi(black_box(input))));
+    group.bench_function("chi", |b| b.iter(|| keccak::chi(black_box(input))));
+    group.bench_function("iota", |b| {
+        b.iter(|| keccak::iota(black_box(input), black_box(0)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, keccak);
+criterion_main!(benches);
@@ -0,0 +1,489 @@
+//! Balloon password hashing over cSHAKE128
+//!
+//! Balloon hashing (Boneh, Corrigan-Gibbs and Schechter, 2016) fills a buffer of blocks from the
+//! password and the salt, then mixes every block with its predecessor and with blocks at
+//! pseudorandom positions, so that computing a hash with less memory than the buffer costs much
+//! more time. Every block is an output of [`cshake128`] customized with `TD1 balloon`, and each
+//! call absorbs a counter first. With a parallelism above 1, independent buffers are filled on as
+//! many threads, and their last blocks are combined.
+//!
+//! Hashes are stored in the PHC string format,
+//! `$balloon-shake128$m=<KiB>,t=<rounds>,p=<threads>$<salt>$<hash>`, with the salt and the hash
+//! in unpadded base64:
+//! ```
+//! use TD1::balloon::{hash_password, needs_rehash, verify_password, Params};
+//!
+//! let params = Params { space_cost: 16, time_cost: 1, parallelism: 1 };
+//! let stored = hash_password(b"correct horse", &params).unwrap();
+//! assert!(stored.starts_with("$balloon-shake128$m=16,t=1,p=1$"));
+//! assert!(verify_password(b"correct horse", &stored).unwrap());
+//! assert!(!verify_password(b"battery staple", &stored).unwrap());
+//! assert!(needs_rehash(&stored, &Params::default()).unwrap());
+//! ```
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{
+    encoding::{base64_unpadded, decode_base64_unpadded},
+    hmac::constant_time_eq,
+    keyed::random_bytes,
+    kmac::{cshake128, encode_string},
+    sponge::Prefix,
+    Error, Result,
+};
+
+/// identifier of the algorithm in PHC strings
+const ALGORITHM: &str = "balloon-shake128";
+/// number of bytes of a block of the buffer, and of the hash
+pub const BLOCK_BYTES: usize = 32;
+/// number of bytes of the salts drawn by [`hash_password`]
+pub const SALT_BYTES: usize = 16;
+/// largest space cost, in KiB (1 GiB per buffer)
+pub const MAX_SPACE_COST: u32 = 1 << 20;
+/// largest time cost, in rounds
+pub const MAX_TIME_COST: u32 = 256;
+/// largest parallelism, in threads
+pub const MAX_PARALLELISM: u32 = 64;
+/// number of pseudorandom blocks mixed into every block at each round (`delta` in the paper)
+const DELTA: u64 = 3;
+
+type Block = [u8; BLOCK_BYTES];
+
+/// cost parameters of a hash
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Params {
+    /// KiB of memory of each buffer
+    pub space_cost: u32,
+    /// number of mixing rounds over each buffer
+    pub time_cost: u32,
+    /// number of buffers, filled in parallel
+    pub parallelism: u32,
+}
+
+/// 1 MiB, 3 rounds and a single thread
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            space_cost: 1024,
+            time_cost: 3,
+            parallelism: 1,
+        }
+    }
+}
+
+impl Params {
+    /// checks that the costs are within bounds, as they may come from an untrusted PHC string
+    fn check(&self) -> Result<()> {
+        let invalid = |message: String| Err(Error::InvalidParameter(message));
+        if !(1..=MAX_SPACE_COST).contains(&self.space_cost) {
+            return invalid(format!(
+                "the space cost must be between 1 and {MAX_SPACE_COST} KiB"
+            ));
+        }
+        if !(1..=MAX_TIME_COST).contains(&self.time_cost) {
+            return invalid(format!(
+                "the time cost must be between 1 and {MAX_TIME_COST} rounds"
+            ));
+        }
+        if !(1..=MAX_PARALLELISM).contains(&self.parallelism) {
+            return invalid(format!(
+                "the parallelism must be between 1 and {MAX_PARALLELISM} threads"
+            ));
+        }
+        Ok(())
+    }
+
+    /// number of blocks of each buffer
+    fn blocks(&self) -> usize {
+        self.space_cost as usize * 1024 / BLOCK_BYTES
+    }
+}
+
+/// written as in PHC strings: `m=1024,t=3,p=1`
+impl fmt::Display for Params {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "m={},t={},p={}",
+            self.space_cost, self.time_cost, self.parallelism
+        )
+    }
+}
+
+impl FromStr for Params {
+    type Err = Error;
+
+    /// reads the parameters written by [`Display`](fmt::Display), in the same order
+    fn from_str(text: &str) -> Result<Self> {
+        let invalid = || Error::InvalidParameter(format!("`{text}` is not m=..,t=..,p=.."));
+        let mut values = text
+            .split(',')
+            .zip(["m=", "t=", "p="])
+            .map(|(field, name)| {
+                field
+                    .strip_prefix(name)
+                    .and_then(|value| value.parse::<u32>().ok())
+                    .ok_or_else(invalid)
+            });
+        let params = Params {
+            space_cost: values.next().ok_or_else(invalid)??,
+            time_cost: values.next().ok_or_else(invalid)??,
+            parallelism: values.next().ok_or_else(invalid)??,
+        };
+        if text.split(',').count() != 3 {
+            return Err(invalid());
+        }
+        params.check()?;
+        Ok(params)
+    }
+}
+
+/// the hash function of the blocks, with its counter of calls
+struct Hasher {
+    prefix: Prefix,
+    counter: u64,
+}
+
+impl Hasher {
+    fn new() -> Self {
+        Hasher {
+            prefix: cshake128(b"", b"TD1 balloon").freeze(),
+            counter: 0,
+        }
+    }
+
+    fn hash(&mut self, parts: &[&[u8]]) -> Block {
+        let mut hasher = self.prefix.fork();
+        hasher.update(&self.counter.to_le_bytes());
+        self.counter += 1;
+        for part in parts {
+            hasher.update(part);
+        }
+        hasher.finalize().squeeze_array()
+    }
+}
+
+/// fills and mixes the buffer of the instance `instance`, returning its last block
+fn balloon_instance(password: &[u8], salt: &[u8], params: &Params, instance: u32) -> Result<Block> {
+    let blocks = params.blocks();
+    let salt = encode_string(salt);
+    let mut hasher = Hasher::new();
+
+    let mut buffer = Vec::new();
+    buffer.try_reserve_exact(blocks).map_err(|_| {
+        Error::InvalidParameter(format!(
+            "cannot allocate the {} KiB of the space cost",
+            params.space_cost
+        ))
+    })?;
+    buffer.push(hasher.hash(&[&encode_string(password), &salt, &instance.to_le_bytes()]));
+    for m in 1..blocks {
+        let block = hasher.hash(&[&buffer[m - 1]]);
+        buffer.push(block);
+    }
+
+    for t in 0..params.time_cost as u64 {
+        for m in 0..blocks {
+            let previous = buffer[(m + blocks - 1) % blocks];
+            buffer[m] = hasher.hash(&[&previous, &buffer[m]]);
+            for i in 0..DELTA {
+                let index = [t, m as u64, i].map(u64::to_le_bytes).concat();
+                let position = hasher.hash(&[&salt, &index]);
+                let other = u64::from_le_bytes(position[..8].try_into().unwrap()) % blocks as u64;
+                buffer[m] = hasher.hash(&[&buffer[m], &buffer[other as usize]]);
+            }
+        }
+    }
+    Ok(buffer[blocks - 1])
+}
+
+/// Balloon hash of `password` with `salt`, filling `params.parallelism` buffers on as many
+/// threads
+pub fn balloon(password: &[u8], salt: &[u8], params: &Params) -> Result<Block> {
+    params.check()?;
+    let last_blocks = std::thread::scope(|scope| {
+        let threads = (0..params.parallelism)
+            .map(|instance| {
+                std::thread::Builder::new().spawn_scoped(scope, move || {
+                    balloon_instance(password, salt, params, instance)
+                })
+            })
+            .collect::<Vec<_>>();
+        threads
+            .into_iter()
+            .map(|thread| thread?.join().unwrap())
+            .collect::<Result<Vec<_>>>()
+    })?;
+
+    let mut combined = [0; BLOCK_BYTES];
+    for block in last_blocks {
+        combined.iter_mut().zip(block).for_each(|(x, y)| *x ^= y);
+    }
+    Ok(Hasher::new().hash(&[&encode_string(password), &encode_string(salt), &combined]))
+}
+
+/// a stored hash, as read from a PHC string
+struct PasswordHash {
+    params: Params,
+    salt: Vec<u8>,
+    hash: Vec<u8>,
+}
+
+impl FromStr for PasswordHash {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self> {
+        let invalid = || Error::InvalidPasswordHash(text.to_string());
+        match text.split('$').collect::<Vec<_>>()[..] {
+            ["", ALGORITHM, params, salt, hash] => Ok(PasswordHash {
+                params: params.parse()?,
+                salt: decode_base64_unpadded(salt).map_err(|_| invalid())?,
+                hash: decode_base64_unpadded(hash).map_err(|_| invalid())?,
+            }),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// hashes `password` with a new salt drawn from the CSPRNG of the operating system, in the PHC
+/// string format
+pub fn hash_password(password: &[u8], params: &Params) -> Result<String> {
+    let mut salt = [0; SALT_BYTES];
+    random_bytes(&mut salt);
+    hash_password_with_salt(password, &salt, params)
+}
+
+/// hashes `password` with a given salt, in the PHC string format
+/// ```
+/// # use TD1::balloon::{hash_password_with_salt, Params};
+/// let params = Params { space_cost: 1, time_cost: 1, parallelism: 1 };
+/// let stored = hash_password_with_salt(b"password", b"salt", &params).unwrap();
+/// assert!(stored.starts_with("$balloon-shake128$m=1,t=1,p=1$c2FsdA$"));
+/// ```
+pub fn hash_password_with_salt(password: &[u8], salt: &[u8], params: &Params) -> Result<String> {
+    let hash = balloon(password, salt, params)?;
+    Ok(format!(
+        "${ALGORITHM}${params}${}${}",
+        base64_unpadded(salt),
+        base64_unpadded(&hash)
+    ))
+}
+
+/// whether `password` is the one hashed into `stored`, a PHC string of [`hash_password`]
+pub fn verify_password(password: &[u8], stored: &str) -> Result<bool> {
+    let stored = stored.parse::<PasswordHash>()?;
+    let hash = balloon(password, &stored.salt, &stored.params)?;
+    Ok(constant_time_eq(&hash, &stored.hash))
+}
+
+/// whether `stored` was hashed with other parameters than `params`, and should be replaced by a
+/// new hash the next time the password is known
+pub fn needs_rehash(stored: &str, params: &Params) -> Result<bool> {
+    let stored = stored.parse::<PasswordHash>()?;
+    Ok(stored.params != *params || stored.hash.len() != BLOCK_BYTES)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SMALL: Params = Params {
+        space_cost: 2,
+        time_cost: 2,
+        parallelism: 1,
+    };
+
+    #[test]
+    fn deterministic_and_separated() {
+        let hash = balloon(b"password", b"salt", &SMALL).unwrap();
+        assert_eq!(balloon(b"password", b"salt", &SMALL).unwrap(), hash);
+        for other in [
+            balloon(b"password", b"salt!", &SMALL),
+            balloon(b"passwore", b"salt", &SMALL),
+            // the boundary between password and salt is encoded
+            balloon(b"passwordsalt", b"", &SMALL),
+            balloon(
+                b"password",
+                b"salt",
+                &Params {
+                    time_cost: 3,
+                    ..SMALL
+                },
+            ),
+            balloon(
+                b"password",
+                b"salt",
+                &Params {
+                    space_cost: 3,
+                    ..SMALL
+                },
+            ),
+            balloon(
+                b"password",
+                b"salt",
+                &Params {
+                    parallelism: 2,
+                    ..SMALL
+                },
+            ),
+        ] {
+            assert_ne!(other.unwrap(), hash);
+        }
+    }
+
+    /// hashes that stored PHC strings depend on: a change to the block hashing, the counters or the
+    /// positions must not go unnoticed
+    #[test]
+    fn known_answers() {
+        for (parallelism, expected) in [
+            (
+                1,
+                "$balloon-shake128$m=16,t=2,p=1$TmFDbC0wMTIzNDU2Nzg5IQ$\
+                 Axzt/ehqEyZr29AzsTgMZA9YxZyIS79RyRTLVQH+0x0",
+            ),
+            (
+                2,
+                "$balloon-shake128$m=16,t=2,p=2$TmFDbC0wMTIzNDU2Nzg5IQ$\
+                 k29KLyBqQk/qdpsjcJucROQLp1DB63hP1O7y9FO+V28",
+            ),
+        ] {
+            let params = Params {
+                space_cost: 16,
+                time_cost: 2,
+                parallelism,
+            };
+            let password = b"correct horse battery staple";
+            let stored = hash_password_with_salt(password, b"NaCl-0123456789!", &params).unwrap();
+            assert_eq!(stored, expected);
+            assert!(verify_password(password, expected).unwrap());
+        }
+    }
+
+    #[test]
+    fn parallel_instances_differ() {
+        let params = Params {
+            parallelism: 4,
+            ..SMALL
+        };
+        let blocks = (0..4)
+            .map(|instance| balloon_instance(b"password", b"salt", &params, instance).unwrap())
+            .collect::<std::collections::HashSet<_>>();
+        assert_eq!(blocks.len(), 4);
+    }
+
+    #[test]
+    fn phc_round_trip() {
+        let stored = hash_password_with_salt(b"password", &[0xA5; 16], &SMALL).unwrap();
+        let parsed = stored.parse::<PasswordHash>().unwrap();
+        assert_eq!(parsed.params, SMALL);
+        assert_eq!(parsed.salt, [0xA5; 16]);
+        assert_eq!(
+            parsed.hash,
+            balloon(b"password", &[0xA5; 16], &SMALL).unwrap()
+        );
+        assert!(verify_password(b"password", &stored).unwrap());
+        assert!(!needs_rehash(&stored, &SMALL).unwrap());
+        assert!(needs_rehash(
+            &stored,
+            &Params {
+                time_cost: 3,
+                ..SMALL
+            }
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn rejects_invalid_hashes() {
+        let stored = hash_password_with_salt(b"password", b"salt", &SMALL).unwrap();
+        let (head, hash) = stored.rsplit_once('$').unwrap();
+        for text in [
+            "",
+            "$argon2id$m=2,t=2,p=1$c2FsdA$AAAA",
+            &format!("{head}$${hash}"),
+            &format!("{head}${hash}="),
+            &stored.replace("balloon-shake128", "balloon-shake256"),
+        ] {
+            assert!(
+                matches!(
+                    verify_password(b"password", text),
+                    Err(Error::InvalidPasswordHash(_))
+                ),
+                "{text}"
+            );
+        }
+        for params in [
+            "m=0,t=2,p=1",
+            "m=2,t=2",
+            "t=2,m=2,p=1",
+            "m=2,t=2,p=1,x=3",
+            "m=2,t=-1,p=1",
+        ] {
+            let text = stored.replace("m=2,t=2,p=1", params);
+            assert!(
+                matches!(
+                    verify_password(b"password", &text),
+                    Err(Error::InvalidParameter(_))
+                ),
+                "{text}"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_zero_costs() {
+        for params in [
+            Params {
+                space_cost: 0,
+                ..SMALL
+            },
+            Params {
+                time_cost: 0,
+                ..SMALL
+            },
+            Params {
+                parallelism: 0,
+                ..SMALL
+            },
+        ] {
+            assert!(matches!(
+                hash_password(b"password", &params),
+                Err(Error::InvalidParameter(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn rejects_oversized_costs() {
+        for params in [
+            Params {
+                space_cost: MAX_SPACE_COST + 1,
+                ..SMALL
+            },
+            Params {
+                time_cost: MAX_TIME_COST + 1,
+                ..SMALL
+            },
+            Params {
+                parallelism: MAX_PARALLELISM + 1,
+                ..SMALL
+            },
+        ] {
+            assert!(matches!(
+                hash_password(b"password", &params),
+                Err(Error::InvalidParameter(_))
+            ));
+        }
+        // the costs of a stored hash are checked before anything is allocated or spawned
+        for costs in [
+            "m=4000000000,t=1,p=1",
+            "m=1,t=4000000000,p=1",
+            "m=1,t=1,p=4000000000",
+        ] {
+            assert!(matches!(
+                verify_password(b"pw", &format!("$balloon-shake128${costs}$c2FsdA$AAAA")),
+                Err(Error::InvalidParameter(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,165 @@
+//! Throughput measurements of the hashers, as written by `shake128 bench`
+//!
+//! The permutation has a single implementation, the portable one of [`crate::keccak`] over `u64`
+//! lanes, and every [`Algorithm`] is a sponge built on it, with its own rate and number of rounds.
+//! A message of any size is hashed by absorbing a buffer of at most [`BUFFER_BYTES`] again and
+//! again, so that measuring large messages takes little memory.
+//!
+//! Cycles are those of the time-stamp counter on `x86_64`, which ticks at a constant rate whatever
+//! the current frequency of the core; there is no count on other architectures.
+//! ```
+//! use std::time::Duration;
+//! use TD1::bench::{measure, ALGORITHMS};
+//!
+//! let measurement = measure(&ALGORITHMS[0], 1000, Duration::ZERO);
+//! assert_eq!(measurement.bytes, 1000);
+//! assert!(measurement.megabytes_per_second() > 0.0);
+//! ```
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::{
+    sha3::Sha3_256,
+    sponge::{Shake128, Squeezer},
+};
+
+/// largest buffer absorbed at once, in bytes
+pub const BUFFER_BYTES: usize = 1 << 20;
+
+/// a hasher whose speed is measured
+pub struct Algorithm {
+    pub name: &'static str,
+    /// absorbs the buffer `repeats` times, and returns the first byte of the output
+    hash: fn(&[u8], usize) -> u8,
+}
+
+fn absorb_repeated(mut hasher: Shake128, buffer: &[u8], repeats: usize) -> Squeezer {
+    for _ in 0..repeats {
+        hasher.update(buffer);
+    }
+    hasher.finalize()
+}
+
+/// the hashers of this crate whose cost per byte differs
+pub const ALGORITHMS: [Algorithm; 3] = [
+    Algorithm {
+        name: "SHAKE128",
+        hash: |buffer, repeats| absorb_repeated(Shake128::new(), buffer, repeats).squeeze_vec(1)[0],
+    },
+    Algorithm {
+        name: "TurboSHAKE128",
+        hash: |buffer, repeats| {
+            absorb_repeated(Shake128::turbo(0x1F), buffer, repeats).squeeze_vec(1)[0]
+        },
+    },
+    Algorithm {
+        name: "SHA3-256",
+        hash: |buffer, repeats| {
+            let mut hasher = Sha3_256::new();
+            for _ in 0..repeats {
+                hasher.update(buffer);
+            }
+            hasher.finalize()[0]
+        },
+    },
+];
+
+/// what hashing a message again and again cost
+#[derive(Clone, Copy, Debug)]
+pub struct Measurement {
+    /// number of bytes hashed, over every run
+    pub bytes: u64,
+    pub elapsed: Duration,
+    /// time-stamp counter cycles, where there is one
+    pub cycles: Option<u64>,
+}
+
+impl Measurement {
+    /// millions of bytes per second
+    pub fn megabytes_per_second(&self) -> f64 {
+        self.bytes as f64 / self.elapsed.as_secs_f64().max(f64::MIN_POSITIVE) / 1e6
+    }
+
+    pub fn cycles_per_byte(&self) -> Option<f64> {
+        self.cycles
+            .map(|cycles| cycles as f64 / self.bytes.max(1) as f64)
+    }
+}
+
+/// current value of the time-stamp counter
+fn cycles() -> Option<u64> {
+    #[cfg(target_arch = "x86_64")]
+    {
+        // SAFETY: every x86_64 processor has the RDTSC instruction
+        Some(unsafe { std::arch::x86_64::_rdtsc() })
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    None
+}
+
+/// hashes messages of `message_bytes` bytes with `algorithm`, at least once and until `duration`
+/// has elapsed
+///
+/// Messages larger than [`BUFFER_BYTES`] are rounded up to a whole number of buffers.
+pub fn measure(algorithm: &Algorithm, message_bytes: usize, duration: Duration) -> Measurement {
+    let buffer = (0..message_bytes.min(BUFFER_BYTES))
+        .map(|i| (i % 251) as u8)
+        .collect::<Vec<_>>();
+    let repeats = match buffer.len() {
+        0 => 1,
+        length => message_bytes.div_ceil(length),
+    };
+
+    let (start, start_cycles) = (Instant::now(), cycles());
+    let mut runs = 0;
+    while runs == 0 || start.elapsed() < duration {
+        black_box((algorithm.hash)(black_box(&buffer), repeats));
+        runs += 1;
+    }
+    let (elapsed, end_cycles) = (start.elapsed(), cycles());
+    Measurement {
+        bytes: runs * (buffer.len() * repeats) as u64,
+        elapsed,
+        cycles: start_cycles
+            .zip(end_cycles)
+            .map(|(start, end)| end.wrapping_sub(start)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::sha3::FixedHash;
+
+    #[test]
+    fn algorithms_hash_the_repeated_buffer() {
+        let buffer = [0x5A; 100];
+        let message = buffer.repeat(3);
+        let mut shake = Shake128::new();
+        shake.update(&message);
+        assert_eq!(
+            (ALGORITHMS[0].hash)(&buffer, 3),
+            shake.finalize().squeeze_vec(1)[0]
+        );
+        let mut turbo = Shake128::turbo(0x1F);
+        turbo.update(&message);
+        assert_eq!(
+            (ALGORITHMS[1].hash)(&buffer, 3),
+            turbo.finalize().squeeze_vec(1)[0]
+        );
+        assert_eq!(
+            (ALGORITHMS[2].hash)(&buffer, 3),
+            Sha3_256::digest(&message)[0]
+        );
+    }
+
+    #[test]
+    fn measures_whole_buffers() {
+        let measurement = measure(&ALGORITHMS[0], BUFFER_BYTES + 1, Duration::ZERO);
+        assert_eq!(measurement.bytes, 2 * BUFFER_BYTES as u64);
+        let empty = measure(&ALGORITHMS[2], 0, Duration::from_millis(10));
+        assert_eq!(empty.bytes, 0);
+        assert!(empty.elapsed >= Duration::from_millis(10));
+        assert_eq!(cycles().is_some(), cfg!(target_arch = "x86_64"));
+    }
+}
@@ -1,153 +1,630 @@
-use std::io::Read;
+use std::io::{Read, Write};
+use std::str::FromStr;
+use std::time::Duration;
 
-use itertools::Itertools;
 use TD1::{
-    convert::bytes_to_chunks, keccak::shake128, BYTES_PER_CHUNK, DIGITS_PER_BYTE, RATE, STATE_SIZE,
+    balloon::{hash_password, verify_password, Params},
+    bench::{measure, ALGORITHMS},
+    cdc,
+    convert::parse_output_size,
+    dir::{hash_path, Options, Symlinks},
+    encoding::Encoding,
+    explain::write_example,
+    hex::{self, Format},
+    kat::check_rsp,
+    kmac::derive_key,
+    merkle::{self, Proof},
+    parallel::hash_files,
+    sponge::{truncate_bits, Shake128},
+    store::{parse_address, Store},
+    trace::HexTrace,
+    Error, BITS_PER_BYTE,
 };
 
-#[allow(non_snake_case)]
-fn partial_XOR(chunk: &[u64], mut state: [u64; STATE_SIZE]) -> [u64; STATE_SIZE] {
-    assert!(chunk.len() == RATE);
-    for b in 0..RATE {
-        state[b] ^= chunk[b]
-    }
-    state
-}
-
-fn sponge(chunks: Vec<[u64; RATE]>) -> [u64; STATE_SIZE] {
-    chunks.into_iter().fold([0u64; STATE_SIZE], |state, chunk| {
-        shake128(partial_XOR(&chunk, state))
-    })
-}
-
-fn squeeze(mut state: [u64; STATE_SIZE], hash_size: usize) -> Vec<[u64; RATE]> {
-    let nb_states_stored = hash_size / (RATE * BYTES_PER_CHUNK) + 1;
-    let mut hash: Vec<[u64; RATE]> = Vec::with_capacity(nb_states_stored);
-    for _ in 0..nb_states_stored {
-        hash.push(state[..RATE].try_into().unwrap());
-        state = shake128(state);
-    }
-    hash
-}
-
-fn resize_hash(hash: Vec<[u64; RATE]>, hash_size: usize, separator: &str) -> String {
-    format!("{}", {
-        let mut hash = hash
-            .into_iter()
-            .map(|state| {
-                state
-                    .map(|word| {
-                        word.to_le_bytes()
-                            .map(|byt| format!("{byt:0width$X}", width = DIGITS_PER_BYTE))
-                            .join(separator)
-                    })
-                    .join(separator)
-            })
-            .join(separator);
-        hash.truncate(hash_size * DIGITS_PER_BYTE);
-        hash
-    })
+/// why the command failed, which gives its exit code
+enum Failure {
+    /// some known-answer tests did not give the expected output
+    Mismatch,
+    /// the command line is wrong
+    Usage(String),
+    Error(Error),
+    /// error while reading the named file
+    InFile(String, Error),
 }
 
-fn main() {
-    let hash_size: usize = std::env::args() // hash_size is a number of bytes
-        .nth(1)
-        .expect("No hash size provided")
-        .parse()
-        .expect("Argument is not an integer");
+impl From<Error> for Failure {
+    fn from(error: Error) -> Self {
+        match error {
+            Error::InvalidOutputSize(_)
+            | Error::UnknownEncoding(_)
+            | Error::InvalidParameter(_) => Failure::Usage(error.to_string()),
+            error => Failure::Error(error),
+        }
+    }
+}
+
+impl From<std::io::Error> for Failure {
+    fn from(error: std::io::Error) -> Self {
+        Failure::Error(Error::Io(error))
+    }
+}
+
+impl Failure {
+    fn exit_code(&self) -> i32 {
+        match self {
+            Failure::Mismatch => 1,
+            Failure::Usage(_) => 2,
+            Failure::Error(Error::Io(_)) | Failure::InFile(_, Error::Io(_)) => 4,
+            Failure::Error(_) | Failure::InFile(..) => 3,
+        }
+    }
+}
+
+/// removes `flag` from `args`, returning whether it was there
+fn take_flag(args: &mut Vec<String>, flag: &str) -> bool {
+    let len = args.len();
+    args.retain(|arg| arg != flag);
+    args.len() != len
+}
+
+/// removes `option` and the value following it from `args`, returning the value
+fn take_option(args: &mut Vec<String>, option: &str) -> Result<Option<String>, Failure> {
+    match args.iter().position(|arg| arg == option) {
+        Some(i) if i + 1 < args.len() => Ok(args.drain(i..i + 2).nth(1)),
+        Some(_) => Err(Failure::Usage(format!("no value provided for {option}"))),
+        None => Ok(None),
+    }
+}
 
+fn read_stdin() -> Result<Vec<u8>, Failure> {
     let mut byt_input = Vec::new();
-    let _ = std::io::stdin().read_to_end(&mut byt_input);
-    let chunks = bytes_to_chunks(&byt_input);
+    std::io::stdin().read_to_end(&mut byt_input)?;
+    Ok(byt_input)
+}
+
+/// `shake128 explain [--rounds] [--bits <message_bits>] [<output_size>]`: writes the
+/// intermediate values of hashing `stdin` in the format of `input_example.txt`
+fn explain(mut args: Vec<String>) -> Result<(), Failure> {
+    let rounds = take_flag(&mut args, "--rounds");
+    let message_bits = take_option(&mut args, "--bits")?
+        .map(|bits| {
+            bits.parse()
+                .map_err(|_| Failure::Usage(format!("`{bits}` is not a number of bits")))
+        })
+        .transpose()?;
+
+    let byt_input = read_stdin()?;
+    let message_bits = message_bits.unwrap_or(byt_input.len() * BITS_PER_BYTE);
+    // the NIST examples squeeze 4096 bits
+    let output_size = match args.first() {
+        Some(size) => parse_output_size(size)?,
+        None => 512,
+    };
+
+    let description = if message_bits == 0 {
+        "This is the empty string.".to_string()
+    } else {
+        format!("This is the message of length {message_bits}.")
+    };
+    let mut stdout = std::io::stdout().lock();
+    write_example(
+        &mut stdout,
+        &description,
+        &byt_input,
+        message_bits,
+        output_size,
+        rounds,
+    )?;
+    Ok(stdout.flush()?)
+}
+
+/// `shake128 kat <file.rsp>...`: runs every vector of CAVP response files, and fails if any of
+/// them does not give the expected output
+fn kat(files: Vec<String>) -> Result<(), Failure> {
+    if files.is_empty() {
+        return Err(Failure::Usage("no response file provided".to_string()));
+    }
+    let mut stdout = std::io::stdout().lock();
+    let mut success = true;
+    for file in files {
+        let report = std::fs::read_to_string(&file)
+            .map_err(Error::from)
+            .and_then(|rsp| check_rsp(&rsp))
+            .map_err(|error| Failure::InFile(file.clone(), error))?;
+        writeln!(stdout, "{file}: {report}")?;
+        for failed in &report.failed {
+            writeln!(stdout, "    failed {failed}")?;
+        }
+        success &= report.failed.is_empty();
+    }
+    if success {
+        Ok(())
+    } else {
+        Err(Failure::Mismatch)
+    }
+}
+
+/// reads the master key of `derive`, which is never given on the command line so that it does
+/// not show in the process list or the shell history
+fn read_master_key(key_file: Option<String>, key_env: Option<String>) -> Result<Vec<u8>, Failure> {
+    match (key_file, key_env) {
+        (Some(file), None) => {
+            std::fs::read(&file).map_err(|error| Failure::InFile(file, Error::Io(error)))
+        }
+        (None, Some(variable)) => match std::env::var_os(&variable) {
+            Some(key) => Ok(key.into_encoded_bytes()),
+            None => Err(Failure::Usage(format!(
+                "the environment variable {variable} is not set"
+            ))),
+        },
+        (Some(_), Some(_)) => Err(Failure::Usage(
+            "the master key is given both by a file and an environment variable".to_string(),
+        )),
+        (None, None) => Err(Failure::Usage(
+            "no master key provided (--key-file or --key-env)".to_string(),
+        )),
+    }
+}
+
+/// `shake128 derive (--key-file <path> | --key-env <variable>) --label <label>
+/// [--context <context>] [--format <encoding>] <output_size>`: derives a key for the label and
+/// context from the master key with KMAC128
+fn derive(mut args: Vec<String>) -> Result<(), Failure> {
+    let master = read_master_key(
+        take_option(&mut args, "--key-file")?,
+        take_option(&mut args, "--key-env")?,
+    )?;
+    let label = take_option(&mut args, "--label")?
+        .ok_or_else(|| Failure::Usage("no label provided".to_string()))?;
+    let context = take_option(&mut args, "--context")?.unwrap_or_default();
+    let encoding = match take_option(&mut args, "--format")? {
+        Some(name) => name.parse()?,
+        None => Encoding::default(),
+    };
+    let output_size = match args.first() {
+        Some(size) => parse_output_size(size)?,
+        None => return Err(Failure::Usage("no output size provided".to_string())),
+    };
+
+    let key = derive_key(&master, label.as_bytes(), context.as_bytes(), output_size);
+    let mut stdout = std::io::stdout().lock();
+    stdout.write_all(&encoding.encode(&key))?;
+    if encoding.is_text() {
+        writeln!(stdout)?;
+    }
+    Ok(stdout.flush()?)
+}
+
+/// removes `option` and the number following it from `args`
+fn take_number<T: FromStr>(args: &mut Vec<String>, option: &str) -> Result<Option<T>, Failure> {
+    take_option(args, option)?
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|_| Failure::Usage(format!("`{value}` is not a valid {option}")))
+        })
+        .transpose()
+}
+
+/// `shake128 passwd [--memory <KiB>] [--time <rounds>] [--parallelism <threads>]`: hashes the
+/// password read from `stdin` into a PHC string
+///
+/// `shake128 passwd --verify <hash>`: checks the password read from `stdin` against a PHC string,
+/// and fails if it does not match
+fn passwd(mut args: Vec<String>) -> Result<(), Failure> {
+    let verify = take_option(&mut args, "--verify")?;
+    let defaults = Params::default();
+    let params = Params {
+        space_cost: take_number(&mut args, "--memory")?.unwrap_or(defaults.space_cost),
+        time_cost: take_number(&mut args, "--time")?.unwrap_or(defaults.time_cost),
+        parallelism: take_number(&mut args, "--parallelism")?.unwrap_or(defaults.parallelism),
+    };
+    if let Some(arg) = args.first() {
+        return Err(Failure::Usage(format!("unexpected argument `{arg}`")));
+    }
+
+    // the line ending added by `echo` or typed in a terminal is not part of the password
+    let mut password = read_stdin()?;
+    if password.ends_with(b"\n") {
+        password.pop();
+        if password.ends_with(b"\r") {
+            password.pop();
+        }
+    }
 
-    let state = sponge(chunks);
-    let hash = resize_hash(squeeze(state, hash_size), hash_size, "");
-    println!("{hash}");
+    match verify {
+        Some(stored) => {
+            if verify_password(&password, &stored)? {
+                Ok(())
+            } else {
+                Err(Failure::Mismatch)
+            }
+        }
+        None => {
+            let mut stdout = std::io::stdout().lock();
+            writeln!(stdout, "{}", hash_password(&password, &params)?)?;
+            Ok(stdout.flush()?)
+        }
+    }
+}
+
+fn open(file: &str) -> Result<std::fs::File, Failure> {
+    std::fs::File::open(file).map_err(|error| Failure::InFile(file.to_string(), Error::Io(error)))
+}
+
+/// `shake128 tree root [--chunk-size <bytes>] [--fanout <n>] [--format <encoding>] <file>`:
+/// writes the Merkle root of a file
+///
+/// `shake128 tree prove [--chunk-size <bytes>] [--fanout <n>] <file> <chunk_index>`: writes the
+/// inclusion proof of a chunk of a file
+///
+/// `shake128 tree verify [--chunk-size <bytes>] [--fanout <n>] --index <chunk_index> --root <root>
+/// <proof_file>`: checks the chunk read from `stdin` against a proof, a trusted root and a trusted
+/// index, and fails if it does not match
+fn tree(mut args: Vec<String>) -> Result<(), Failure> {
+    let defaults = merkle::Params::default();
+    let params = merkle::Params {
+        chunk_size: take_number(&mut args, "--chunk-size")?.unwrap_or(defaults.chunk_size),
+        fanout: take_number(&mut args, "--fanout")?.unwrap_or(defaults.fanout),
+    };
+    let encoding = match take_option(&mut args, "--format")? {
+        Some(name) => name.parse()?,
+        None => Encoding::default(),
+    };
+    let trusted_root = take_option(&mut args, "--root")?;
+    let trusted_index = take_number::<u64>(&mut args, "--index")?;
+
+    let mut stdout = std::io::stdout().lock();
+    match (
+        args.first().map(String::as_str),
+        &args.get(1..).unwrap_or_default(),
+    ) {
+        (Some("root"), [file]) => {
+            let root = merkle::root(open(file)?, &params)
+                .map_err(|error| Failure::InFile(file.clone(), error))?;
+            stdout.write_all(&encoding.encode(&root))?;
+            if encoding.is_text() {
+                writeln!(stdout)?;
+            }
+        }
+        (Some("prove"), [file, index]) => {
+            let index = index
+                .parse()
+                .map_err(|_| Failure::Usage(format!("`{index}` is not a chunk index")))?;
+            let proof = match merkle::prove(open(file)?, &params, index) {
+                Err(Error::Io(error)) => {
+                    return Err(Failure::InFile(file.clone(), Error::Io(error)))
+                }
+                proof => proof?,
+            };
+            write!(stdout, "{proof}")?;
+        }
+        (Some("verify"), [file]) => {
+            let trusted_root = trusted_root
+                .ok_or_else(|| Failure::Usage("no trusted root provided".to_string()))?;
+            let trusted_index = trusted_index
+                .ok_or_else(|| Failure::Usage("no chunk index provided".to_string()))?;
+            let trusted_root = hex::decode(&trusted_root.to_ascii_lowercase(), Format::LOWER)?;
+            let trusted_root: merkle::Hash =
+                trusted_root
+                    .as_slice()
+                    .try_into()
+                    .map_err(|_| Error::WrongLength {
+                        expected: merkle::HASH_BYTES,
+                        found: trusted_root.len(),
+                    })?;
+            let proof = std::fs::read_to_string(file)
+                .map_err(Error::from)
+                .and_then(|text| text.parse::<Proof>())
+                .map_err(|error| Failure::InFile(file.clone(), error))?;
+            let chunk = read_stdin()?;
+            if !proof.verify(&params, trusted_index, &chunk, &trusted_root) {
+                return Err(Failure::Mismatch);
+            }
+        }
+        _ => {
+            return Err(Failure::Usage(
+                "expected tree root <file>, tree prove <file> <chunk_index> or tree verify \
+                 --index <chunk_index> --root <root> <proof_file>"
+                    .to_string(),
+            ))
+        }
+    }
+    Ok(stdout.flush()?)
+}
+
+/// `shake128 dir [--follow-symlinks | --skip-symlinks] [--ignore <pattern>]...
+/// [--format <encoding>] <path>`: writes the hash of a directory tree
+fn dir(mut args: Vec<String>) -> Result<(), Failure> {
+    let symlinks = match (
+        take_flag(&mut args, "--follow-symlinks"),
+        take_flag(&mut args, "--skip-symlinks"),
+    ) {
+        (false, false) => Symlinks::Record,
+        (true, false) => Symlinks::Follow,
+        (false, true) => Symlinks::Skip,
+        (true, true) => {
+            return Err(Failure::Usage(
+                "symbolic links cannot be both followed and skipped".to_string(),
+            ))
+        }
+    };
+    let mut ignore = Vec::new();
+    while let Some(pattern) = take_option(&mut args, "--ignore")? {
+        ignore.push(pattern);
+    }
+    let encoding = match take_option(&mut args, "--format")? {
+        Some(name) => name.parse()?,
+        None => Encoding::default(),
+    };
+    let path = match args.as_slice() {
+        [path] => path,
+        _ => return Err(Failure::Usage("expected a single path".to_string())),
+    };
+
+    let hash = hash_path(path.as_ref(), &Options { symlinks, ignore })?;
+    let mut stdout = std::io::stdout().lock();
+    stdout.write_all(&encoding.encode(&hash))?;
+    if encoding.is_text() {
+        writeln!(stdout)?;
+    }
+    Ok(stdout.flush()?)
 }
 
-#[cfg(test)]
-mod test {
-    use TD1::{
-        convert::{bytes_to_chunks, example_to_state},
-        RATE,
+/// `shake128 store --dir <store> put [<file>]`: stores a file, or `stdin`, and writes the address
+/// of its manifest
+///
+/// `shake128 store --dir <store> get <manifest>`: writes the stored file to `stdout`
+///
+/// `shake128 store --dir <store> forget <manifest>`: removes a manifest, whose chunks are removed
+/// by the next `gc`
+///
+/// `shake128 store --dir <store> gc`: removes the chunks that no manifest uses
+///
+/// `shake128 store --dir <store> verify`: checks every object against its address, and fails if
+/// one is corrupt or missing
+fn store(mut args: Vec<String>) -> Result<(), Failure> {
+    let directory = take_option(&mut args, "--dir")?
+        .ok_or_else(|| Failure::Usage("no store directory provided".to_string()))?;
+    let in_store = |error| Failure::InFile(directory.clone(), error);
+    let store = Store::open(&directory).map_err(in_store)?;
+
+    let mut stdout = std::io::stdout().lock();
+    match (
+        args.first().map(String::as_str),
+        &args.get(1..).unwrap_or_default(),
+    ) {
+        (Some("put"), files @ ([] | [_])) => {
+            let params = cdc::Params::default();
+            let manifest = match files.first() {
+                Some(file) => store
+                    .put(open(file)?, &params)
+                    .map_err(|error| Failure::InFile(file.clone(), error))?,
+                None => store.put(std::io::stdin().lock(), &params)?,
+            };
+            writeln!(stdout, "{}", hex::encode(&manifest, Format::LOWER))?;
+        }
+        (Some("get"), [manifest]) => store
+            .get(&parse_address(manifest)?, &mut stdout)
+            .map_err(in_store)?,
+        (Some("forget"), [manifest]) => {
+            store.forget(&parse_address(manifest)?).map_err(in_store)?
+        }
+        (Some("gc"), []) => {
+            let collected = store.gc().map_err(in_store)?;
+            writeln!(
+                stdout,
+                "removed {} chunks ({} bytes)",
+                collected.chunks, collected.bytes
+            )?;
+        }
+        (Some("verify"), []) => {
+            let problems = store.verify().map_err(in_store)?;
+            for problem in &problems {
+                writeln!(stdout, "{problem}")?;
+            }
+            if !problems.is_empty() {
+                stdout.flush()?;
+                return Err(Failure::Mismatch);
+            }
+        }
+        _ => {
+            return Err(Failure::Usage(
+                "expected store put [<file>], store get <manifest>, store forget <manifest>, \
+                 store gc or store verify"
+                    .to_string(),
+            ))
+        }
+    }
+    Ok(stdout.flush()?)
+}
+
+/// writes the hashes of `files` as `<hash>  <file>` lines, hashing them on `jobs` threads, and
+/// stops at the first file that cannot be read
+fn hash_many(
+    files: &[String],
+    output_bits: usize,
+    encoding: Encoding,
+    jobs: usize,
+    progress: bool,
+) -> Result<(), Failure> {
+    let mut stdout = std::io::stdout().lock();
+    let mut result = Ok(());
+    hash_files(files, output_bits, jobs, |index, hash| {
+        result = hash
+            .map_err(|error| Failure::InFile(files[index].clone(), error))
+            .and_then(|hash| {
+                stdout.write_all(&encoding.encode(&hash))?;
+                if encoding.is_text() {
+                    writeln!(stdout, "  {}", files[index])?;
+                }
+                Ok(())
+            });
+        if progress {
+            eprint!("\r{}/{} files hashed", index + 1, files.len());
+        }
+        result.is_ok()
+    });
+    if progress {
+        eprintln!();
+    }
+    result?;
+    Ok(stdout.flush()?)
+}
+
+/// absorbs `stdin`, straight from the pages of the file it is redirected from with the `mmap`
+/// feature
+fn absorb_input(hasher: &mut Shake128) -> std::io::Result<u64> {
+    // SAFETY: the map is only sound if the file does not change while it is hashed, which the
+    // command cannot ensure: like other tools that map their input, it leaves that to the user
+    // who redirected it, as the README says
+    #[cfg(feature = "mmap")]
+    return unsafe { TD1::input::absorb_stdin_mapped(hasher) };
+    #[cfg(not(feature = "mmap"))]
+    TD1::input::absorb_stdin(hasher)
+}
+
+/// `shake128 [--trace] [--format <encoding>] (<hash_size> | --bits <hash_bits>)`: hashes `stdin`
+///
+/// `shake128 [-j <jobs>] [--progress] [--format <encoding>] (<hash_size> | --bits <hash_bits>)
+/// [--] <file>...`: hashes every file, on as many threads as there are processors by default
+fn hash(mut args: Vec<String>) -> Result<(), Failure> {
+    // the arguments after `--` are files, even when they look like a hash size or an option
+    let marked = match args.iter().position(|arg| arg == "--") {
+        Some(i) => args.drain(i..).skip(1).collect(),
+        None => Vec::new(),
+    };
+    // with --trace, the state after every step of every permutation is written to stderr
+    let trace = take_flag(&mut args, "--trace");
+    let encoding = match take_option(&mut args, "--format")? {
+        Some(name) => name.parse()?,
+        None => Encoding::default(),
+    };
+    let jobs = take_number(&mut args, "-j")?;
+    let progress = take_flag(&mut args, "--progress");
+
+    // the output size is either a number of bytes, or a number of bits with --bits, and the
+    // other arguments are files
+    let (output_bits, mut files) = match (take_option(&mut args, "--bits")?, args.split_first()) {
+        (Some(_), Some((size, _))) if size.bytes().all(|byte| byte.is_ascii_digit()) => {
+            return Err(Failure::Usage(
+                "the hash size is given both in bytes and in bits".to_string(),
+            ))
+        }
+        (Some(bits), _) => (parse_output_size(&bits)?, args.clone()),
+        (None, Some((hash_size, files))) => (
+            parse_output_size(hash_size)?
+                .checked_mul(BITS_PER_BYTE)
+                .ok_or_else(|| Error::InvalidOutputSize(hash_size.to_string()))?,
+            files.to_vec(),
+        ),
+        (None, None) => return Err(Failure::Usage("no hash size provided".to_string())),
+    };
+    files.extend(marked);
+    if !files.is_empty() {
+        if trace {
+            return Err(Failure::Usage(
+                "--trace only applies to stdin, not to files".to_string(),
+            ));
+        }
+        let jobs = match jobs {
+            Some(0) => return Err(Failure::Usage("-j must be at least 1".to_string())),
+            Some(jobs) => jobs,
+            None => std::thread::available_parallelism().map_or(1, usize::from),
+        };
+        return hash_many(&files, output_bits, encoding, jobs, progress);
+    }
+
+    let hash = if !trace {
+        let mut hasher = Shake128::new();
+        absorb_input(&mut hasher)?;
+        hasher.finalize().squeeze_bits(output_bits)
+    } else {
+        let byt_input = read_stdin()?;
+        let mut trace = HexTrace::new(std::io::stderr().lock());
+        let mut hasher = Shake128::new();
+        hasher.update_observed(&byt_input, &mut trace);
+        let mut hash = vec![0; output_bits.div_ceil(BITS_PER_BYTE)];
+        hasher
+            .finalize_observed(&mut trace)
+            .squeeze_observed(&mut hash, &mut trace);
+        trace.finish()?.flush()?;
+        truncate_bits(&mut hash, output_bits);
+        hash
+    };
+    let mut stdout = std::io::stdout().lock();
+    stdout.write_all(&encoding.encode(&hash))?;
+    if encoding.is_text() {
+        writeln!(stdout)?;
+    }
+    Ok(stdout.flush()?)
+}
+
+/// `shake128 bench [--size <bytes>] [--time <seconds>]`: measures the throughput of every hasher
+fn bench(mut args: Vec<String>) -> Result<(), Failure> {
+    let size = take_number(&mut args, "--size")?.unwrap_or(16 << 20);
+    let seconds = take_number::<f64>(&mut args, "--time")?.unwrap_or(1.0);
+    let duration = Duration::try_from_secs_f64(seconds)
+        .map_err(|_| Failure::Usage(format!("`{seconds}` is not a valid --time")))?;
+    if let Some(arg) = args.first() {
+        return Err(Failure::Usage(format!("unexpected argument `{arg}`")));
+    }
+
+    let mut stdout = std::io::stdout().lock();
+    writeln!(stdout, "permutation: portable, 64-bit lanes")?;
+    writeln!(stdout, "message size: {size} bytes")?;
+    for algorithm in &ALGORITHMS {
+        let measurement = measure(algorithm, size, duration);
+        let cycles = match measurement.cycles_per_byte() {
+            Some(cycles) => format!("{cycles:.2} cycles/byte"),
+            None => "- cycles/byte".to_string(),
+        };
+        writeln!(
+            stdout,
+            "{:<14}{:>10.1} MB/s{cycles:>20}",
+            algorithm.name,
+            measurement.megabytes_per_second()
+        )?;
+    }
+    Ok(stdout.flush()?)
+}
+
+fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<String>>();
+    let result = match args.first().map(String::as_str) {
+        Some("explain") => explain(args[1..].to_vec()),
+        Some("kat") => kat(args[1..].to_vec()),
+        Some("derive") => derive(args[1..].to_vec()),
+        Some("passwd") => passwd(args[1..].to_vec()),
+        Some("tree") => tree(args[1..].to_vec()),
+        Some("store") => store(args[1..].to_vec()),
+        Some("dir") => dir(args[1..].to_vec()),
+        Some("bench") => bench(args[1..].to_vec()),
+        _ => hash(args),
     };
 
-    use crate::{resize_hash, sponge, squeeze};
-
-    //const EMPTY: [u64; STATE_SIZE] = [0; STATE_SIZE];
-    //const EMPTY_PADDED: [u64; STATE_SIZE] = [
-    //    0x1F,
-    //    0,
-    //    0,
-    //    0,
-    //    0,
-    //    0,
-    //    0,
-    //    0,
-    //    0,
-    //    0,
-    //    0,
-    //    0,
-    //    0,
-    //    0,
-    //    0,
-    //    0,
-    //    0,
-    //    0,
-    //    0,
-    //    0,
-    //    0x8000000000000000,
-    //    0,
-    //    0,
-    //    0,
-    //    0,
-    //];
-
-    const EMPTY: &str = "";
-
-    #[test]
-    fn in_to_init() {
-        assert_eq!(
-            example_to_state(INIT)[..RATE],
-            bytes_to_chunks(EMPTY.as_bytes())
-                .first()
-                .unwrap()
-                .to_owned()
-        )
-    }
-    const INIT:&str = "1F 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 80 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 ";
-
-    #[test]
-    fn init_to_sponge() {
-        assert_eq!(
-            example_to_state(SPONGE),
-            sponge(vec!(example_to_state(INIT)[..RATE].try_into().unwrap()))
-        );
-    }
-    const SPONGE:&str="7F 9C 2B A4 E8 8F 82 7D 61 60 45 50 76 05 85 3E D7 3B 80 93 F6 EF BC 88 EB 1A 6E AC FA 66 EF 26 3C B1 EE A9 88 00 4B 93 10 3C FB 0A EE FD 2A 68 6E 01 FA 4A 58 E8 A3 63 9C A8 A1 E3 F9 AE 57 E2 35 B8 CC 87 3C 23 DC 62 B8 D2 60 16 9A FA 2F 75 AB 91 6A 58 D9 74 91 88 35 D2 5E 6A 43 50 85 B2 BA DF D6 DF AA C3 59 A5 EF BB 7B CC 4B 59 D5 38 DF 9A 04 30 2E 10 C8 BC 1C BF 1A 0B 3A 51 20 EA 17 CD A7 CF AD 76 5F 56 23 47 4D 36 8C CC A8 AF 00 07 CD 9F 5E 4C 84 9F 16 7A 58 0B 14 AA BD EF AE E7 EE F4 7C B0 FC A9 4C CA AE BA 77 4E C2 0C FF 6A 94 85 A9 7B FC 65 AA 93 AA 4F C9 58 D1 ED B5 27 C0 2E 3A E5 7B CC ";
-
-    #[test]
-    fn sponge_to_squeeze() {
-        assert_eq!(
-            example_to_state(SQUEEZE)[..RATE],
-            squeeze(example_to_state(SPONGE), 32)
-                .first()
-                .unwrap()
-                .to_owned()
-        );
-    }
-    const SQUEEZE:&str="7F 9C 2B A4 E8 8F 82 7D 61 60 45 50 76 05 85 3E D7 3B 80 93 F6 EF BC 88 EB 1A 6E AC FA 66 EF 26 3C B1 EE A9 88 00 4B 93 10 3C FB 0A EE FD 2A 68 6E 01 FA 4A 58 E8 A3 63 9C A8 A1 E3 F9 AE 57 E2 35 B8 CC 87 3C 23 DC 62 B8 D2 60 16 9A FA 2F 75 AB 91 6A 58 D9 74 91 88 35 D2 5E 6A 43 50 85 B2 BA DF D6 DF AA C3 59 A5 EF BB 7B CC 4B 59 D5 38 DF 9A 04 30 2E 10 C8 BC 1C BF 1A 0B 3A 51 20 EA 17 CD A7 CF AD 76 5F 56 23 47 4D 36 8C CC A8 AF 00 07 CD 9F 5E 4C 84 9F 16 7A 58 0B 14 AA BD EF AE E7 EE F4 7C B0 FC A9 4C CA AE BA 77 4E C2 0C FF 6A 94 85 A9 7B FC 65 AA 93 AA 4F C9 58 D1 ED B5 27 C0 2E 3A E5 7B CC ";
-
-    #[test]
-    fn squeeze_to_truncated() {
-        assert_eq!(
-            resize_hash(
-                vec!(example_to_state(SQUEEZE)[..RATE].try_into().unwrap()),
-                32,
-                ""
+    if let Err(failure) = result {
+        match &failure {
+            Failure::Mismatch => {}
+            Failure::Usage(message) => eprintln!(
+                "shake128: {message}\n\
+                 usage: shake128 [--trace] [--format <encoding>] (<hash_size> | --bits <hash_bits>)\n       \
+                 shake128 [-j <jobs>] [--progress] [--format <encoding>] (<hash_size> | --bits <hash_bits>) [--] <file>...\n       \
+                 shake128 explain [--rounds] [--bits <message_bits>] [<output_size>]\n       \
+                 shake128 kat <file.rsp>...\n       \
+                 shake128 derive (--key-file <path> | --key-env <variable>) --label <label> \
+                 [--context <context>] [--format <encoding>] <output_size>\n       \
+                 shake128 passwd [--memory <KiB>] [--time <rounds>] [--parallelism <threads>]\n       \
+                 shake128 passwd --verify <hash>\n       \
+                 shake128 tree (root | prove) [--chunk-size <bytes>] [--fanout <n>] <file> [<chunk_index>]\n       \
+                 shake128 tree verify [--chunk-size <bytes>] [--fanout <n>] --index <chunk_index> --root <root> <proof_file>\n       \
+                 shake128 store --dir <store> (put [<file>] | get <manifest> | forget <manifest> | gc | verify)\n       \
+                 shake128 dir [--follow-symlinks | --skip-symlinks] [--ignore <pattern>]... [--format <encoding>] <path>\n       \
+                 shake128 bench [--size <bytes>] [--time <seconds>]"
             ),
-            HASH
-        );
+            Failure::Error(error) => eprintln!("shake128: {error}"),
+            Failure::InFile(file, error) => eprintln!("shake128: {file}: {error}"),
+        }
+        std::process::exit(failure.exit_code());
     }
-    const HASH: &str = "7F9C2BA4E88F827D616045507605853ED73B8093F6EFBC88EB1A6EACFA66EF26";
 }
@@ -0,0 +1,256 @@
+//! Content-defined chunking with a gear rolling hash
+//!
+//! A stream is cut where a rolling hash of its last bytes has its top bits all zero, so that the
+//! boundaries depend only on the content around them: inserting bytes in a file moves the
+//! boundaries next to the insertion, and every other chunk stays the same. This is what lets a
+//! [store](crate::store) share the chunks of similar files.
+//!
+//! The hash is the gear hash of FastCDC: every byte shifts it left by one bit and adds an entry of
+//! a table of 256 random words, so that a bit of the hash depends on the last 64 bytes at most.
+//! The table is the output of [`cshake128`] customized with `TD1 gear`.
+//! ```
+//! use TD1::cdc::{Chunker, Params};
+//!
+//! let data = (0..100_000u32).map(|i| (i.wrapping_mul(i) >> 7) as u8).collect::<Vec<_>>();
+//! let chunks = Chunker::new(&data[..], Params::default())
+//!     .collect::<std::io::Result<Vec<_>>>()
+//!     .unwrap();
+//! assert_eq!(chunks.concat(), data);
+//! ```
+use std::io::{self, Read};
+use std::sync::OnceLock;
+
+use crate::{kmac::cshake128, Error, Result};
+
+/// bounds of the chunk sizes, in bytes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Params {
+    /// size below which no boundary is looked for
+    pub min_size: usize,
+    /// expected size of the chunks past `min_size`, a power of 2
+    pub avg_size: usize,
+    /// size at which a chunk is cut whatever its content
+    pub max_size: usize,
+}
+
+/// chunks of 4 KiB to 64 KiB, of about 16 KiB past the minimum
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            min_size: 4 * 1024,
+            avg_size: 16 * 1024,
+            max_size: 64 * 1024,
+        }
+    }
+}
+
+impl Params {
+    pub fn check(&self) -> Result<()> {
+        if !self.avg_size.is_power_of_two() || self.avg_size < 2 {
+            return Err(Error::InvalidParameter(format!(
+                "the average chunk size must be a power of 2, not {}",
+                self.avg_size
+            )));
+        }
+        if self.min_size == 0 || self.min_size > self.max_size {
+            return Err(Error::InvalidParameter(format!(
+                "the chunk sizes must satisfy 0 < {} <= {}",
+                self.min_size, self.max_size
+            )));
+        }
+        Ok(())
+    }
+
+    /// the top `log2(avg_size)` bits of the hash, which are all zero at a boundary
+    fn mask(&self) -> u64 {
+        let bits = self.avg_size.trailing_zeros();
+        !(u64::MAX >> bits)
+    }
+}
+
+/// the 256 words added to the hash for each byte value
+fn gear() -> &'static [u64; 256] {
+    static GEAR: OnceLock<[u64; 256]> = OnceLock::new();
+    GEAR.get_or_init(|| {
+        let mut squeezer = cshake128(b"", b"TD1 gear").finalize();
+        let mut gear = [0; 256];
+        for word in &mut gear {
+            *word = u64::from_le_bytes(squeezer.squeeze_array());
+        }
+        gear
+    })
+}
+
+/// length of the first chunk of `data`, where `data` holds at least [`Params::max_size`] bytes
+/// unless it is the end of the stream
+pub fn cut(data: &[u8], params: &Params) -> usize {
+    if data.len() <= params.min_size {
+        return data.len();
+    }
+    let end = data.len().min(params.max_size);
+    let (gear, mask) = (gear(), params.mask());
+    let mut hash = 0u64;
+    for (i, &byt) in data.iter().enumerate().take(end).skip(params.min_size) {
+        hash = (hash << 1).wrapping_add(gear[byt as usize]);
+        if hash & mask == 0 {
+            return i + 1;
+        }
+    }
+    end
+}
+
+/// iterator over the chunks of a stream, which holds at most [`Params::max_size`] bytes of it
+///
+/// An empty stream has no chunks.
+pub struct Chunker<R> {
+    reader: R,
+    params: Params,
+    buffer: Vec<u8>,
+    end: bool,
+}
+
+impl<R: Read> Chunker<R> {
+    /// chunker of `reader`, whose `params` must pass [`Params::check`]
+    pub fn new(reader: R, params: Params) -> Self {
+        Chunker {
+            reader,
+            params,
+            buffer: Vec::with_capacity(params.max_size),
+            end: false,
+        }
+    }
+
+    /// reads until the buffer holds [`Params::max_size`] bytes or the stream ends
+    fn fill(&mut self) -> io::Result<()> {
+        while !self.end && self.buffer.len() < self.params.max_size {
+            let filled = self.buffer.len();
+            self.buffer.resize(self.params.max_size, 0);
+            match self.reader.read(&mut self.buffer[filled..]) {
+                Ok(read) => {
+                    self.buffer.truncate(filled + read);
+                    self.end = read == 0;
+                }
+                Err(error) => {
+                    self.buffer.truncate(filled);
+                    if error.kind() != io::ErrorKind::Interrupted {
+                        return Err(error);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<R: Read> Iterator for Chunker<R> {
+    type Item = io::Result<Vec<u8>>;
+
+    fn next(&mut self) -> Option<io::Result<Vec<u8>>> {
+        if let Err(error) = self.fill() {
+            return Some(Err(error));
+        }
+        if self.buffer.is_empty() {
+            return None;
+        }
+        let length = cut(&self.buffer, &self.params);
+        Some(Ok(self.buffer.drain(..length).collect()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// pseudorandom bytes, so that boundaries are found
+    fn data(length: usize, seed: u8) -> Vec<u8> {
+        let mut squeezer = cshake128(b"", &[seed]).finalize();
+        squeezer.squeeze_vec(length)
+    }
+
+    fn chunks(data: &[u8], params: Params) -> Vec<Vec<u8>> {
+        Chunker::new(data, params)
+            .collect::<io::Result<_>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn chunks_cover_the_stream_within_bounds() {
+        let params = Params {
+            min_size: 64,
+            avg_size: 256,
+            max_size: 1024,
+        };
+        assert!(chunks(b"", params).is_empty());
+        assert_eq!(chunks(b"short", params), [b"short"]);
+
+        let data = data(100_000, 0);
+        let chunks = chunks(&data, params);
+        assert_eq!(chunks.concat(), data);
+        let (last, others) = chunks.split_last().unwrap();
+        assert!(!last.is_empty() && last.len() <= params.max_size);
+        for chunk in others {
+            assert!((params.min_size..=params.max_size).contains(&chunk.len()));
+        }
+        // about avg_size past min_size, on average
+        let mean = data.len() / chunks.len();
+        assert!((200..500).contains(&mean), "mean chunk size {mean}");
+    }
+
+    #[test]
+    fn reads_of_any_size_give_the_same_chunks() {
+        /// reader giving at most 7 bytes at a time
+        struct Trickle<'a>(&'a [u8]);
+        impl Read for Trickle<'_> {
+            fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+                let length = buffer.len().min(self.0.len()).min(7);
+                buffer[..length].copy_from_slice(&self.0[..length]);
+                self.0 = &self.0[length..];
+                Ok(length)
+            }
+        }
+        let params = Params {
+            min_size: 32,
+            avg_size: 128,
+            max_size: 512,
+        };
+        let data = data(20_000, 1);
+        let trickled = Chunker::new(Trickle(&data), params)
+            .collect::<io::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(trickled, chunks(&data, params));
+    }
+
+    #[test]
+    fn insertion_keeps_most_boundaries() {
+        let params = Params {
+            min_size: 64,
+            avg_size: 256,
+            max_size: 2048,
+        };
+        let original = data(50_000, 2);
+        let mut edited = original.clone();
+        edited.splice(25_000..25_000, b"inserted in the middle".iter().copied());
+
+        let before = chunks(&original, params);
+        let after = chunks(&edited, params);
+        let shared = after.iter().filter(|chunk| before.contains(chunk)).count();
+        assert!(shared + 4 >= before.len(), "{shared} of {}", before.len());
+    }
+
+    #[test]
+    fn rejects_bad_parameters() {
+        let bad = |min_size, avg_size, max_size| {
+            Params {
+                min_size,
+                avg_size,
+                max_size,
+            }
+            .check()
+            .is_err()
+        };
+        assert!(Params::default().check().is_ok());
+        assert!(bad(64, 300, 1024));
+        assert!(bad(0, 256, 1024));
+        assert!(bad(2048, 256, 1024));
+    }
+}
@@ -1,119 +1,183 @@
-use crate::{BITS_PER_BYTE, BYTES_PER_CHUNK, DIGITS_PER_BYTE, RATE, STATE_SIZE};
-use itertools::Itertools;
+use crate::{
+    hex::{encode, encode_lanes, Format, Order},
+    Error, Result, BITS_PER_BYTE, BYTES_PER_CHUNK, RATE, STATE_SIZE,
+};
 
+/// bytes separated by single spaces, as in the NIST example files without their trailing space
+const SPACED: Format<'static> = Format::UPPER.grouped(" ", 1);
+
+/// formats every lane as a number, most significant digit first
+/// ```
+/// # use TD1::convert::*;
+/// assert_eq!(chunk_to_string(&[0x000000005544081F, 0x67], " "), "000000005544081F 0000000000000067");
+/// ```
 pub fn chunk_to_string(state: &[u64], separator: &str) -> String {
-    state
-        .into_iter()
-        .map(|word| {
-            format!(
-                "{word:0>width$X}",
-                width = DIGITS_PER_BYTE * BYTES_PER_CHUNK
-            )
-        })
-        .collect::<Vec<String>>()
-        .join(separator)
+    encode_lanes(
+        state,
+        Order::Lanes,
+        Format::UPPER.grouped(separator, BYTES_PER_CHUNK),
+    )
+}
+
+/// formats a state as its bytes in memory order, each followed by a space, as in the NIST example
+/// files
+/// ```
+/// # use TD1::convert::*;
+/// assert_eq!(state_to_bytes_string(&[0x000000005544081F, 0x67]), "1F 08 44 55 00 00 00 00 67 00 00 00 00 00 00 00 ");
+/// ```
+pub fn state_to_bytes_string(state: &[u64]) -> String {
+    encode_lanes(state, Order::Bytes, Format::NIST)
+}
+
+/// formats bytes in uppercase hexadecimal, each followed by a space
+/// ```
+/// # use TD1::convert::*;
+/// assert_eq!(bytes_to_string(&[0xF3, 0x01]), "F3 01 ");
+/// ```
+pub fn bytes_to_string(bytes: &[u8]) -> String {
+    encode(bytes, Format::NIST)
 }
 
 /// takes a string of the form `1F 08 44 55 67` and converts it into chunks, with correct
-/// endianness
+/// endianness, the last chunk being completed with zeros
 /// ```
 /// # use TD1::convert::*;
-/// assert_eq!(read_example("1F 08 44 55 00 00 00 00 67"), "000000005544081F 0000000000000067".to_string());
-/// ```
-pub fn read_example(example: &str) -> String {
-    example
-        .split_whitespace()
-        .chunks(8)
-        .into_iter()
-        .map(|chunk| {
-            format!(
-                "{:0>width$}",
-                chunk.collect::<Vec<_>>().into_iter().rev().join(""),
-                width = DIGITS_PER_BYTE * BYTES_PER_CHUNK
-            )
-        })
-        .join(" ")
+/// assert_eq!(read_example("1F 08 44 55 00 00 00 00 67").unwrap(), "000000005544081F 0000000000000067".to_string());
+/// ```
+pub fn read_example(example: &str) -> Result<String> {
+    let mut bytes = crate::hex::decode(example.trim_end(), SPACED)?;
+    bytes.resize(bytes.len().next_multiple_of(BYTES_PER_CHUNK), 0);
+    let lanes = bytes
+        .chunks_exact(BYTES_PER_CHUNK)
+        .map(|lane| pack_bytes(lane.try_into().unwrap()))
+        .collect::<Vec<u64>>();
+    Ok(chunk_to_string(&lanes, " "))
 }
 
-pub fn example_to_state(example: &str) -> [u64; STATE_SIZE] {
-    read_example(example)
-        .split_whitespace()
-        .map(|chunk| u64::from_str_radix(chunk, 16).unwrap())
-        .collect::<Vec<u64>>()
-        .try_into()
-        .unwrap_or([0; STATE_SIZE])
+/// reads a state written as its bytes in memory order, as in the NIST example files
+/// ```
+/// # use TD1::{convert::*, Error};
+/// let state = example_to_state(&"00 ".repeat(200)).unwrap();
+/// assert_eq!(state, [0; 25]);
+/// assert!(matches!(example_to_state("1F 08"), Err(Error::WrongLength { expected: 200, found: 2 })));
+/// assert!(matches!(example_to_state("1F 8G"), Err(Error::BadHex(_))));
+/// ```
+pub fn example_to_state(example: &str) -> Result<[u64; STATE_SIZE]> {
+    let bytes = crate::hex::decode(example.trim_end(), SPACED)?;
+    if bytes.len() != STATE_SIZE * BYTES_PER_CHUNK {
+        return Err(Error::WrongLength {
+            expected: STATE_SIZE * BYTES_PER_CHUNK,
+            found: bytes.len(),
+        });
+    }
+    Ok(std::array::from_fn(|i| {
+        pack_bytes(
+            bytes[i * BYTES_PER_CHUNK..(i + 1) * BYTES_PER_CHUNK]
+                .try_into()
+                .unwrap(),
+        )
+    }))
 }
 
-#[allow(unused)]
+/// reads a number of output bytes, as given on the command line
+/// ```
+/// # use TD1::{convert::*, Error};
+/// assert_eq!(parse_output_size("32").unwrap(), 32);
+/// assert!(matches!(parse_output_size("-1"), Err(Error::InvalidOutputSize(_))));
+/// ```
+pub fn parse_output_size(size: &str) -> Result<usize> {
+    size.parse()
+        .map_err(|_| Error::InvalidOutputSize(size.to_string()))
+}
+
+#[cfg(test)]
 /// slices a u64 as 8 hexadecimal u8, ordered from least significant to most significant
 fn unpack_bytes(packed_bytes: &u64) -> [u8; BITS_PER_BYTE] {
-    let mut reducing = *packed_bytes;
-    let mut byte_array: [u8; BITS_PER_BYTE] = [0; BITS_PER_BYTE];
-    for i in 0..BITS_PER_BYTE {
-        byte_array[i] = reducing as u8;
-        reducing >>= BITS_PER_BYTE * size_of::<u8>(); // since size_of returns the size in bytes, you have to
-                                                      // count the number of bits per byte
-        #[cfg(test)]
-        eprintln!(
-            "{reducing:0width$X}, extracted = {:0width$X}",
-            byte_array[i],
-            width = DIGITS_PER_BYTE
-        );
-    }
-    byte_array
+    packed_bytes.to_le_bytes()
+}
+
+/// reads 8 bytes as a lane, the first one being the least significant
+pub(crate) fn pack_bytes(unpacked_bytes: &[u8; BITS_PER_BYTE]) -> u64 {
+    u64::from_le_bytes(*unpacked_bytes)
 }
 
-fn pack_bytes(unpacked_bytes: &[u8; BITS_PER_BYTE]) -> u64 {
-    assert_eq!(unpacked_bytes.len(), BITS_PER_BYTE);
-    unpacked_bytes.iter().rev().fold(0, |packed, &byt| {
-        #[cfg(test)]
-        eprint!("{packed:0width$X}", width = DIGITS_PER_BYTE);
-        (packed << (BITS_PER_BYTE * size_of::<u8>())) + (byt as u64)
-    })
+/// XORs `block`, whose length is a multiple of [`BYTES_PER_CHUNK`], into the first lanes of
+/// `state`, reading every 8 bytes in place as a little-endian lane
+/// ```
+/// # use TD1::convert::*;
+/// let mut state = [0; 25];
+/// xor_block(&mut state, &[0x1F, 0, 0, 0, 0, 0, 0, 0, 0x01, 0x02, 0, 0, 0, 0, 0, 0x80]);
+/// assert_eq!(state[..3], [0x1F, 0x8000_0000_0000_0201, 0]);
+/// ```
+///
+/// # Panics
+///
+/// If the length of `block` is not a multiple of [`BYTES_PER_CHUNK`], or if `block` is larger
+/// than the state, as otherwise some of its bytes would be silently dropped.
+pub fn xor_block(state: &mut [u64; STATE_SIZE], block: &[u8]) {
+    let (lanes, rest) = block.as_chunks::<BYTES_PER_CHUNK>();
+    assert!(
+        rest.is_empty() && lanes.len() <= STATE_SIZE,
+        "a block of {} bytes is not a whole number of lanes of the state",
+        block.len()
+    );
+    for (lane, bytes) in state.iter_mut().zip(lanes) {
+        *lane ^= u64::from_le_bytes(*bytes);
+    }
 }
 
 /// takes in (less than 168) bytes, and possibly appends 0b111110…01 to pad to 168 bytes
 /// equivalently, the 168 byte-array first non filled byte is XORed with 0x1F, and the last byte
 /// with 0x80
-fn bytes_to_chunk(b: &[u8]) -> [u64; RATE] {
-    assert!(b.len() <= RATE * BYTES_PER_CHUNK);
-    if b.len() == RATE * BYTES_PER_CHUNK {
-        b.chunks(BYTES_PER_CHUNK)
-            .map(|chunk| pack_bytes(chunk.try_into().unwrap()))
-            .collect::<Vec<u64>>()
-            .try_into()
-            .unwrap()
-    } else {
-        #[cfg(test)]
-        eprintln!("{b:x?}");
-        let mut padded = [0u8; RATE * BYTES_PER_CHUNK];
-        padded[..b.len()].copy_from_slice(b);
-        padded[padded.into_iter().position(|byte| byte == 0u8).unwrap_or(0)] ^= 0x1F;
-        padded[padded.len() - 1] ^= 0x80;
-        bytes_to_chunk(&padded)
+/// ```
+/// # use TD1::{convert::*, Error};
+/// assert!(matches!(bytes_to_chunk(&[0; 169]), Err(Error::WrongLength { expected: 168, found: 169 })));
+/// ```
+pub fn bytes_to_chunk(b: &[u8]) -> Result<[u64; RATE]> {
+    if b.len() > RATE * BYTES_PER_CHUNK {
+        return Err(Error::WrongLength {
+            expected: RATE * BYTES_PER_CHUNK,
+            found: b.len(),
+        });
+    }
+    let mut padded = [0u8; RATE * BYTES_PER_CHUNK];
+    padded[..b.len()].copy_from_slice(b);
+    if b.len() < padded.len() {
+        padded[b.len()] ^= 0x1F;
+        padded[RATE * BYTES_PER_CHUNK - 1] ^= 0x80;
     }
+    let (lanes, _) = padded.as_chunks::<BYTES_PER_CHUNK>();
+    Ok(std::array::from_fn(|i| u64::from_le_bytes(lanes[i])))
 }
 
-pub fn bytes_to_chunks(b: &[u8]) -> Vec<[u64; RATE]> {
-    {
-        if !b.is_empty() {
-            b
-        } else {
-            #[cfg(test)]
-            eprintln!("Empty string given here");
-            &[0]
-        }
-    }
-    .chunks(RATE * BYTES_PER_CHUNK)
-    .map(bytes_to_chunk)
-    .collect::<Vec<[u64; RATE]>>()
-    .try_into()
-    .unwrap()
+/// the chunks of [`bytes_to_chunks`], computed one at a time on the stack
+/// ```
+/// # use TD1::convert::*;
+/// let message = [0xA3; 200];
+/// assert!(iter_chunks(&message).eq(bytes_to_chunks(&message).unwrap()));
+/// // a whole block of message is followed by a block of padding
+/// assert_eq!(iter_chunks(&[0xA3; 168]).count(), 2);
+/// ```
+pub fn iter_chunks(b: &[u8]) -> impl Iterator<Item = [u64; RATE]> + '_ {
+    // a message filling whole blocks, including the empty one, ends with a block of padding only
+    let chunks = b.chunks(RATE * BYTES_PER_CHUNK);
+    let padding = b
+        .len()
+        .is_multiple_of(RATE * BYTES_PER_CHUNK)
+        .then_some(&[][..]);
+    chunks
+        .chain(padding)
+        .map(|chunk| bytes_to_chunk(chunk).expect("chunks are at most a block long"))
+}
+
+pub fn bytes_to_chunks(b: &[u8]) -> Result<Vec<[u64; RATE]>> {
+    Ok(iter_chunks(b).collect())
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::DIGITS_PER_BYTE;
 
     const ZERO_STATE: [u64; STATE_SIZE] = [0; STATE_SIZE];
 
@@ -127,6 +191,18 @@ mod test {
     const UNPACKED: [u8; 8] = [0x00, 0x0C, 0x11, 0xAA, 0x0A, 0xFF, 0x00, 0x10];
     const PACKED: u64 = 0x10_00_FF_0A_AA_11_0C_00;
 
+    #[test]
+    #[should_panic(expected = "not a whole number of lanes")]
+    fn xor_block_rejects_partial_lanes() {
+        xor_block(&mut ZERO_STATE.clone(), &[0; 7]);
+    }
+
+    #[test]
+    #[should_panic(expected = "not a whole number of lanes")]
+    fn xor_block_rejects_oversized_blocks() {
+        xor_block(&mut ZERO_STATE.clone(), &[0; 8 * STATE_SIZE + 8]);
+    }
+
     #[test]
     fn unpacking() {
         assert_eq!(unpack_bytes(&PACKED), UNPACKED);
@@ -137,6 +213,35 @@ mod test {
         assert_eq!(PACKED, pack_bytes(&UNPACKED));
     }
 
+    #[test]
+    fn padding_follows_the_message_bytes() {
+        // zero bytes of the message are not mistaken for the start of the padding
+        let chunk = bytes_to_chunk(&[0x00, 0x41, 0x00]).unwrap();
+        assert_eq!(chunk[0], 0x1F_00_41_00);
+        assert_eq!(chunk[RATE - 1], 0x80 << 56);
+    }
+
+    #[test]
+    fn chunks_hash_like_the_sponge() {
+        for length in [0, 1, 100, 167, 168, 169, 336, 400] {
+            let message = (0..length).map(|i| (i % 3) as u8).collect::<Vec<_>>();
+            let mut state = ZERO_STATE;
+            for chunk in iter_chunks(&message) {
+                for (lane, word) in state.iter_mut().zip(chunk) {
+                    *lane ^= word;
+                }
+                state = crate::keccak::shake128(state);
+            }
+            let mut hasher = crate::sponge::Shake128::new();
+            hasher.update(&message);
+            assert_eq!(
+                hasher.finalize().squeeze_vec(8),
+                state[0].to_le_bytes(),
+                "{length} bytes"
+            );
+        }
+    }
+
     #[test]
     #[should_panic]
     fn hex() {
@@ -149,11 +254,12 @@ mod test {
         let empty_padded= "1F 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 80 ";
         assert_eq!(
             bytes_to_chunks(empty)
+                .unwrap()
                 .into_iter()
                 .map(|chunk| chunk_to_string(&chunk, " "))
                 .collect::<Vec<String>>()
                 .join(" "),
-            read_example(empty_padded)
+            read_example(empty_padded).unwrap()
         );
     }
 
@@ -164,25 +270,27 @@ mod test {
         let padded= "53 58 7B D9 07 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 80 ";
         assert_eq!(
             bytes_to_chunks(&message)
+                .unwrap()
                 .into_iter()
                 .map(|chunk| chunk_to_string(&chunk, " "))
                 .collect::<Vec<String>>()
                 .join(" "),
-            read_example(padded)
+            read_example(padded).unwrap()
         );
     }
 
     #[test]
     fn padding_0xA3_200_times() {
         let message = [0xA3; 168];
-        let padded= "A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 ";
+        let padded= "A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 1F 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 80 ";
         assert_eq!(
             bytes_to_chunks(&message)
+                .unwrap()
                 .into_iter()
                 .map(|chunk| chunk_to_string(&chunk, " "))
                 .collect::<Vec<String>>()
                 .join(" "),
-            read_example(padded)
+            read_example(padded).unwrap()
         );
     }
 
@@ -194,11 +302,12 @@ mod test {
         let padded= "A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 A3 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 ";
         assert_eq!(
             bytes_to_chunks(&message)
+                .unwrap()
                 .into_iter()
                 .map(|chunk| chunk_to_string(&chunk, " "))
                 .collect::<Vec<String>>()
                 .join(" "),
-            read_example(padded)
+            read_example(padded).unwrap()
         );
     }
 }
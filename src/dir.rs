@@ -0,0 +1,437 @@
+//! Deterministic hashes of directory trees
+//!
+//! The hash of a directory is the output of [`cshake128`] customized with `TD1 dir`, over the
+//! encoding of its entries sorted by the bytes of their names. Every entry is encoded as
+//! ```text
+//! type || left_encode(mode) || encode_string(name) || left_encode(size) || hash
+//! ```
+//! where `type` is `f` for a file, `d` for a directory and `l` for a symbolic link, `mode` holds
+//! the permission bits, and `size` and `hash` are
+//! - for a file, its number of bytes and `SHAKE128(content, 256)`,
+//! - for a directory, its number of entries and its hash,
+//! - for a symbolic link, the length of its target and `SHAKE128(target, 256)`.
+//!
+//! The encodings of SP 800-185 ([`left_encode`], [`encode_string`]) give the length of each
+//! field first, so two trees have the same encoding only if they are the same. Modification
+//! times, owners and the order in which the file system lists entries are left out, so that the
+//! hash only changes with the content:
+//! ```
+//! use TD1::dir::{hash_path, Options};
+//!
+//! let root = std::env::temp_dir().join(format!("dir-doctest-{}", std::process::id()));
+//! std::fs::create_dir_all(root.join("bin")).unwrap();
+//! std::fs::write(root.join("bin/tool"), b"binary").unwrap();
+//! let fingerprint = hash_path(&root, &Options::default()).unwrap();
+//!
+//! std::fs::write(root.join("bin/tool"), b"binary").unwrap();
+//! assert_eq!(hash_path(&root, &Options::default()).unwrap(), fingerprint);
+//! # std::fs::remove_dir_all(root).unwrap();
+//! ```
+use std::fs::{self, Metadata};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::{
+    input::absorb_file,
+    kmac::{cshake128, encode_string, left_encode},
+    sponge::Shake128,
+    Error, Result,
+};
+
+/// number of bytes of the hashes
+pub const HASH_BYTES: usize = 32;
+/// customization of the cSHAKE128 of directories
+const DIR_CUSTOMIZATION: &[u8] = b"TD1 dir";
+
+pub type Hash = [u8; HASH_BYTES];
+
+/// what to do with symbolic links
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Symlinks {
+    /// hash the link itself, that is its target path
+    #[default]
+    Record,
+    /// hash what the link points to, as if it were there
+    Follow,
+    /// leave the link out
+    Skip,
+}
+
+/// how a tree is walked
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Options {
+    pub symlinks: Symlinks,
+    /// glob patterns of the entries to leave out
+    ///
+    /// `*` matches any characters but `/`, `**` any characters, and `?` a single one. A pattern
+    /// without `/` is matched against the name of every entry, any other against its path from
+    /// the root, such as `target/*.o`.
+    pub ignore: Vec<String>,
+}
+
+impl Options {
+    fn ignores(&self, name: &str, relative: &str) -> bool {
+        self.ignore.iter().any(|pattern| {
+            let text = if pattern.contains('/') {
+                relative
+            } else {
+                name
+            };
+            glob_match(pattern.as_bytes(), text.as_bytes())
+        })
+    }
+}
+
+/// an element of a glob pattern
+enum Glob {
+    /// `**`, any bytes
+    AnyPath,
+    /// `*`, any bytes but `/`
+    AnyName,
+    /// `?`, any byte but `/`
+    AnyByte,
+    Byte(u8),
+}
+
+/// whether `text` matches the glob `pattern`
+///
+/// The pattern is matched from its end, one element at a time against every suffix of `text`, so
+/// that the time is the product of both lengths whatever the number of stars.
+fn glob_match(mut pattern: &[u8], text: &[u8]) -> bool {
+    let mut globs = Vec::new();
+    while let Some((glob, rest)) = match pattern {
+        [] => None,
+        [b'*', b'*', rest @ ..] => Some((Glob::AnyPath, rest)),
+        [b'*', rest @ ..] => Some((Glob::AnyName, rest)),
+        [b'?', rest @ ..] => Some((Glob::AnyByte, rest)),
+        [c, rest @ ..] => Some((Glob::Byte(*c), rest)),
+    } {
+        globs.push(glob);
+        pattern = rest;
+    }
+
+    // `matches[j]`: whether the elements after the current one match `text[j..]`
+    let mut matches = vec![false; text.len() + 1];
+    matches[text.len()] = true;
+    for glob in globs.iter().rev() {
+        let mut previous = vec![false; text.len() + 1];
+        for j in (0..=text.len()).rev() {
+            let next = text.get(j);
+            previous[j] = match glob {
+                Glob::AnyPath => matches[j] || (next.is_some() && previous[j + 1]),
+                Glob::AnyName => {
+                    matches[j] || (next.is_some_and(|&c| c != b'/') && previous[j + 1])
+                }
+                Glob::AnyByte => next.is_some_and(|&c| c != b'/') && matches[j + 1],
+                Glob::Byte(byte) => next == Some(byte) && matches[j + 1],
+            };
+        }
+        matches = previous;
+    }
+    matches[0]
+}
+
+/// the error of `error` on `path`, which names it
+fn at(path: &Path) -> impl Fn(io::Error) -> Error + '_ {
+    move |error| {
+        Error::Io(io::Error::new(
+            error.kind(),
+            format!("{}: {error}", path.display()),
+        ))
+    }
+}
+
+#[cfg(unix)]
+fn mode(metadata: &Metadata) -> u32 {
+    use std::os::unix::fs::PermissionsExt;
+    metadata.permissions().mode() & 0o7777
+}
+
+/// the usual Unix modes, since other systems have no permission bits
+#[cfg(not(unix))]
+fn mode(metadata: &Metadata) -> u32 {
+    match (metadata.is_dir(), metadata.permissions().readonly()) {
+        (true, _) => 0o755,
+        (false, true) => 0o444,
+        (false, false) => 0o644,
+    }
+}
+
+fn shake(data: &[u8]) -> Hash {
+    Shake128::digest(&[data])
+}
+
+/// the number of bytes and the hash of a file, which is read once
+fn hash_file(path: &Path) -> Result<(u64, Hash)> {
+    let mut file = fs::File::open(path).map_err(at(path))?;
+    let mut hasher = Shake128::new();
+    let size = absorb_file(&mut hasher, &mut file).map_err(at(path))?;
+    Ok((size, hasher.finalize().squeeze_array()))
+}
+
+/// walk of a tree, which remembers the directories being hashed to find symbolic link loops
+struct Walk<'a> {
+    options: &'a Options,
+    ancestors: Vec<PathBuf>,
+}
+
+impl Walk<'_> {
+    /// the number of entries and the hash of the directory `path`, at `relative` from the root
+    fn hash_directory(&mut self, path: &Path, relative: &str) -> Result<(u64, Hash)> {
+        if self.options.symlinks == Symlinks::Follow {
+            let canonical = fs::canonicalize(path).map_err(at(path))?;
+            if self.ancestors.contains(&canonical) {
+                return Err(at(path)(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "symbolic link loop",
+                )));
+            }
+            self.ancestors.push(canonical);
+        }
+
+        let mut entries = fs::read_dir(path)
+            .map_err(at(path))?
+            .map(|entry| entry.map(|entry| entry.file_name()))
+            .collect::<io::Result<Vec<_>>>()
+            .map_err(at(path))?;
+        entries.sort_unstable_by(|a, b| a.as_encoded_bytes().cmp(b.as_encoded_bytes()));
+
+        let mut hasher = cshake128(b"", DIR_CUSTOMIZATION);
+        let mut count = 0;
+        for file_name in entries {
+            let path = path.join(&file_name);
+            let name = file_name.to_string_lossy();
+            let relative = match relative {
+                "" => name.to_string(),
+                parent => format!("{parent}/{name}"),
+            };
+            if self.options.ignores(&name, &relative) {
+                continue;
+            }
+
+            let mut metadata = fs::symlink_metadata(&path).map_err(at(&path))?;
+            if metadata.is_symlink() {
+                match self.options.symlinks {
+                    Symlinks::Record => {}
+                    Symlinks::Follow => metadata = fs::metadata(&path).map_err(at(&path))?,
+                    Symlinks::Skip => continue,
+                }
+            }
+            let (kind, size, hash) = if metadata.is_symlink() {
+                let target = fs::read_link(&path).map_err(at(&path))?;
+                let target = target.as_os_str().as_encoded_bytes();
+                (b'l', target.len() as u64, shake(target))
+            } else if metadata.is_dir() {
+                let (size, hash) = self.hash_directory(&path, &relative)?;
+                (b'd', size, hash)
+            } else if metadata.is_file() {
+                let (size, hash) = hash_file(&path)?;
+                (b'f', size, hash)
+            } else {
+                return Err(at(&path)(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "not a file, a directory or a symbolic link",
+                )));
+            };
+
+            hasher.update(&[kind]);
+            hasher.update(&left_encode(mode(&metadata) as usize));
+            hasher.update(&encode_string(file_name.as_encoded_bytes()));
+            hasher.update(&left_encode(size as usize));
+            hasher.update(&hash);
+            count += 1;
+        }
+
+        if self.options.symlinks == Symlinks::Follow {
+            self.ancestors.pop();
+        }
+        Ok((count, hasher.finalize().squeeze_array()))
+    }
+}
+
+/// the hash of the tree at `path`, or `SHAKE128(content, 256)` if it is a file
+///
+/// The name and the mode of `path` itself are not part of the hash, so that a tree hashes the
+/// same wherever it is. A symbolic link at `path` is always followed.
+pub fn hash_path(path: &Path, options: &Options) -> Result<Hash> {
+    if fs::metadata(path).map_err(at(path))?.is_dir() {
+        let mut walk = Walk {
+            options,
+            ancestors: Vec::new(),
+        };
+        Ok(walk.hash_directory(path, "")?.1)
+    } else {
+        Ok(hash_file(path)?.1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::testing::TempDir;
+
+    fn hash(dir: &TempDir, options: &Options) -> Hash {
+        hash_path(dir, options).unwrap()
+    }
+
+    #[test]
+    fn glob_patterns() {
+        let matches = |pattern: &str, text: &str| glob_match(pattern.as_bytes(), text.as_bytes());
+        assert!(matches("*.o", "main.o"));
+        assert!(!matches("*.o", "main.c"));
+        assert!(!matches("*.o", "src/main.o"));
+        assert!(matches("**.o", "src/main.o"));
+        assert!(matches("target/**", "target/debug/build"));
+        assert!(matches("src/?.rs", "src/a.rs"));
+        assert!(!matches("src/?.rs", "src/ab.rs"));
+        assert!(matches("*", ""));
+        assert!(matches("a*b*c", "abxbyc"));
+        assert!(!matches("a*b*c", "abxbyd"));
+        assert!(matches("**", "a/b"));
+        assert!(!matches("?", ""));
+        // a backtracking matcher would take exponential time on this
+        let name = "a".repeat(200);
+        assert!(!matches(&("*a".repeat(20) + "*b"), &name));
+        assert!(matches(&"*a".repeat(20), &name));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn encoding_of_a_small_tree() {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = TempDir::new("dir", "encoding");
+        dir.write("b", b"bee");
+        dir.write("a/c", b"");
+        for (path, mode) in [("b", 0o644), ("a/c", 0o600), ("a", 0o755)] {
+            fs::set_permissions(dir.join(path), fs::Permissions::from_mode(mode)).unwrap();
+        }
+
+        let entry = |kind: u8, mode: usize, name: &[u8], size: usize, hash: Hash| {
+            [
+                vec![kind],
+                left_encode(mode),
+                encode_string(name),
+                left_encode(size),
+                hash.to_vec(),
+            ]
+            .concat()
+        };
+        let directory = |entries: &[Vec<u8>]| {
+            let mut hasher = cshake128(b"", DIR_CUSTOMIZATION);
+            hasher.update(&entries.concat());
+            hasher.finalize().squeeze_array()
+        };
+        let a = directory(&[entry(b'f', 0o600, b"c", 0, shake(b""))]);
+        let root = directory(&[
+            entry(b'd', 0o755, b"a", 1, a),
+            entry(b'f', 0o644, b"b", 3, shake(b"bee")),
+        ]);
+        assert_eq!(hash(&dir, &Options::default()), root);
+        assert_eq!(
+            hash_path(&dir.join("b"), &Options::default()).unwrap(),
+            shake(b"bee")
+        );
+    }
+
+    #[test]
+    fn hash_follows_the_content() {
+        let dir = TempDir::new("dir", "content");
+        dir.write("src/main.rs", b"fn main() {}");
+        dir.write("README", b"readme");
+        let options = Options::default();
+        let original = hash(&dir, &options);
+        assert_eq!(hash(&dir, &options), original);
+
+        dir.write("README", b"readme!");
+        let edited = hash(&dir, &options);
+        assert_ne!(edited, original);
+        dir.write("README", b"readme");
+        assert_eq!(hash(&dir, &options), original);
+
+        fs::rename(dir.join("README"), dir.join("README.md")).unwrap();
+        assert_ne!(hash(&dir, &options), original);
+        fs::rename(dir.join("README.md"), dir.join("README")).unwrap();
+
+        // an empty file is not an empty directory
+        fs::create_dir(dir.join("empty")).unwrap();
+        let with_directory = hash(&dir, &options);
+        fs::remove_dir(dir.join("empty")).unwrap();
+        dir.write("empty", b"");
+        assert_ne!(hash(&dir, &options), with_directory);
+        assert_ne!(hash(&dir, &options), original);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn mode_is_hashed() {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = TempDir::new("dir", "mode");
+        dir.write("tool", b"#!/bin/sh");
+        let tool = dir.join("tool");
+        fs::set_permissions(&tool, fs::Permissions::from_mode(0o644)).unwrap();
+        let plain = hash(&dir, &Options::default());
+        fs::set_permissions(&tool, fs::Permissions::from_mode(0o755)).unwrap();
+        assert_ne!(hash(&dir, &Options::default()), plain);
+    }
+
+    #[test]
+    fn ignored_entries_are_left_out() {
+        let dir = TempDir::new("dir", "ignore");
+        dir.write("src/lib.rs", b"lib");
+        let clean = hash(&dir, &Options::default());
+        dir.write("src/lib.o", b"object");
+        dir.write("target/debug/out", b"build");
+        dir.write("target/keep", b"kept");
+
+        let options = Options {
+            ignore: vec!["*.o".to_string(), "target".to_string()],
+            ..Options::default()
+        };
+        assert_eq!(hash(&dir, &options), clean);
+        let options = Options {
+            ignore: vec!["*.o".to_string(), "target/**".to_string()],
+            ..Options::default()
+        };
+        assert_ne!(hash(&dir, &options), clean);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn symbolic_links() {
+        use std::os::unix::fs::symlink;
+        let dir = TempDir::new("dir", "symlinks");
+        dir.write("data/file", b"content");
+        let without_link = hash(&dir, &Options::default());
+        symlink("data", dir.join("link")).unwrap();
+
+        let with = |symlinks| Options {
+            symlinks,
+            ..Options::default()
+        };
+        let recorded = hash(&dir, &with(Symlinks::Record));
+        let followed = hash(&dir, &with(Symlinks::Follow));
+        assert_ne!(recorded, without_link);
+        assert_ne!(followed, recorded);
+        assert_eq!(hash(&dir, &with(Symlinks::Skip)), without_link);
+
+        // following the link gives the same tree as a copy of its target
+        fs::remove_file(dir.join("link")).unwrap();
+        dir.write("link/file", b"content");
+        assert_eq!(hash(&dir, &with(Symlinks::Record)), followed);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn symbolic_link_loops_fail() {
+        use std::os::unix::fs::symlink;
+        let dir = TempDir::new("dir", "loop");
+        dir.write("a/file", b"content");
+        symlink("..", dir.join("a/up")).unwrap();
+        assert!(hash_path(&dir, &Options::default()).is_ok());
+        let follow = Options {
+            symlinks: Symlinks::Follow,
+            ..Options::default()
+        };
+        assert!(matches!(hash_path(&dir, &follow), Err(Error::Io(_))));
+    }
+}
@@ -0,0 +1,236 @@
+//! Text and binary encodings of digests
+//!
+//! [`Encoding`] names the output formats of the binary: hexadecimal in either case, the base64,
+//! base64url and base32 alphabets of RFC 4648 (with their `=` padding), and the raw bytes.
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{
+    hex::{self, Format},
+    Error, BITS_PER_BYTE,
+};
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const BASE64URL: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+const BASE32: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const PADDING: char = '=';
+
+/// encodes `bytes` with the `2^bits` symbols of `alphabet`, then pads to a whole number of
+/// `block` symbols
+fn encode_bits(bytes: &[u8], alphabet: &[u8], bits: usize, block: usize) -> String {
+    let mut text = String::with_capacity((bytes.len() * BITS_PER_BYTE).div_ceil(bits) + block);
+    let mask = (1 << bits) - 1;
+    let mut buffer = 0u32;
+    let mut buffered = 0;
+    for &byt in bytes {
+        buffer = (buffer << BITS_PER_BYTE) | byt as u32;
+        buffered += BITS_PER_BYTE;
+        while buffered >= bits {
+            buffered -= bits;
+            text.push(alphabet[(buffer >> buffered) as usize & mask] as char);
+        }
+        buffer &= (1 << buffered) - 1;
+    }
+    // the last bits are completed with zeros
+    if buffered > 0 {
+        text.push(alphabet[(buffer << (bits - buffered)) as usize & mask] as char);
+    }
+    while !text.len().is_multiple_of(block) {
+        text.push(PADDING);
+    }
+    text
+}
+
+/// base64 of RFC 4648, as in `integrity` attributes
+/// ```
+/// # use TD1::encoding::base64;
+/// assert_eq!(base64(b"foob"), "Zm9vYg==");
+/// assert_eq!(base64(&[0xFB, 0xFF]), "+/8=");
+/// ```
+pub fn base64(bytes: &[u8]) -> String {
+    encode_bits(bytes, BASE64, 6, 4)
+}
+
+/// base64 without its `=` padding, as in the PHC string format
+/// ```
+/// # use TD1::encoding::base64_unpadded;
+/// assert_eq!(base64_unpadded(b"foob"), "Zm9vYg");
+/// ```
+pub fn base64_unpadded(bytes: &[u8]) -> String {
+    encode_bits(bytes, BASE64, 6, 1)
+}
+
+/// reads text written by [`base64_unpadded`], whose unused last bits must be zeros
+/// ```
+/// # use TD1::{encoding::decode_base64_unpadded, Error};
+/// assert_eq!(decode_base64_unpadded("Zm9vYg").unwrap(), b"foob");
+/// assert!(matches!(decode_base64_unpadded("Zm9vYh"), Err(Error::BadBase64(_))));
+/// assert!(matches!(decode_base64_unpadded("Zm9vYg=="), Err(Error::BadBase64(_))));
+/// ```
+pub fn decode_base64_unpadded(text: &str) -> Result<Vec<u8>, Error> {
+    let bad_base64 = || Error::BadBase64(text.to_string());
+    let bits = 6;
+    let mut bytes = Vec::with_capacity(text.len() * bits / BITS_PER_BYTE);
+    let mut buffer = 0u32;
+    let mut buffered = 0;
+    for symbol in text.bytes() {
+        let value = BASE64
+            .iter()
+            .position(|&expected| expected == symbol)
+            .ok_or_else(bad_base64)?;
+        buffer = (buffer << bits) | value as u32;
+        buffered += bits;
+        if buffered >= BITS_PER_BYTE {
+            buffered -= BITS_PER_BYTE;
+            bytes.push((buffer >> buffered) as u8);
+            buffer &= (1 << buffered) - 1;
+        }
+    }
+    // a whole symbol left over, or bits that encode_bits would not have written
+    if buffered >= bits || buffer != 0 {
+        return Err(bad_base64());
+    }
+    Ok(bytes)
+}
+
+/// base64 with the URL and filename safe alphabet of RFC 4648
+/// ```
+/// # use TD1::encoding::base64url;
+/// assert_eq!(base64url(&[0xFB, 0xFF]), "-_8=");
+/// ```
+pub fn base64url(bytes: &[u8]) -> String {
+    encode_bits(bytes, BASE64URL, 6, 4)
+}
+
+/// base32 of RFC 4648, which only uses uppercase letters and digits
+/// ```
+/// # use TD1::encoding::base32;
+/// assert_eq!(base32(b"foob"), "MZXW6YQ=");
+/// ```
+pub fn base32(bytes: &[u8]) -> String {
+    encode_bits(bytes, BASE32, 5, 8)
+}
+
+/// output format of a digest
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Encoding {
+    /// lowercase hexadecimal
+    Hex,
+    /// uppercase hexadecimal
+    #[default]
+    HexUpper,
+    Base64,
+    Base64Url,
+    Base32,
+    /// the bytes themselves
+    Raw,
+}
+
+impl Encoding {
+    /// the encoded digest, which is text for every encoding but [`Encoding::Raw`]
+    /// ```
+    /// # use TD1::encoding::Encoding;
+    /// assert_eq!(Encoding::Hex.encode(&[0x7F, 0x9C]), b"7f9c");
+    /// assert_eq!(Encoding::Raw.encode(&[0x7F, 0x9C]), [0x7F, 0x9C]);
+    /// ```
+    pub fn encode(self, bytes: &[u8]) -> Vec<u8> {
+        match self {
+            Encoding::Hex => hex::encode(bytes, Format::LOWER).into_bytes(),
+            Encoding::HexUpper => hex::encode(bytes, Format::UPPER).into_bytes(),
+            Encoding::Base64 => base64(bytes).into_bytes(),
+            Encoding::Base64Url => base64url(bytes).into_bytes(),
+            Encoding::Base32 => base32(bytes).into_bytes(),
+            Encoding::Raw => bytes.to_vec(),
+        }
+    }
+
+    pub fn is_text(self) -> bool {
+        self != Encoding::Raw
+    }
+}
+
+impl fmt::Display for Encoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Encoding::Hex => "hex",
+            Encoding::HexUpper => "HEX",
+            Encoding::Base64 => "base64",
+            Encoding::Base64Url => "base64url",
+            Encoding::Base32 => "base32",
+            Encoding::Raw => "raw",
+        })
+    }
+}
+
+impl FromStr for Encoding {
+    type Err = Error;
+
+    /// reads the names written by [`Display`](fmt::Display), as given to `--format`
+    fn from_str(name: &str) -> Result<Self, Error> {
+        match name {
+            "hex" => Ok(Encoding::Hex),
+            "HEX" => Ok(Encoding::HexUpper),
+            "base64" => Ok(Encoding::Base64),
+            "base64url" => Ok(Encoding::Base64Url),
+            "base32" => Ok(Encoding::Base32),
+            "raw" => Ok(Encoding::Raw),
+            _ => Err(Error::UnknownEncoding(name.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// test vectors of RFC 4648, section 10
+    const RFC_4648: [(&str, &str, &str); 7] = [
+        ("", "", ""),
+        ("f", "Zg==", "MY======"),
+        ("fo", "Zm8=", "MZXQ===="),
+        ("foo", "Zm9v", "MZXW6==="),
+        ("foob", "Zm9vYg==", "MZXW6YQ="),
+        ("fooba", "Zm9vYmE=", "MZXW6YTB"),
+        ("foobar", "Zm9vYmFy", "MZXW6YTBOI======"),
+    ];
+
+    #[test]
+    fn rfc_4648() {
+        for (data, base64_text, base32_text) in RFC_4648 {
+            assert_eq!(base64(data.as_bytes()), base64_text);
+            assert_eq!(base64url(data.as_bytes()), base64_text);
+            assert_eq!(base32(data.as_bytes()), base32_text);
+            let unpadded = base64_text.trim_end_matches(PADDING);
+            assert_eq!(base64_unpadded(data.as_bytes()), unpadded);
+            assert_eq!(decode_base64_unpadded(unpadded).unwrap(), data.as_bytes());
+        }
+    }
+
+    #[test]
+    fn rejects_bad_base64() {
+        for text in ["Z", "Zm9vY", "Zg=", "Zm8 ", "Zh", "Zm9"] {
+            assert!(
+                matches!(decode_base64_unpadded(text), Err(Error::BadBase64(_))),
+                "{text}"
+            );
+        }
+    }
+
+    #[test]
+    fn names_round_trip() {
+        for encoding in [
+            Encoding::Hex,
+            Encoding::HexUpper,
+            Encoding::Base64,
+            Encoding::Base64Url,
+            Encoding::Base32,
+            Encoding::Raw,
+        ] {
+            assert_eq!(encoding.to_string().parse::<Encoding>().unwrap(), encoding);
+        }
+        assert!(matches!(
+            "base58".parse::<Encoding>(),
+            Err(Error::UnknownEncoding(_))
+        ));
+    }
+}
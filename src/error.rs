@@ -0,0 +1,109 @@
+//! Errors of the conversions, the file parsers and the hashing APIs
+use std::fmt;
+use std::io;
+
+/// what is wrong with an example or response file, and on which line (starting from 1)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug)]
+pub enum Error {
+    /// text that should be a hexadecimal byte
+    BadHex(String),
+    /// text that should be unpadded base64
+    BadBase64(String),
+    /// `found` bytes were given where `expected` are needed
+    WrongLength {
+        expected: usize,
+        found: usize,
+    },
+    /// text that should be a number of output bytes
+    InvalidOutputSize(String),
+    /// more output bytes are `requested` than can be derived
+    OutputTooLong {
+        max: usize,
+        requested: usize,
+    },
+    /// name that is not one of the output [`Encoding`](crate::encoding::Encoding)s
+    UnknownEncoding(String),
+    /// cost parameters that are out of range, or that do not match what is expected
+    InvalidParameter(String),
+    /// text that should be a password hash in the PHC string format
+    InvalidPasswordHash(String),
+    /// address of an object which is not in the [store](crate::store::Store)
+    MissingObject(String),
+    /// address of an object of the [store](crate::store::Store) whose content does not match it
+    CorruptObject(String),
+    Parse(ParseError),
+    Io(io::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::BadHex(hex) => write!(f, "`{hex}` is not hexadecimal"),
+            Error::BadBase64(text) => write!(f, "`{text}` is not unpadded base64"),
+            Error::WrongLength { expected, found } => {
+                write!(f, "expected {expected} bytes, found {found}")
+            }
+            Error::InvalidOutputSize(size) => {
+                write!(f, "`{size}` is not a number of output bytes")
+            }
+            Error::OutputTooLong { max, requested } => {
+                write!(
+                    f,
+                    "{requested} output bytes requested, at most {max} can be derived"
+                )
+            }
+            Error::UnknownEncoding(name) => write!(
+                f,
+                "`{name}` is not an output format (hex, HEX, base64, base64url, base32, raw)"
+            ),
+            Error::InvalidParameter(message) => write!(f, "invalid parameter: {message}"),
+            Error::InvalidPasswordHash(hash) => {
+                write!(f, "`{hash}` is not a balloon-shake128 password hash")
+            }
+            Error::MissingObject(address) => write!(f, "object {address} is not in the store"),
+            Error::CorruptObject(address) => {
+                write!(f, "object {address} does not match its address")
+            }
+            Error::Parse(error) => error.fmt(f),
+            Error::Io(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Parse(error) => Some(error),
+            Error::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        Error::Parse(error)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Error::Io(error)
+    }
+}
@@ -0,0 +1,289 @@
+//! Parsing the NIST / KeccakTools example files
+//!
+//! [`parse_examples`] reads text in the format of `input_example.txt`, as written by
+//! [`write_example`](crate::explain::write_example), back into [`Example`]s: the message, the
+//! padded message cut into blocks, every permutation with its optional per-round states, and the
+//! squeezed output. Any file in that format can then be checked against the permutation and the
+//! sponge instead of copy-pasting its states into the tests.
+use crate::{
+    error::ParseError,
+    hex::{self, Format},
+    sponge::RATE_BYTES,
+    trace::Step,
+    Result, BYTES_PER_CHUNK, STATE_SIZE,
+};
+
+/// states after every step of one round, in the order the steps are applied
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Round {
+    /// round index, as in `--- Round 0 ---`
+    pub index: i64,
+    pub steps: Vec<(Step, [u64; STATE_SIZE])>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Permutation {
+    pub input: [u64; STATE_SIZE],
+    /// empty when the example does not detail the rounds
+    pub rounds: Vec<Round>,
+    pub output: [u64; STATE_SIZE],
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Example {
+    pub description: String,
+    /// message, its last byte aligned on LSB
+    pub message: Vec<u8>,
+    pub message_bits: usize,
+    /// message with its suffix and padding, cut into the blocks XORed before each permutation
+    pub blocks: Vec<[u8; RATE_BYTES]>,
+    pub permutations: Vec<Permutation>,
+    /// all the squeezed bytes
+    pub output: Vec<u8>,
+}
+
+fn parse_bytes(data: &str) -> std::result::Result<Vec<u8>, String> {
+    hex::decode(data, Format::NIST).map_err(|error| error.to_string())
+}
+
+fn parse_state(data: &str) -> std::result::Result<[u64; STATE_SIZE], String> {
+    let bytes = parse_bytes(data)?;
+    if bytes.len() != STATE_SIZE * BYTES_PER_CHUNK {
+        return Err(format!("a state has 200 bytes, not {}", bytes.len()));
+    }
+    Ok(std::array::from_fn(|i| {
+        u64::from_le_bytes(
+            bytes[i * BYTES_PER_CHUNK..(i + 1) * BYTES_PER_CHUNK]
+                .try_into()
+                .unwrap(),
+        )
+    }))
+}
+
+fn parse_step(name: &str) -> Option<Step> {
+    match name {
+        "theta" => Some(Step::Theta),
+        "rho" => Some(Step::Rho),
+        "pi" => Some(Step::Pi),
+        "chi" => Some(Step::Chi),
+        "iota" => Some(Step::Iota),
+        _ => None,
+    }
+}
+
+/// example being read, with the parts that are only complete at the next section
+#[derive(Default)]
+struct Parser {
+    example: Example,
+    /// padded bytes not followed by a permutation yet
+    block: Vec<u8>,
+    squeezing: bool,
+    /// permutation whose output has not been read yet
+    permutation: Option<Permutation>,
+}
+
+impl Parser {
+    fn permutation(&mut self) -> std::result::Result<&mut Permutation, String> {
+        self.permutation
+            .as_mut()
+            .ok_or_else(|| "no permutation is running".to_string())
+    }
+
+    fn section(&mut self, title: &str, data: &str) -> std::result::Result<(), String> {
+        match title {
+            "Input message (last byte aligned on LSB)" => {
+                self.example.message = parse_bytes(data)?;
+            }
+            "Input message (in bits)" => {
+                self.example.message_bits = data.split_whitespace().count();
+            }
+            "Delimited suffix"
+            | "Suffix (in bits)"
+            | "Input message with suffix appended to it (in bits)"
+            | "Input message with suffix appended to it (last byte aligned on LSB)" => {}
+            "Initial state" => {
+                parse_state(data)?;
+            }
+            "Block to be absorbed"
+            | "Block to be absorbed (part)"
+            | "Block to be absorbed (last few bits + first bit of padding)" => {
+                self.block.extend(parse_bytes(data)?);
+                if self.block.len() > RATE_BYTES {
+                    return Err(format!("a block has {RATE_BYTES} bytes at most"));
+                }
+            }
+            "Second bit of padding" => {
+                let padding = parse_bytes(data)?;
+                if padding.len() != RATE_BYTES {
+                    return Err(format!("the padding block has {RATE_BYTES} bytes"));
+                }
+                self.block.resize(RATE_BYTES, 0);
+                for (byt, pad) in self.block.iter_mut().zip(padding) {
+                    *byt ^= pad;
+                }
+            }
+            "Input of permutation" => {
+                if !self.squeezing {
+                    let mut block = [0; RATE_BYTES];
+                    block[..self.block.len()].copy_from_slice(&self.block);
+                    self.example.blocks.push(block);
+                    self.block.clear();
+                }
+                self.permutation = Some(Permutation {
+                    input: parse_state(data)?,
+                    rounds: Vec::new(),
+                    output: [0; STATE_SIZE],
+                });
+            }
+            "State after permutation" => {
+                let mut permutation = self
+                    .permutation
+                    .take()
+                    .ok_or_else(|| "no permutation is running".to_string())?;
+                permutation.output = parse_state(data)?;
+                self.example.permutations.push(permutation);
+            }
+            "Squeezed block" | "Squeezed block (part)" => {
+                self.example.output.extend(parse_bytes(data)?);
+            }
+            _ => {
+                let step = title
+                    .strip_prefix("After ")
+                    .and_then(parse_step)
+                    .ok_or_else(|| format!("unknown section `{title}`"))?;
+                let state = parse_state(data)?;
+                self.permutation()?
+                    .rounds
+                    .last_mut()
+                    .ok_or_else(|| "no round is running".to_string())?
+                    .steps
+                    .push((step, state));
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> std::result::Result<Example, String> {
+        if self.permutation.is_some() {
+            return Err("the last permutation has no output".to_string());
+        }
+        if !self.block.is_empty() {
+            return Err("the last block is not permuted".to_string());
+        }
+        Ok(self.example)
+    }
+}
+
+/// reads every example of a text in the format of `input_example.txt`
+/// ```
+/// # use TD1::example::parse_examples;
+/// let examples = parse_examples(include_str!("../input_example.txt")).unwrap();
+/// assert_eq!(examples.len(), 7);
+/// assert_eq!(examples[1].message_bits, 5);
+/// assert_eq!(examples[1].message, [0x13]);
+/// ```
+pub fn parse_examples(text: &str) -> Result<Vec<Example>> {
+    let mut examples = Vec::new();
+    let mut parser: Option<Parser> = None;
+    let mut lines = text.lines().enumerate().map(|(i, line)| (i + 1, line));
+
+    while let Some((line, content)) = lines.next() {
+        let error = |message| ParseError { line, message };
+        if content.is_empty() {
+            continue;
+        }
+
+        if content.starts_with("+++ Example") {
+            if let Some(previous) = parser.take() {
+                examples.push(previous.finish().map_err(error)?);
+            }
+            // the description is the first line that is not blank
+            let description = lines
+                .by_ref()
+                .map(|(_, description)| description)
+                .find(|description| !description.is_empty())
+                .ok_or_else(|| error("the example has no description".to_string()))?;
+            parser = Some(Parser {
+                example: Example {
+                    description: description.to_string(),
+                    ..Example::default()
+                },
+                ..Parser::default()
+            });
+            continue;
+        }
+
+        let current = parser
+            .as_mut()
+            .ok_or_else(|| error("the file does not start with an example".to_string()))?;
+        if content == "--- Switching to squeezing phase ---" {
+            current.squeezing = true;
+        } else if let Some(round) = content
+            .strip_prefix("--- Round ")
+            .and_then(|round| round.strip_suffix(" ---"))
+        {
+            let index = round
+                .parse()
+                .map_err(|_| error(format!("`{round}` is not a round index")))?;
+            current.permutation().map_err(error)?.rounds.push(Round {
+                index,
+                steps: Vec::new(),
+            });
+        } else if let Some(title) = content.strip_suffix(':') {
+            // the data is on the next line, which is blank for the empty message
+            let (_, data) = lines.next().unwrap_or((line + 1, ""));
+            current.section(title, data).map_err(error)?;
+        } else {
+            return Err(error(format!("unexpected line `{content}`")).into());
+        }
+    }
+
+    if let Some(last) = parser {
+        let line = text.lines().count();
+        examples.push(
+            last.finish()
+                .map_err(|message| ParseError { line, message })?,
+        );
+    }
+    Ok(examples)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{explain::write_example, Error};
+
+    #[test]
+    fn reads_back_written_rounds() {
+        let mut text = Vec::new();
+        write_example(&mut text, "Two blocks.", &[0xA3; 201], 1605, 200, true).unwrap();
+        let examples = parse_examples(&String::from_utf8(text).unwrap()).unwrap();
+
+        assert_eq!(examples.len(), 1);
+        let example = &examples[0];
+        assert_eq!(example.description, "Two blocks.");
+        assert_eq!(example.message_bits, 1605);
+        assert_eq!(example.message.len(), 201);
+        assert_eq!(example.blocks.len(), 2);
+        assert_eq!(example.permutations.len(), 3);
+        assert_eq!(example.output.len(), 200);
+        for permutation in &example.permutations {
+            assert_eq!(permutation.rounds.len(), 24);
+            assert!(permutation
+                .rounds
+                .iter()
+                .all(|round| round.steps.len() == 5));
+            assert_eq!(permutation.rounds[23].steps[4].1, permutation.output);
+        }
+    }
+
+    #[test]
+    fn reports_line() {
+        let error = parse_examples("+++ Example +++\n\nEmpty.\n\nInput of permutation:\n00 00 \n")
+            .unwrap_err();
+        assert_eq!(error.to_string(), "line 5: a state has 200 bytes, not 2");
+
+        let error = parse_examples("Initial state:\n").unwrap_err();
+        assert!(matches!(error, Error::Parse(ParseError { line: 1, .. })));
+    }
+}
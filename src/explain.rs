@@ -0,0 +1,332 @@
+//! Intermediate values of SHAKE128, as in the NIST / KeccakTools example files
+//!
+//! [`write_example`] writes the same text as `input_example.txt` for any message: the message and
+//! its suffix in bits and bytes, every block absorbed, the input and output of every permutation
+//! and the squeezed blocks. Optionally, the state after every step of every round is written
+//! between the input and output of the permutations, like the `--trace` of the binary.
+//!
+//! The message is absorbed and the output squeezed by [`Shake128`] itself, in the pieces that the
+//! reference implementation of the Keccak team displays, and an [`Observer`] writes every
+//! permutation in between.
+use std::io::{self, Write};
+
+use crate::{
+    convert::{bytes_to_string, state_to_bytes_string},
+    keccak::rounds,
+    sponge::{Shake128, RATE_BYTES},
+    trace::{HexTrace, Observer, Step},
+    Error, Result, BITS_PER_BYTE, BYTES_PER_CHUNK, STATE_SIZE,
+};
+
+/// domain separation suffix of SHAKE (`1111`), followed by its delimiter bit
+const SHAKE_DELIMITED_SUFFIX: u8 = 0x1F;
+/// rounds of Keccak-f\[1600\], whose last one has the index `SHAKE_ROUNDS - 1`
+const SHAKE_ROUNDS: usize = rounds(u64::BITS);
+
+/// bits of `bytes` from the least significant bit of each byte, keeping only the first `length`
+fn to_bits(bytes: &[u8], length: usize) -> Vec<u8> {
+    (0..length)
+        .map(|i| (bytes[i / BITS_PER_BYTE] >> (i % BITS_PER_BYTE)) & 1)
+        .collect()
+}
+
+/// bits packed from the least significant bit of each byte, the last byte being aligned on LSB
+fn from_bits(bits: &[u8]) -> Vec<u8> {
+    bits.chunks(BITS_PER_BYTE)
+        .map(|byte| {
+            byte.iter()
+                .enumerate()
+                .fold(0, |packed, (i, bit)| packed | (bit << i))
+        })
+        .collect()
+}
+
+fn display_bytes(out: &mut impl Write, title: &str, bytes: &[u8]) -> io::Result<()> {
+    write!(out, "{title}:\n{}\n\n", bytes_to_string(bytes))
+}
+
+fn display_bits(out: &mut impl Write, title: &str, bits: &[u8]) -> io::Result<()> {
+    writeln!(out, "{title}:")?;
+    for bit in bits {
+        write!(out, "{bit} ")?;
+    }
+    write!(out, "\n\n")
+}
+
+fn display_text(out: &mut impl Write, text: &str) -> io::Result<()> {
+    write!(out, "{text}\n\n")
+}
+
+/// writes the input and output of every permutation and, with `rounds`, the state after every
+/// step, between the blocks that [`write_example`] writes through [`ExampleTrace::display`]
+struct ExampleTrace<W: Write> {
+    trace: HexTrace<W>,
+    rounds: bool,
+    /// number of permutations started so far
+    permutations: usize,
+    /// the permutation that absorbs the second bit of padding, which is written before it
+    padding: Option<usize>,
+}
+
+impl<W: Write> ExampleTrace<W> {
+    fn new(out: W, rounds: bool) -> Self {
+        ExampleTrace {
+            trace: HexTrace::new(out),
+            rounds,
+            permutations: 0,
+            padding: None,
+        }
+    }
+
+    fn display(&mut self, title: &str, bytes: &[u8]) {
+        self.trace
+            .write(format_args!("{title}:\n{}\n\n", bytes_to_string(bytes)));
+    }
+}
+
+impl<W: Write> Observer<u64> for ExampleTrace<W> {
+    fn round(&mut self, round: i64, state: &[u64; STATE_SIZE]) {
+        if round == 0 {
+            if self.padding == Some(self.permutations) {
+                let mut padding = [0; RATE_BYTES];
+                padding[RATE_BYTES - 1] = 0x80;
+                self.display("Second bit of padding", &padding);
+            }
+            self.permutations += 1;
+            self.trace.write(format_args!(
+                "Input of permutation:\n{}\n\n",
+                state_to_bytes_string(state)
+            ));
+        }
+        if self.rounds {
+            self.trace.round(round, state);
+        }
+    }
+
+    fn step(&mut self, round: i64, step: Step, state: &[u64; STATE_SIZE]) {
+        if self.rounds {
+            self.trace.step(round, step, state);
+        }
+        if step == Step::Iota && round == SHAKE_ROUNDS as i64 - 1 {
+            self.trace.write(format_args!(
+                "State after permutation:\n{}\n\n",
+                state_to_bytes_string(state)
+            ));
+        }
+    }
+}
+
+/// writes the intermediate values of SHAKE128 on the first `message_bits` bits of `message` (the
+/// last byte being aligned on LSB), for an output of `output_size` bytes
+///
+/// With `rounds`, the state after every step of every round is also written. Fails with
+/// [`Error::WrongLength`] if `message` has fewer than `message_bits` bits.
+/// ```
+/// # use TD1::explain::write_example;
+/// let mut text = Vec::new();
+/// write_example(&mut text, "This is the empty string.", &[], 0, 32, false).unwrap();
+/// let text = String::from_utf8(text).unwrap();
+/// assert!(text.starts_with("+++ Example with a small message +++\n\nThis is the empty string.\n\n"));
+/// assert!(text.ends_with("Squeezed block (part):\n7F 9C 2B A4 E8 8F 82 7D 61 60 45 50 76 05 85 3E D7 3B 80 93 F6 EF BC 88 EB 1A 6E AC FA 66 EF 26 \n\n"));
+/// ```
+pub fn write_example(
+    out: &mut impl Write,
+    description: &str,
+    message: &[u8],
+    message_bits: usize,
+    output_size: usize,
+    rounds: bool,
+) -> Result<()> {
+    if message.len() * BITS_PER_BYTE < message_bits {
+        return Err(Error::WrongLength {
+            expected: message_bits.div_ceil(BITS_PER_BYTE),
+            found: message.len(),
+        });
+    }
+    let kind = if message_bits < RATE_BYTES * BITS_PER_BYTE {
+        "small"
+    } else {
+        "larger"
+    };
+    display_text(out, &format!("+++ Example with a {kind} message +++"))?;
+    display_text(out, description)?;
+
+    let message_bits = to_bits(message, message_bits);
+    display_bytes(
+        out,
+        "Input message (last byte aligned on LSB)",
+        &from_bits(&message_bits),
+    )?;
+    display_bits(out, "Input message (in bits)", &message_bits)?;
+
+    display_bytes(out, "Delimited suffix", &[SHAKE_DELIMITED_SUFFIX])?;
+    let suffix_bits = SHAKE_DELIMITED_SUFFIX.ilog2() as usize;
+    let suffix_bits = to_bits(&[SHAKE_DELIMITED_SUFFIX], suffix_bits);
+    display_bits(out, "Suffix (in bits)", &suffix_bits)?;
+
+    let appended_bits = [message_bits, suffix_bits].concat();
+    let appended = from_bits(&appended_bits);
+    display_bits(
+        out,
+        "Input message with suffix appended to it (in bits)",
+        &appended_bits,
+    )?;
+    display_bytes(
+        out,
+        "Input message with suffix appended to it (last byte aligned on LSB)",
+        &appended,
+    )?;
+
+    let full_bytes = appended_bits.len() / BITS_PER_BYTE;
+    let last_bits = appended_bits.len() % BITS_PER_BYTE;
+    let delimited_data = appended.get(full_bytes).copied().unwrap_or(0) | (1 << last_bits);
+
+    let mut trace = ExampleTrace::new(out, rounds);
+    trace.display("Initial state", &[0; STATE_SIZE * BYTES_PER_CHUNK]);
+    // the last bits of the message and the suffix are absorbed as the domain separation bits
+    let mut hasher = Shake128::with_parameters(RATE_BYTES, SHAKE_ROUNDS, delimited_data);
+    let (mut data, mut position) = (&appended[..full_bytes], 0);
+    while !data.is_empty() {
+        let title = if position == 0 && data.len() >= RATE_BYTES {
+            "Block to be absorbed"
+        } else {
+            "Block to be absorbed (part)"
+        };
+        let (block, rest) = data.split_at(data.len().min(RATE_BYTES - position));
+        trace.display(title, block);
+        hasher.update_observed(block, &mut trace);
+        position = (position + block.len()) % RATE_BYTES;
+        data = rest;
+    }
+
+    trace.display(
+        "Block to be absorbed (last few bits + first bit of padding)",
+        &[delimited_data],
+    );
+    // the second bit of padding needs a whole new block if the first one was the last bit
+    let full = delimited_data >= 0x80 && position == RATE_BYTES - 1;
+    trace.padding = Some(trace.permutations + usize::from(full));
+    let mut squeezer = hasher.finalize_observed(&mut trace);
+    trace
+        .trace
+        .write(format_args!("--- Switching to squeezing phase ---\n\n"));
+
+    // the first block is always written as a part, and the following ones as whole blocks
+    let (mut remaining, mut offset) = (output_size, 0);
+    while remaining > 0 {
+        let title = if offset == RATE_BYTES && remaining >= RATE_BYTES {
+            "Squeezed block"
+        } else {
+            "Squeezed block (part)"
+        };
+        offset %= RATE_BYTES;
+        let mut block = vec![0; remaining.min(RATE_BYTES - offset)];
+        squeezer.squeeze_observed(&mut block, &mut trace);
+        trace.display(title, &block);
+        offset += block.len();
+        remaining -= block.len();
+    }
+    trace.trace.finish()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hex::{decode, Format};
+    use itertools::Itertools;
+
+    const INPUT_EXAMPLE: &str = include_str!("../input_example.txt");
+
+    /// lines following the line `title` in `text`, in order
+    fn sections<'a>(text: &'a str, title: &'a str) -> impl Iterator<Item = &'a str> {
+        text.lines()
+            .tuple_windows()
+            .filter(move |(line, _)| *line == title)
+            .map(|(_, next)| next)
+    }
+
+    #[test]
+    fn bits_round_trip() {
+        assert_eq!(to_bits(&[0x13], 5), [1, 1, 0, 0, 1]);
+        assert_eq!(from_bits(&[1, 1, 0, 0, 1, 1, 1, 1, 1]), [0xF3, 0x01]);
+    }
+
+    #[test]
+    fn reproduces_input_example() {
+        let descriptions = INPUT_EXAMPLE
+            .lines()
+            .filter(|line| line.starts_with("This is the "));
+        let messages = sections(INPUT_EXAMPLE, "Input message (last byte aligned on LSB):");
+        let lengths = sections(INPUT_EXAMPLE, "Input message (in bits):");
+
+        let mut text = Vec::new();
+        for ((description, message), bits) in descriptions.zip(messages).zip(lengths) {
+            let message = decode(message, Format::NIST).unwrap();
+            let bits = bits.split_whitespace().count();
+            write_example(&mut text, description, &message, bits, 512, false).unwrap();
+        }
+
+        // the file does not end with the blank line that separates examples
+        assert_eq!(
+            String::from_utf8(text).unwrap(),
+            INPUT_EXAMPLE.to_owned() + "\n"
+        );
+    }
+
+    #[test]
+    fn last_padding_bit_in_a_new_block() {
+        // 1339 bits and the suffix leave 7 bits in the last byte of the block, so that the first
+        // bit of padding is its last bit
+        let message = (0..168).map(|i| (i * 29 % 251) as u8).collect::<Vec<_>>();
+        let mut text = Vec::new();
+        write_example(&mut text, "", &message, 1339, 32, false).unwrap();
+        let text = String::from_utf8(text).unwrap();
+        let titles = text
+            .lines()
+            .filter(|line| {
+                line.starts_with("Block to be absorbed (last")
+                    || line.starts_with("Second bit")
+                    || line.starts_with("Input of permutation")
+                    || line.starts_with("---")
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            titles,
+            [
+                "Block to be absorbed (last few bits + first bit of padding):",
+                "Input of permutation:",
+                "Second bit of padding:",
+                "Input of permutation:",
+                "--- Switching to squeezing phase ---",
+            ]
+        );
+        assert_eq!(
+            sections(&text, "Squeezed block (part):").collect::<Vec<_>>(),
+            ["8E 90 12 7E AC 34 D5 42 6A 76 34 DD 2A C0 5F 8F 41 9E 90 AE 76 28 A8 21 C2 E6 1C 5A BB CB 28 BA "]
+        );
+    }
+
+    #[test]
+    fn rounds_are_interleaved() {
+        let mut plain = Vec::new();
+        write_example(&mut plain, "", &[0x13], 5, 8, false).unwrap();
+        let plain = String::from_utf8(plain).unwrap();
+        let mut traced = Vec::new();
+        write_example(&mut traced, "", &[0x13], 5, 8, true).unwrap();
+        let traced = String::from_utf8(traced).unwrap();
+
+        assert_eq!(traced.matches("--- Round 0 ---").count(), 1);
+        assert!(matches!(
+            write_example(&mut Vec::new(), "", &[0x13], 9, 8, false),
+            Err(Error::WrongLength {
+                expected: 2,
+                found: 1
+            })
+        ));
+        let permuted = sections(&plain, "State after permutation:").next().unwrap();
+        assert!(traced.contains(&format!(
+            "After iota:\n{permuted}\n\nState after permutation:\n{permuted}\n\n"
+        )));
+    }
+}
@@ -0,0 +1,250 @@
+//! Hexadecimal encoding and decoding of bytes and lanes
+//!
+//! A [`Format`] gives the case of the digits and how bytes are grouped and separated. [`decode`]
+//! only accepts text that [`encode`] would write with the same format, so that both round-trip
+//! exactly. States are written either as lanes, most significant digit first, or as their bytes
+//! in memory order as in the NIST example files (see [`Order`]).
+use crate::{Error, Result, BYTES_PER_CHUNK};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Case {
+    Lower,
+    Upper,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Format<'a> {
+    pub case: Case,
+    /// written between groups of bytes
+    pub separator: &'a str,
+    /// number of bytes in a group, `0` for a single group
+    pub group: usize,
+    /// whether the separator also follows the last group
+    pub trailing: bool,
+}
+
+impl<'a> Format<'a> {
+    /// `7F9C2BA4`, as printed by the binary
+    pub const UPPER: Format<'static> = Format {
+        case: Case::Upper,
+        separator: "",
+        group: 0,
+        trailing: false,
+    };
+    /// `7f9c2ba4`, as in the CAVP response files
+    pub const LOWER: Format<'static> = Format {
+        case: Case::Lower,
+        ..Format::UPPER
+    };
+    /// `7F 9C 2B A4 `, as in the NIST example files
+    pub const NIST: Format<'static> = Format {
+        case: Case::Upper,
+        separator: " ",
+        group: 1,
+        trailing: true,
+    };
+
+    /// same format with `separator` between groups of `group` bytes, and not after the last one
+    pub const fn grouped<'b>(self, separator: &'b str, group: usize) -> Format<'b> {
+        Format {
+            case: self.case,
+            separator,
+            group,
+            trailing: false,
+        }
+    }
+
+    fn digits(&self) -> &'static [u8; 16] {
+        match self.case {
+            Case::Lower => b"0123456789abcdef",
+            Case::Upper => b"0123456789ABCDEF",
+        }
+    }
+}
+
+/// how the lanes of a state are written
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Order {
+    /// every lane as a number, most significant digit first
+    Lanes,
+    /// the bytes of the lanes in memory order, least significant byte first
+    Bytes,
+}
+
+/// writes `bytes` in hexadecimal
+/// ```
+/// # use TD1::hex::*;
+/// assert_eq!(encode(&[0x7F, 0x0C, 0xA4], Format::UPPER), "7F0CA4");
+/// assert_eq!(encode(&[0x7F, 0x0C, 0xA4], Format::LOWER.grouped(":", 1)), "7f:0c:a4");
+/// assert_eq!(encode(&[0x7F, 0x0C, 0xA4], Format::NIST), "7F 0C A4 ");
+/// ```
+pub fn encode(bytes: &[u8], format: Format) -> String {
+    let digits = format.digits();
+    let mut text = String::with_capacity(bytes.len() * (2 + format.separator.len()));
+    for (i, &byt) in bytes.iter().enumerate() {
+        if format.group > 0 && i > 0 && i % format.group == 0 {
+            text.push_str(format.separator);
+        }
+        text.push(digits[(byt >> 4) as usize] as char);
+        text.push(digits[(byt & 0xF) as usize] as char);
+    }
+    if format.trailing && !bytes.is_empty() {
+        text.push_str(format.separator);
+    }
+    text
+}
+
+fn decode_digit(digit: u8, format: Format) -> Option<u8> {
+    format
+        .digits()
+        .iter()
+        .position(|&expected| expected == digit)
+        .map(|value| value as u8)
+}
+
+/// reads text written by [`encode`] with the same `format`
+/// ```
+/// # use TD1::{hex::*, Error};
+/// assert_eq!(decode("7F 0C A4 ", Format::NIST).unwrap(), [0x7F, 0x0C, 0xA4]);
+/// assert_eq!(decode("", Format::NIST).unwrap(), []);
+/// // wrong case, missing trailing separator, odd number of digits
+/// assert!(matches!(decode("7f 0c a4 ", Format::NIST), Err(Error::BadHex(_))));
+/// assert!(matches!(decode("7F 0C A4", Format::NIST), Err(Error::BadHex(_))));
+/// assert!(matches!(decode("7F0", Format::UPPER), Err(Error::BadHex(_))));
+/// ```
+pub fn decode(text: &str, format: Format) -> Result<Vec<u8>> {
+    let bad_hex = || Error::BadHex(text.to_string());
+    let mut rest = text;
+    if format.trailing && !text.is_empty() {
+        rest = text.strip_suffix(format.separator).ok_or_else(bad_hex)?;
+    }
+
+    let mut bytes = Vec::with_capacity(rest.len() / 2);
+    while !rest.is_empty() {
+        // the separator comes before every group but the first
+        if format.group > 0 && !bytes.is_empty() && bytes.len() % format.group == 0 {
+            rest = rest.strip_prefix(format.separator).ok_or_else(bad_hex)?;
+        }
+        match rest.as_bytes() {
+            [high, low, ..] => {
+                let high = decode_digit(*high, format).ok_or_else(bad_hex)?;
+                let low = decode_digit(*low, format).ok_or_else(bad_hex)?;
+                bytes.push((high << 4) | low);
+                rest = &rest[2..];
+            }
+            _ => return Err(bad_hex()),
+        }
+    }
+    Ok(bytes)
+}
+
+/// writes the lanes of a state, either as numbers or as their bytes in memory order
+/// ```
+/// # use TD1::hex::*;
+/// let lanes = [0x000000005544081F, 0x67];
+/// assert_eq!(encode_lanes(&lanes, Order::Lanes, Format::UPPER.grouped(" ", 8)), "000000005544081F 0000000000000067");
+/// assert_eq!(encode_lanes(&lanes[..1], Order::Bytes, Format::NIST), "1F 08 44 55 00 00 00 00 ");
+/// ```
+pub fn encode_lanes(lanes: &[u64], order: Order, format: Format) -> String {
+    let bytes = lanes
+        .iter()
+        .flat_map(|lane| match order {
+            Order::Lanes => lane.to_be_bytes(),
+            Order::Bytes => lane.to_le_bytes(),
+        })
+        .collect::<Vec<u8>>();
+    encode(&bytes, format)
+}
+
+/// reads lanes written by [`encode_lanes`] with the same `order` and `format`
+/// ```
+/// # use TD1::{hex::*, Error};
+/// assert_eq!(decode_lanes("1F 08 44 55 00 00 00 00 ", Order::Bytes, Format::NIST).unwrap(), [0x5544081F]);
+/// assert!(matches!(decode_lanes("1F 08 ", Order::Bytes, Format::NIST), Err(Error::WrongLength { expected: 8, found: 2 })));
+/// ```
+pub fn decode_lanes(text: &str, order: Order, format: Format) -> Result<Vec<u64>> {
+    let bytes = decode(text, format)?;
+    if bytes.len() % BYTES_PER_CHUNK != 0 {
+        return Err(Error::WrongLength {
+            expected: bytes.len().next_multiple_of(BYTES_PER_CHUNK),
+            found: bytes.len(),
+        });
+    }
+    Ok(bytes
+        .chunks_exact(BYTES_PER_CHUNK)
+        .map(|lane| {
+            let lane = lane.try_into().unwrap();
+            match order {
+                Order::Lanes => u64::from_be_bytes(lane),
+                Order::Bytes => u64::from_le_bytes(lane),
+            }
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const FORMATS: [Format<'static>; 7] = [
+        Format::UPPER,
+        Format::LOWER,
+        Format::NIST,
+        Format::UPPER.grouped(" ", 8),
+        Format::LOWER.grouped(":", 1),
+        Format::LOWER.grouped(", ", 3),
+        Format {
+            case: Case::Lower,
+            separator: "\n",
+            group: 4,
+            trailing: true,
+        },
+    ];
+
+    #[test]
+    fn round_trips() {
+        let bytes = (0..=255).collect::<Vec<u8>>();
+        for format in FORMATS {
+            for length in [0, 1, 2, 3, 7, 8, 9, 256] {
+                let text = encode(&bytes[..length], format);
+                assert_eq!(
+                    decode(&text, format).unwrap(),
+                    &bytes[..length],
+                    "{format:?}"
+                );
+                assert_eq!(
+                    encode(&decode(&text, format).unwrap(), format),
+                    text,
+                    "{format:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn lanes_round_trip() {
+        let lanes = [0x0123456789ABCDEF, 0, u64::MAX, 0x80];
+        for format in FORMATS {
+            for order in [Order::Lanes, Order::Bytes] {
+                let text = encode_lanes(&lanes, order, format);
+                assert_eq!(decode_lanes(&text, order, format).unwrap(), lanes);
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_misplaced_separators() {
+        let format = Format::UPPER.grouped(" ", 2);
+        assert_eq!(decode("7F0C A4", format).unwrap(), [0x7F, 0x0C, 0xA4]);
+        for text in [
+            "7F 0CA4", "7F0CA4", "7F0C  A4", " 7F0C A4", "7F0C A4 ", "7F0C ", "7G",
+        ] {
+            assert!(
+                matches!(decode(text, format), Err(Error::BadHex(_))),
+                "{text}"
+            );
+        }
+        assert!(decode("7F0C", format).is_ok());
+        assert!(decode("7F 0C", Format::UPPER).is_err());
+    }
+}
@@ -0,0 +1,179 @@
+//! HKDF (RFC 5869) over [`crate::hmac`]
+//!
+//! [`Hkdf::extract`] concentrates the entropy of the input keying material into a pseudorandom
+//! key, from which [`Hkdf::expand`] derives as many keys as needed, each bound to its `info`.
+//! ```
+//! use TD1::{hkdf::Hkdf, sha3::Sha3_256};
+//!
+//! let hkdf = Hkdf::<Sha3_256>::extract(Some(b"salt"), b"shared secret");
+//! let mut encryption_key = [0; 32];
+//! hkdf.expand(b"encryption", &mut encryption_key).unwrap();
+//! let mut mac_key = [0; 32];
+//! hkdf.expand(b"authentication", &mut mac_key).unwrap();
+//! assert_ne!(encryption_key, mac_key);
+//! ```
+use std::marker::PhantomData;
+
+use crate::{
+    hmac::{hmac, Hmac},
+    sha3::FixedHash,
+    Error, Result,
+};
+
+/// pseudorandom key of HKDF with the hash `H`
+#[derive(Clone, Debug)]
+pub struct Hkdf<H: FixedHash> {
+    prk: Vec<u8>,
+    hash: PhantomData<H>,
+}
+
+impl<H: FixedHash> Hkdf<H> {
+    /// HKDF-Extract, whose salt is [`FixedHash::OUTPUT_BYTES`] zeros when not given
+    pub fn extract(salt: Option<&[u8]>, ikm: &[u8]) -> Self {
+        let zeros = vec![0; H::OUTPUT_BYTES];
+        Hkdf {
+            prk: hmac::<H>(salt.unwrap_or(&zeros), ikm),
+            hash: PhantomData,
+        }
+    }
+
+    /// skips the extraction, for a key that is already pseudorandom and at least
+    /// [`FixedHash::OUTPUT_BYTES`] long
+    pub fn from_prk(prk: &[u8]) -> Result<Self> {
+        if prk.len() < H::OUTPUT_BYTES {
+            return Err(Error::WrongLength {
+                expected: H::OUTPUT_BYTES,
+                found: prk.len(),
+            });
+        }
+        Ok(Hkdf {
+            prk: prk.to_vec(),
+            hash: PhantomData,
+        })
+    }
+
+    /// the pseudorandom key output by [`Hkdf::extract`]
+    pub fn prk(&self) -> &[u8] {
+        &self.prk
+    }
+
+    /// HKDF-Expand, filling `okm`, which can be at most 255 times [`FixedHash::OUTPUT_BYTES`] long
+    pub fn expand(&self, info: &[u8], okm: &mut [u8]) -> Result<()> {
+        let max = 255 * H::OUTPUT_BYTES;
+        if okm.len() > max {
+            return Err(Error::OutputTooLong {
+                max,
+                requested: okm.len(),
+            });
+        }
+
+        let mac = Hmac::<H>::new(&self.prk);
+        let mut previous = Vec::new();
+        for (counter, chunk) in (1..=255).zip(okm.chunks_mut(H::OUTPUT_BYTES)) {
+            let mut block = mac.clone();
+            block.update(&previous);
+            block.update(info);
+            block.update(&[counter]);
+            previous = block.finalize();
+            chunk.copy_from_slice(&previous[..chunk.len()]);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hex::{decode, encode, Format};
+    use crate::sha3::{Sha3_256, Sha3_512};
+
+    /// the inputs of the first three test cases of RFC 5869, with SHA-3 instead of SHA-256
+    ///
+    /// RFC 5869 only has outputs for SHA-1 and SHA-256, so the expected PRKs and OKMs below were
+    /// computed with the `hmac` and `hashlib` modules of Python 3.11 (OpenSSL 3.0), following the
+    /// two steps of the RFC; `tests/digest.rs` checks HKDF against the `hmac` crate as well.
+    fn check<H: FixedHash>(
+        salt: Option<&[u8]>,
+        ikm: &[u8],
+        info: &[u8],
+        expected_prk: &str,
+        expected_okm: &str,
+    ) {
+        let hkdf = Hkdf::<H>::extract(salt, ikm);
+        assert_eq!(encode(hkdf.prk(), Format::LOWER), expected_prk);
+        let mut okm = vec![0; expected_okm.len() / 2];
+        hkdf.expand(info, &mut okm).unwrap();
+        assert_eq!(encode(&okm, Format::LOWER), expected_okm);
+
+        let mut again = vec![0; okm.len()];
+        Hkdf::<H>::from_prk(&decode(expected_prk, Format::LOWER).unwrap())
+            .unwrap()
+            .expand(info, &mut again)
+            .unwrap();
+        assert_eq!(again, okm);
+    }
+
+    fn range(bytes: std::ops::Range<u8>) -> Vec<u8> {
+        bytes.collect()
+    }
+
+    #[test]
+    fn rfc_5869_inputs() {
+        check::<Sha3_256>(
+            Some(&range(0x00..0x0D)),
+            &[0x0B; 22],
+            &range(0xF0..0xFA),
+            "7d4194836f7a113a44677abc825640ade07af1c1d69a9a4b109b280a8fe54ef0",
+            "0c5160501d65021deaf2c14f5abce04c5bd2635abceeba61c2edb6e8ed726749\
+             00557728f2c9f2c4c179",
+        );
+        check::<Sha3_256>(
+            Some(&range(0x60..0xB0)),
+            &range(0x00..0x50),
+            &[range(0xB0..0xFF), vec![0xFF]].concat(),
+            "addf31835b49366ac27734104d9f1865c1c2e7c8a2ebc1fed712808e4eab677c",
+            "3dc251e66c75da6560405ec5ac10e17d851eedfbfdc13feafbec16964c25d021\
+             bd971465a3e9c615f27769019e3f0407d84986fb0ba24e729c99834624baa21c\
+             b623dc0098f430d52e18bbdf694df4edd8b2",
+        );
+        check::<Sha3_256>(
+            None,
+            &[0x0B; 22],
+            b"",
+            "b899e6e4b88a35f9f5d618f48b424c313f9704012763eb6295414d673365928a",
+            "bc1342cdd75c05e8b0c3ae609ce4410684d197232875073499b30cdfe2de2853\
+             c1c1bed63d725e885e78",
+        );
+        check::<Sha3_512>(
+            Some(&range(0x60..0xB0)),
+            &range(0x00..0x50),
+            &[range(0xB0..0xFF), vec![0xFF]].concat(),
+            "bc138b5ec5f398198e333105a8ed3c2e775016e53c8de21aaddc2d776964e14e\
+             9e1fd19bf5678aa97c2a57427d1eeac6e8ca44ddbae018a47dc18fe8201efdc6",
+            "3adf31011245f82cc6b5c3b2ea31fe2a9b855b425c3ecdd8da4a3fc5d0c3563f\
+             63bbdedf7ca912d2e98cbc853d978066ab177f19a7349e3982549b82a307e211\
+             3891691f2536ce45eb5ddf9b5175859ce8d588401fe790b495cba39d79541426\
+             87b6faf5",
+        );
+    }
+
+    #[test]
+    fn output_limits() {
+        let hkdf = Hkdf::<Sha3_512>::extract(None, b"ikm");
+        assert!(hkdf.expand(b"", &mut [0; 255 * 64]).is_ok());
+        assert!(matches!(
+            hkdf.expand(b"", &mut [0; 255 * 64 + 1]),
+            Err(Error::OutputTooLong {
+                max: 16320,
+                requested: 16321
+            })
+        ));
+        assert!(matches!(
+            Hkdf::<Sha3_512>::from_prk(&[0; 63]),
+            Err(Error::WrongLength {
+                expected: 64,
+                found: 63
+            })
+        ));
+    }
+}
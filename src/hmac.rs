@@ -0,0 +1,227 @@
+//! HMAC (FIPS 198-1) over the SHA-3 hashes of [`crate::sha3`]
+//!
+//! The key is padded to the block size of the hash, which is its rate, and the inner and outer
+//! hashers absorb their padded keys when the [`Hmac`] is created: the message is then absorbed as
+//! it comes, and each tag only pays for the message and the outer hash.
+//! ```
+//! use TD1::{hmac::Hmac, sha3::Sha3_256};
+//!
+//! let mut mac = Hmac::<Sha3_256>::new(b"key");
+//! mac.update(b"streamed ");
+//! mac.update(b"message");
+//! let tag = mac.clone().finalize();
+//! assert!(mac.clone().verify(&tag));
+//! assert!(mac.clone().verify_truncated(&tag[..16], 16));
+//! assert!(!mac.verify(&tag[..16]));
+//! ```
+use std::io::{self, Write};
+
+use crate::sha3::FixedHash;
+
+const INNER_PAD: u8 = 0x36;
+const OUTER_PAD: u8 = 0x5C;
+
+/// HMAC of the hash `H` with a given key
+#[derive(Clone, Debug)]
+pub struct Hmac<H: FixedHash> {
+    /// hasher that absorbed the key XORed with [`INNER_PAD`], then the message so far
+    inner: H,
+    /// hasher that absorbed the key XORed with [`OUTER_PAD`]
+    outer: H,
+}
+
+impl<H: FixedHash> Hmac<H> {
+    /// keys longer than the block size are hashed first, as specified
+    pub fn new(key: &[u8]) -> Self {
+        let mut block = vec![0; H::BLOCK_BYTES];
+        if key.len() > H::BLOCK_BYTES {
+            block[..H::OUTPUT_BYTES].copy_from_slice(&H::digest(key));
+        } else {
+            block[..key.len()].copy_from_slice(key);
+        }
+
+        let padded = |pad: u8| {
+            let mut hasher = H::default();
+            hasher.update(&block.iter().map(|byt| byt ^ pad).collect::<Vec<u8>>());
+            hasher
+        };
+        Hmac {
+            inner: padded(INNER_PAD),
+            outer: padded(OUTER_PAD),
+        }
+    }
+
+    /// absorbs `data` into the message
+    pub fn update(&mut self, data: &[u8]) {
+        self.inner.update(data)
+    }
+
+    /// the tag of the message, [`FixedHash::OUTPUT_BYTES`] long
+    pub fn finalize(self) -> Vec<u8> {
+        let mut inner = vec![0; H::OUTPUT_BYTES];
+        self.inner.finalize_into(&mut inner);
+        let mut outer = self.outer;
+        outer.update(&inner);
+        let mut tag = vec![0; H::OUTPUT_BYTES];
+        outer.finalize_into(&mut tag);
+        tag
+    }
+
+    /// whether `tag` is the whole tag of the message, in a time that does not depend on where
+    /// they differ
+    pub fn verify(self, tag: &[u8]) -> bool {
+        constant_time_eq(&self.finalize(), tag)
+    }
+
+    /// whether `tag` is the tag of the message truncated to `len` bytes, its leftmost ones as in
+    /// SP 800-107, in a time that does not depend on where they differ
+    ///
+    /// # Panics
+    ///
+    /// If `len` is shorter than half the tag, which this crate takes as the least a truncated
+    /// tag may keep, or longer than the tag.
+    pub fn verify_truncated(self, tag: &[u8], len: usize) -> bool {
+        assert!(
+            (H::OUTPUT_BYTES.div_ceil(2)..=H::OUTPUT_BYTES).contains(&len),
+            "a truncated tag keeps from half to all of the {} bytes of the tag",
+            H::OUTPUT_BYTES
+        );
+        tag.len() == len && constant_time_eq(&self.finalize()[..len], tag)
+    }
+}
+
+/// absorbs everything written into the message
+impl<H: FixedHash> Write for Hmac<H> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// HMAC of `message` under `key`
+/// ```
+/// # use TD1::{hmac::hmac, sha3::Sha3_256};
+/// assert_eq!(hmac::<Sha3_256>(b"key", b"").len(), 32);
+/// ```
+pub fn hmac<H: FixedHash>(key: &[u8], message: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<H>::new(key);
+    mac.update(message);
+    mac.finalize()
+}
+
+/// whether `a` and `b` are equal, looking at all their bytes whatever their differences
+///
+/// Only the lengths, which are public, are compared early.
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let difference = a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y));
+    std::hint::black_box(difference) == 0
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hex::{encode, Format};
+    use crate::sha3::*;
+
+    /// keys `00 01 02 ..` of `length` bytes, as in the NIST examples
+    fn key(length: usize) -> Vec<u8> {
+        (0..length).map(|i| i as u8).collect()
+    }
+
+    /// the HMAC-SHA3 examples of NIST, for keys shorter than, as long as, and longer than a block
+    fn check_nist<H: FixedHash>(tags: [&str; 3]) {
+        let keys = [
+            key(H::OUTPUT_BYTES),
+            key(H::BLOCK_BYTES),
+            key(H::BLOCK_BYTES + H::OUTPUT_BYTES),
+        ];
+        let messages = [
+            "Sample message for keylen<blocklen",
+            "Sample message for keylen=blocklen",
+            "Sample message for keylen>blocklen",
+        ];
+        for ((key, message), tag) in keys.iter().zip(messages).zip(tags) {
+            let computed = hmac::<H>(key, message.as_bytes());
+            assert_eq!(encode(&computed, Format::LOWER), tag, "{message}");
+        }
+    }
+
+    #[test]
+    fn nist_examples() {
+        check_nist::<Sha3_224>([
+            "332cfd59347fdb8e576e77260be4aba2d6dc53117b3bfb52c6d18c04",
+            "d8b733bcf66c644a12323d564e24dcf3fc75f231f3b67968359100c7",
+            "078695eecc227c636ad31d063a15dd05a7e819a66ec6d8de1e193e59",
+        ]);
+        check_nist::<Sha3_256>([
+            "4fe8e202c4f058e8dddc23d8c34e467343e23555e24fc2f025d598f558f67205",
+            "68b94e2e538a9be4103bebb5aa016d47961d4d1aa906061313b557f8af2c3faa",
+            "9bcf2c238e235c3ce88404e813bd2f3a97185ac6f238c63d6229a00b07974258",
+        ]);
+        check_nist::<Sha3_384>([
+            "d588a3c51f3f2d906e8298c1199aa8ff6296218127f6b38a90b6afe2c5617725\
+             bc99987f79b22a557b6520db710b7f42",
+            "a27d24b592e8c8cbf6d4ce6fc5bf62d8fc98bf2d486640d9eb8099e24047837f\
+             5f3bffbe92dcce90b4ed5b1e7e44fa90",
+            "e5ae4c739f455279368ebf36d4f5354c95aa184c899d3870e460ebc288ef1f94\
+             70053f73f7c6da2a71bcaec38ce7d6ac",
+        ]);
+        check_nist::<Sha3_512>([
+            "4efd629d6c71bf86162658f29943b1c308ce27cdfa6db0d9c3ce81763f9cbce5\
+             f7ebe9868031db1a8f8eb7b6b95e5c5e3f657a8996c86a2f6527e307f0213196",
+            "544e257ea2a3e5ea19a590e6a24b724ce6327757723fe2751b75bf007d80f6b3\
+             60744bf1b7a88ea585f9765b47911976d3191cf83c039f5ffab0d29cc9d9b6da",
+            "5f464f5e5b7848e3885e49b2c385f0694985d0e38966242dc4a5fe3fea4b37d4\
+             6b65ceced5dcf59438dd840bab22269f0ba7febdb9fcf74602a35666b2a32915",
+        ]);
+    }
+
+    #[test]
+    fn streaming_matches_one_shot() {
+        let message = [0x5A; 3 * 136 + 7];
+        let mut mac = Hmac::<Sha3_256>::new(b"key");
+        message.chunks(13).for_each(|data| mac.update(data));
+        assert_eq!(mac.finalize(), hmac::<Sha3_256>(b"key", &message));
+    }
+
+    #[test]
+    fn verify_tags() {
+        let tag = hmac::<Sha3_256>(b"key", b"message");
+        let mac = || {
+            let mut mac = Hmac::<Sha3_256>::new(b"key");
+            mac.update(b"message");
+            mac
+        };
+        assert!(mac().verify(&tag));
+        // a truncated tag is only accepted where one is expected
+        assert!(!mac().verify(&tag[..16]));
+        assert!(!mac().verify(&tag[..31]));
+        assert!(!mac().verify(&[]));
+        let mut forged = tag.clone();
+        forged[31] ^= 1;
+        assert!(!mac().verify(&forged));
+        assert!(!Hmac::<Sha3_256>::new(b"other key").verify(&tag));
+
+        assert!(mac().verify_truncated(&tag[..16], 16));
+        assert!(mac().verify_truncated(&tag, 32));
+        assert!(!mac().verify_truncated(&tag[..20], 16));
+        assert!(!mac().verify_truncated(&tag[..16], 20));
+        forged[..16].copy_from_slice(&tag[..16]);
+        forged[0] ^= 1;
+        assert!(!mac().verify_truncated(&forged[..16], 16));
+    }
+
+    #[test]
+    #[should_panic(expected = "from half to all")]
+    fn truncated_tags_keep_half() {
+        let tag = hmac::<Sha3_256>(b"key", b"message");
+        Hmac::<Sha3_256>::new(b"key").verify_truncated(&tag[..15], 15);
+    }
+}
@@ -0,0 +1,186 @@
+//! Absorption of files and of `stdin`
+//!
+//! [`absorb_file`] and [`absorb_stdin`] read their input in blocks through [`io::copy`], which
+//! stays sound whatever happens to the file meanwhile; the rest of the library, such as
+//! [`crate::dir`] and [`crate::parallel`], only uses them. With the `mmap` feature,
+//! [`absorb_file_mapped`] and [`absorb_stdin_mapped`] map a regular file in memory instead, and
+//! absorb it straight from the mapped pages, a whole block of
+//! [`RATE_BYTES`](crate::sponge::RATE_BYTES) at a time, without copying it into a buffer first.
+//! They are `unsafe`, as the map is only sound while nobody changes the file, which the caller has
+//! to ensure. Either way, the input is consumed as by a read to its end.
+//! ```
+//! use TD1::{input::absorb_file, sponge::Shake128};
+//!
+//! let mut hasher = Shake128::new();
+//! let size = absorb_file(&mut hasher, &mut std::fs::File::open("Cargo.toml").unwrap()).unwrap();
+//!
+//! let mut direct = Shake128::new();
+//! direct.update(&std::fs::read("Cargo.toml").unwrap());
+//! assert_eq!(size, std::fs::metadata("Cargo.toml").unwrap().len());
+//! assert_eq!(hasher.finalize().squeeze_vec(32), direct.finalize().squeeze_vec(32));
+//! ```
+use std::fs::File;
+use std::io;
+
+use crate::sponge::Shake128;
+
+/// absorbs what remains of `file` from its current position through a memory map, returning its
+/// number of bytes, or `None` if it cannot be mapped
+///
+/// # Safety
+///
+/// As for [`absorb_file_mapped`].
+#[cfg(feature = "mmap")]
+unsafe fn absorb_mapped(hasher: &mut Shake128, file: &mut File) -> io::Result<Option<u64>> {
+    use io::{Seek, SeekFrom};
+    use memmap2::MmapOptions;
+
+    let metadata = file.metadata()?;
+    if !metadata.is_file() {
+        return Ok(None);
+    }
+    let offset = file.stream_position()?;
+    let length = metadata.len().saturating_sub(offset);
+    let Ok(mapped_length) = usize::try_from(length) else {
+        return Ok(None);
+    };
+    // mapping nothing fails, and an empty regular file may still have content, as in `/proc`
+    if length == 0 {
+        return Ok(None);
+    }
+    // SAFETY: the caller ensures that nobody writes to or truncates the file until this returns,
+    // so the mapped bytes stay in place and unchanged while they are read as a `&[u8]`
+    let map = match unsafe {
+        MmapOptions::new()
+            .offset(offset)
+            .len(mapped_length)
+            .map(&*file)
+    } {
+        Ok(map) => map,
+        // some file systems cannot be mapped, and are read instead
+        Err(_) => return Ok(None),
+    };
+    #[cfg(unix)]
+    let _ = map.advise(memmap2::Advice::Sequential);
+    hasher.update(&map);
+    file.seek(SeekFrom::Current(length as i64))?;
+    Ok(Some(length))
+}
+
+/// absorbs what remains of `file` from its current position, returning its number of bytes
+pub fn absorb_file(hasher: &mut Shake128, file: &mut File) -> io::Result<u64> {
+    io::copy(file, hasher)
+}
+
+/// absorbs what remains of `file` like [`absorb_file`], through a memory map when it is a regular
+/// file that can be mapped
+///
+/// # Safety
+///
+/// Nobody may write to or truncate the file until this returns. The mapped bytes are read as a
+/// `&[u8]`, which must not change meanwhile, and reading pages cut off by a truncation ends the
+/// process with `SIGBUS`.
+#[cfg(feature = "mmap")]
+pub unsafe fn absorb_file_mapped(hasher: &mut Shake128, file: &mut File) -> io::Result<u64> {
+    // SAFETY: forwarded from the caller
+    if let Some(length) = unsafe { absorb_mapped(hasher, file)? } {
+        return Ok(length);
+    }
+    absorb_file(hasher, file)
+}
+
+/// `stdin` as a file, when the platform can tell which one it is
+#[cfg(feature = "mmap")]
+fn stdin_file() -> Option<File> {
+    #[cfg(unix)]
+    {
+        use std::os::fd::AsFd;
+        io::stdin()
+            .as_fd()
+            .try_clone_to_owned()
+            .ok()
+            .map(File::from)
+    }
+    #[cfg(windows)]
+    {
+        use std::os::windows::io::AsHandle;
+        io::stdin()
+            .as_handle()
+            .try_clone_to_owned()
+            .ok()
+            .map(File::from)
+    }
+    #[cfg(not(any(unix, windows)))]
+    None
+}
+
+/// absorbs `stdin` to its end, returning its number of bytes
+pub fn absorb_stdin(hasher: &mut Shake128) -> io::Result<u64> {
+    io::copy(&mut io::stdin().lock(), hasher)
+}
+
+/// absorbs `stdin` like [`absorb_stdin`], through a memory map when it is redirected from a
+/// regular file that can be mapped
+///
+/// # Safety
+///
+/// As for [`absorb_file_mapped`], on the file `stdin` is redirected from, if any.
+#[cfg(feature = "mmap")]
+pub unsafe fn absorb_stdin_mapped(hasher: &mut Shake128) -> io::Result<u64> {
+    if let Some(mut file) = stdin_file() {
+        // SAFETY: forwarded from the caller
+        return unsafe { absorb_file_mapped(hasher, &mut file) };
+    }
+    absorb_stdin(hasher)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::testing::TempDir;
+    use io::{Read, Seek, SeekFrom};
+
+    fn hash(data: &[u8]) -> Vec<u8> {
+        let mut hasher = Shake128::new();
+        hasher.update(data);
+        hasher.finalize().squeeze_vec(32)
+    }
+
+    /// the ways of absorbing a file, all of which must agree
+    fn absorbers() -> Vec<fn(&mut Shake128, &mut File) -> io::Result<u64>> {
+        vec![
+            absorb_file,
+            #[cfg(feature = "mmap")]
+            // SAFETY: the test files are only written before they are absorbed
+            |hasher, file| unsafe { absorb_file_mapped(hasher, file) },
+        ]
+    }
+
+    #[test]
+    fn files_hash_like_their_content() {
+        let dir = TempDir::new("input", "files");
+        for length in [0, 1, 167, 168, 169, 4096, 100_000] {
+            let data = (0..length)
+                .map(|i| (i * 31 % 251) as u8)
+                .collect::<Vec<_>>();
+            let path = dir.write("file", &data);
+
+            for absorb in absorbers() {
+                let mut hasher = Shake128::new();
+                let mut file = File::open(&path).unwrap();
+                assert_eq!(absorb(&mut hasher, &mut file).unwrap(), length as u64);
+                assert_eq!(hasher.finalize().squeeze_vec(32), hash(&data));
+                // the file is consumed, as by a read
+                assert_eq!(file.read(&mut [0; 1]).unwrap(), 0);
+
+                // only what remains after the current position is absorbed
+                let skipped = length / 3;
+                let mut file = File::open(&path).unwrap();
+                file.seek(SeekFrom::Start(skipped as u64)).unwrap();
+                let mut hasher = Shake128::new();
+                absorb(&mut hasher, &mut file).unwrap();
+                assert_eq!(hasher.finalize().squeeze_vec(32), hash(&data[skipped..]));
+            }
+        }
+    }
+}
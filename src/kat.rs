@@ -0,0 +1,363 @@
+//! NIST CAVP Known-Answer-Tests for SHAKE128
+//!
+//! [`parse_rsp`] reads a CAVP response file (`SHAKE128ShortMsg.rsp`, `SHAKE128LongMsg.rsp`,
+//! `SHAKE128VariableOut.rsp` or `SHAKE128Monte.rsp`) into its sections, and [`check_rsp`] runs every
+//! vector of it against [`Shake128`], including the Monte Carlo test of the SHA3VS.
+//!
+//! Only byte-oriented messages are supported, as the sponge does not absorb single bits.
+use std::fmt;
+
+use crate::{
+    error::ParseError,
+    hex::{self, Format},
+    sponge::Shake128,
+    Result, BITS_PER_BYTE,
+};
+
+/// `key = value` lines, in the order of the file
+pub type Record = Vec<(String, String)>;
+
+/// records following a header of `[key = value]` parameters
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Section {
+    /// line of the section in the file, starting from 1
+    pub line: usize,
+    pub params: Record,
+    pub records: Vec<Record>,
+}
+
+fn get<'a>(record: &'a Record, key: &str) -> Option<&'a str> {
+    record
+        .iter()
+        .find(|(name, _)| name == key)
+        .map(|(_, value)| value.as_str())
+}
+
+fn parse_hex(hex: &str) -> std::result::Result<Vec<u8>, String> {
+    hex::decode(hex, Format::LOWER).map_err(|error| error.to_string())
+}
+
+fn parse_bits(value: &str) -> std::result::Result<usize, String> {
+    value
+        .parse()
+        .map_err(|_| format!("`{value}` is not a number of bits"))
+}
+
+/// splits a `key = value` line, trimming both sides
+fn split_key_value(line: &str) -> Option<(&str, &str)> {
+    line.split_once('=')
+        .map(|(key, value)| (key.trim(), value.trim()))
+}
+
+/// the `key = value` lines of `text`, each with its number starting from 1
+///
+/// Unlike [`parse_rsp`], there are no sections, comments or blank lines: every line must be of
+/// this form, as in the proofs of [`crate::merkle`] and the manifests of [`crate::store`].
+/// ```
+/// # use TD1::kat::key_values;
+/// let lines = key_values("Size = 3\nChunk = ab 3\n").collect::<Result<Vec<_>, _>>().unwrap();
+/// assert_eq!(lines, [(1, "Size", "3"), (2, "Chunk", "ab 3")]);
+/// assert!(key_values("Size 3").next().unwrap().is_err());
+/// ```
+pub fn key_values(
+    text: &str,
+) -> impl Iterator<Item = std::result::Result<(usize, &str, &str), ParseError>> {
+    text.lines().enumerate().map(|(i, line)| {
+        split_key_value(line)
+            .map(|(key, value)| (i + 1, key, value))
+            .ok_or_else(|| ParseError {
+                line: i + 1,
+                message: format!("`{line}` is not of the form `key = value`"),
+            })
+    })
+}
+
+/// reads the sections of a CAVP response file; comments (`#`) are skipped and records are
+/// separated by blank lines
+/// ```
+/// # use TD1::kat::parse_rsp;
+/// let sections = parse_rsp("# SHAKE128\n[Outputlen = 128]\n\nLen = 0\nMsg = 00\n").unwrap();
+/// assert_eq!(sections[0].params, [("Outputlen".to_string(), "128".to_string())]);
+/// assert_eq!(sections[0].records[0][1], ("Msg".to_string(), "00".to_string()));
+/// ```
+pub fn parse_rsp(text: &str) -> Result<Vec<Section>> {
+    let mut sections: Vec<Section> = Vec::new();
+    // a header directly after another one belongs to the same section
+    let mut in_header = false;
+    let mut record = Record::new();
+
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        let error = |message| ParseError {
+            line: i + 1,
+            message,
+        };
+        if line.is_empty() || line.starts_with('#') {
+            if !record.is_empty() {
+                let section = sections.last_mut().unwrap();
+                section.records.push(std::mem::take(&mut record));
+            }
+            continue;
+        }
+
+        let header = line
+            .strip_prefix('[')
+            .and_then(|line| line.strip_suffix(']'));
+        let (key, value) = match (header, split_key_value(header.unwrap_or(line))) {
+            (_, Some((key, value))) => (key.to_string(), value.to_string()),
+            // headers such as `[Tested for Output of byte-oriented messages]` have no value
+            (Some(header), None) => (header.to_string(), String::new()),
+            (None, None) => {
+                return Err(error(format!("`{line}` is not of the form `key = value`")).into())
+            }
+        };
+
+        if header.is_some() {
+            if !in_header {
+                sections.push(Section {
+                    line: i + 1,
+                    ..Section::default()
+                });
+            }
+            sections.last_mut().unwrap().params.push((key, value));
+            in_header = true;
+        } else {
+            // records may also come before any header
+            if sections.is_empty() {
+                sections.push(Section {
+                    line: i + 1,
+                    ..Section::default()
+                });
+            }
+            record.push((key, value));
+            in_header = false;
+        }
+    }
+    if !record.is_empty() {
+        sections.last_mut().unwrap().records.push(record);
+    }
+    Ok(sections)
+}
+
+/// the SHA3VS Monte Carlo test of SHAKE: from `seed`, each of the `checkpoints` outputs is
+/// the 1000th iteration of hashing the first 16 bytes of the previous output, whose size is
+/// given by its last 2 bytes, between `min_bytes` and `max_bytes`
+///
+/// Panics unless `2 <= min_bytes <= max_bytes`.
+pub fn monte_carlo(
+    seed: &[u8],
+    min_bytes: usize,
+    max_bytes: usize,
+    checkpoints: usize,
+) -> Vec<Vec<u8>> {
+    const ITERATIONS: usize = 1000;
+    const MESSAGE_BYTES: usize = 16;
+
+    assert!(
+        2 <= min_bytes && min_bytes <= max_bytes,
+        "invalid output lengths {min_bytes}..={max_bytes} bytes"
+    );
+    let range = max_bytes - min_bytes + 1;
+    let mut output = seed.to_vec();
+    let mut output_size = max_bytes;
+    (0..checkpoints)
+        .map(|_| {
+            for _ in 0..ITERATIONS {
+                let mut message = [0; MESSAGE_BYTES];
+                let prefix = output.len().min(MESSAGE_BYTES);
+                message[..prefix].copy_from_slice(&output[..prefix]);
+
+                let mut hasher = Shake128::new();
+                hasher.update(&message);
+                output = hasher.finalize().squeeze_vec(output_size);
+
+                let rightmost = u16::from_be_bytes(output[output.len() - 2..].try_into().unwrap());
+                output_size = min_bytes + rightmost as usize % range;
+            }
+            output.clone()
+        })
+        .collect()
+}
+
+/// outcome of the vectors of a response file
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Report {
+    pub passed: usize,
+    /// description of every vector that did not give the expected output
+    pub failed: Vec<String>,
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} passed, {} failed", self.passed, self.failed.len())
+    }
+}
+
+impl Report {
+    fn check(&mut self, description: String, output: &[u8], expected: &[u8]) {
+        if output == expected {
+            self.passed += 1;
+        } else {
+            self.failed.push(description);
+        }
+    }
+}
+
+fn check_monte(section: &Section, report: &mut Report) -> std::result::Result<(), String> {
+    let bytes = |key| -> std::result::Result<usize, String> {
+        let bits = parse_bits(get(&section.params, key).ok_or(format!("no [{key}]"))?)?;
+        Ok(bits / BITS_PER_BYTE)
+    };
+    let min_bytes = bytes("Minimum Output Length (bits)")?;
+    let max_bytes = bytes("Maximum Output Length (bits)")?;
+    // the size of every output is read from its last 2 bytes
+    if min_bytes < 2 || min_bytes > max_bytes {
+        return Err(format!(
+            "the minimum output length must be at least 2 bytes and at most the maximum, not \
+             {min_bytes} and {max_bytes} bytes"
+        ));
+    }
+    let (seed, checkpoints) = section
+        .records
+        .split_first()
+        .ok_or("the Monte Carlo test has no seed")?;
+    let seed = parse_hex(get(seed, "Msg").ok_or("the Monte Carlo test has no seed")?)?;
+
+    let outputs = monte_carlo(&seed, min_bytes, max_bytes, checkpoints.len());
+    for (checkpoint, output) in checkpoints.iter().zip(outputs) {
+        let count = get(checkpoint, "COUNT").unwrap_or("?");
+        let expected = parse_hex(get(checkpoint, "Output").ok_or("a checkpoint has no Output")?)?;
+        report.check(format!("Monte COUNT = {count}"), &output, &expected);
+    }
+    Ok(())
+}
+
+fn check_hashes(section: &Section, report: &mut Report) -> std::result::Result<(), String> {
+    for record in &section.records {
+        let message = get(record, "Msg").ok_or("a vector has no Msg")?;
+        let expected = parse_hex(get(record, "Output").ok_or("a vector has no Output")?)?;
+        // the length is per vector in ShortMsg and LongMsg, and per section in VariableOut
+        let bits = get(record, "Len")
+            .or_else(|| get(&section.params, "Input Length"))
+            .ok_or("a vector has no Len")?;
+        let bits = parse_bits(bits)?;
+        if !bits.is_multiple_of(BITS_PER_BYTE) {
+            return Err(format!("messages of {bits} bits are not byte-oriented"));
+        }
+        // the empty message is written `00`
+        let message = parse_hex(message)?;
+        let message = message
+            .get(..bits / BITS_PER_BYTE)
+            .ok_or(format!("`Msg` is shorter than {bits} bits"))?;
+
+        // the output length is in bits, and may not be a whole number of bytes
+        let output_bits =
+            match get(record, "Outputlen").or_else(|| get(&section.params, "Outputlen")) {
+                Some(output_bits) => parse_bits(output_bits)?,
+                None => expected.len() * BITS_PER_BYTE,
+            };
+
+        let mut hasher = Shake128::new();
+        hasher.update(message);
+        let output = hasher.finalize().squeeze_bits(output_bits);
+        let description = match get(record, "COUNT") {
+            Some(count) => format!("COUNT = {count}"),
+            None => format!("Len = {bits}"),
+        };
+        report.check(description, &output, &expected);
+    }
+    Ok(())
+}
+
+/// runs every vector of a CAVP response file
+/// ```
+/// # use TD1::kat::check_rsp;
+/// let rsp = "[Outputlen = 128]\n\nLen = 0\nMsg = 00\nOutput = 7f9c2ba4e88f827d616045507605853e\n";
+/// let report = check_rsp(rsp).unwrap();
+/// assert_eq!((report.passed, report.failed.len()), (1, 0));
+/// ```
+pub fn check_rsp(text: &str) -> Result<Report> {
+    let mut report = Report::default();
+    for section in parse_rsp(text)? {
+        // only the Monte Carlo test gives a seed without an output
+        let monte = section
+            .records
+            .first()
+            .is_some_and(|record| get(record, "Output").is_none());
+        if monte {
+            check_monte(&section, &mut report)
+        } else {
+            check_hashes(&section, &mut report)
+        }
+        .map_err(|message| ParseError {
+            line: section.line,
+            message,
+        })?;
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Error;
+
+    #[test]
+    fn sections_and_records() {
+        let sections = parse_rsp(
+            "#  CAVS 19.0\r\n\r\n[Input Length = 128]\r\n[Minimum Output Length (bits) = 128]\r\n\r\n\
+             COUNT = 0\r\nOutputlen = 128\r\n\r\nCOUNT = 1\r\nOutputlen = 136\r\n",
+        )
+        .unwrap();
+        assert_eq!(sections.len(), 1);
+        assert_eq!(sections[0].params.len(), 2);
+        assert_eq!(sections[0].records.len(), 2);
+        assert_eq!(get(&sections[0].records[1], "Outputlen"), Some("136"));
+    }
+
+    #[test]
+    fn wrong_output_fails() {
+        let rsp =
+            "[Outputlen = 128]\n\nLen = 8\nMsg = 13\nOutput = 00000000000000000000000000000000\n";
+        let report = check_rsp(rsp).unwrap();
+        assert_eq!(report.passed, 0);
+        assert_eq!(report.failed, ["Len = 8"]);
+    }
+
+    #[test]
+    fn bit_outputs() {
+        // the first 12 bits of SHAKE128 of the empty message, 7F 9C
+        let rsp = "Len = 0\nMsg = 00\nOutputlen = 12\nOutput = 7f0c\n";
+        assert_eq!(check_rsp(rsp).unwrap().passed, 1);
+        let rsp = "Len = 0\nMsg = 00\nOutputlen = 12\nOutput = 7f9c\n";
+        assert_eq!(check_rsp(rsp).unwrap().failed.len(), 1);
+    }
+
+    #[test]
+    fn invalid_monte_lengths_are_rejected() {
+        for (min, max) in [(256, 128), (8, 8), (0, 128)] {
+            let rsp = format!(
+                "[Minimum Output Length (bits) = {min}]\n[Maximum Output Length (bits) = {max}]\n\n\
+                 Msg = c8b310cb97efa3855434998fa81c7674\n\nCOUNT = 0\nOutputlen = 128\n\
+                 Output = 00000000000000000000000000000000\n"
+            );
+            assert!(
+                matches!(
+                    check_rsp(&rsp),
+                    Err(Error::Parse(ParseError { line: 1, .. }))
+                ),
+                "{min} to {max} bits"
+            );
+        }
+    }
+
+    #[test]
+    fn bit_messages_are_rejected() {
+        let rsp =
+            "[Outputlen = 128]\n\nLen = 5\nMsg = 13\nOutput = 2e0abfba83e6720bfbc225ff6b7ab9ff\n";
+        assert!(matches!(
+            check_rsp(rsp),
+            Err(Error::Parse(ParseError { line: 1, .. }))
+        ));
+    }
+}
@@ -1,9 +1,13 @@
 //! Implements Keccak-$\rho$
 #![allow(dead_code)]
 
-use crate::STATE_SIZE;
+use crate::{
+    lane::Lane,
+    trace::{Observer, Step},
+    STATE_SIZE,
+};
 const ROW_SIZE: usize = 5;
-const N_ROUNDS: usize = 24;
+const N_ROUNDS: usize = rounds(u64::BITS);
 
 /// returns (x,y) coordinates of a lane denoted by i
 /// the convention taken is that i = x + [`ROW_SIZE`] * y (see page 9 of [https://csrc.nist.gov/files/pubs/fips/202/final/docs/fips_202_draft.pdf])
@@ -13,24 +17,14 @@ fn get_coordinates(i: usize) -> (usize, usize) {
 
 /// returns the lane corresponding to the (x,y) coordinates
 /// the convention taken is that i = x + [`ROW_SIZE`] * y (see page 9 of [https://csrc.nist.gov/files/pubs/fips/202/final/docs/fips_202_draft.pdf])
-fn from_coordinates(x: usize, y: usize) -> usize {
+const fn from_coordinates(x: usize, y: usize) -> usize {
     x + ROW_SIZE * y
 }
 
-fn theta(mut state: [u64; STATE_SIZE]) -> [u64; STATE_SIZE] {
-    #[cfg(test)]
-    state.map(|lane| {
-        eprint!(
-            "{lane:0>width$X} ",
-            width = crate::DIGITS_PER_BYTE * crate::BYTES_PER_CHUNK
-        )
-    });
-    #[cfg(test)]
-    eprintln!("");
-
-    let mut xored_plane = [0u64; ROW_SIZE];
+pub fn theta<L: Lane>(mut state: [L; STATE_SIZE]) -> [L; STATE_SIZE] {
+    let mut xored_plane = [L::ZERO; ROW_SIZE];
     for x in 0..ROW_SIZE {
-        xored_plane[x] = (0..ROW_SIZE).fold(0, |acc, t| acc ^ state[from_coordinates(x, t)]);
+        xored_plane[x] = (0..ROW_SIZE).fold(L::ZERO, |acc, t| acc ^ state[from_coordinates(x, t)]);
     }
     for x in 0..ROW_SIZE {
         for y in 0..ROW_SIZE {
@@ -41,52 +35,122 @@ fn theta(mut state: [u64; STATE_SIZE]) -> [u64; STATE_SIZE] {
     state
 }
 
-const RHO_OFFSET: [u32; STATE_SIZE] = [
-    0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56, 14,
-];
-fn rho(mut state: [u64; STATE_SIZE]) -> [u64; STATE_SIZE] {
-    #[cfg(test)]
-    state.map(|lane| {
-        eprint!(
-            "{lane:0>width$X} ",
-            width = crate::DIGITS_PER_BYTE * crate::BYTES_PER_CHUNK
-        )
-    });
-    #[cfg(test)]
-    eprintln!("");
+/// `plane` multiplied by x^`x_shift` z^`z_shift`, seeing a plane of column parities as a
+/// polynomial of GF(2)\[x, z\] / (x^5 - 1, z^w - 1)
+fn shift_plane<L: Lane>(plane: [L; ROW_SIZE], x_shift: usize, z_shift: u32) -> [L; ROW_SIZE] {
+    std::array::from_fn(|x| {
+        plane[(x + ROW_SIZE - x_shift % ROW_SIZE) % ROW_SIZE].rotate_left(z_shift % L::WIDTH)
+    })
+}
+
+/// inverse of [`theta`]
+///
+/// theta maps the column parities C to (1 + x + x^4 z) C. Since squaring is linear over GF(2),
+/// (1 + x + x^4 z)^256 = 1 + x + x^4, whose inverse is 1 + x^2 + x^3, so the parities are
+/// recovered by multiplying by (1 + x^2 + x^3) (1 + x + x^4 z)^255, which is dense.
+pub fn theta_inv<L: Lane>(mut state: [L; STATE_SIZE]) -> [L; STATE_SIZE] {
+    let mut parity = [L::ZERO; ROW_SIZE];
+    for x in 0..ROW_SIZE {
+        parity[x] = (0..ROW_SIZE).fold(L::ZERO, |acc, t| acc ^ state[from_coordinates(x, t)]);
+    }
+
+    // (1 + x + x^4 z)^255 is the product of the (1 + x + x^4 z)^(2^i) for i < 8
+    for i in 0..8 {
+        let shifted = shift_plane(parity, 1 << i, 0);
+        let rotated = shift_plane(parity, 4 << i, 1 << i);
+        for x in 0..ROW_SIZE {
+            parity[x] ^= shifted[x] ^ rotated[x];
+        }
+    }
+    let (squared, cubed) = (shift_plane(parity, 2, 0), shift_plane(parity, 3, 0));
+    for x in 0..ROW_SIZE {
+        parity[x] ^= squared[x] ^ cubed[x];
+    }
+
+    let (previous, next) = (shift_plane(parity, 1, 0), shift_plane(parity, 4, 1));
+    for x in 0..ROW_SIZE {
+        for y in 0..ROW_SIZE {
+            state[from_coordinates(x, y)] ^= previous[x] ^ next[x];
+        }
+    }
+    state
+}
+
+/// whether lanes of `width` bits make a Keccak-f permutation: 1, 2, 4, 8, 16, 32 or 64
+pub const fn is_lane_width(width: u32) -> bool {
+    width.is_power_of_two() && width <= u64::BITS
+}
+
+/// number of rounds of Keccak-f for lanes of `width` bits, that is 12 + 2ℓ with `width` = 2^ℓ
+///
+/// Panics if `width` is not a [lane width](is_lane_width).
+pub const fn rounds(width: u32) -> usize {
+    assert!(is_lane_width(width), "not a lane width");
+    12 + 2 * width.trailing_zeros() as usize
+}
+
+/// offsets of the lanes in rho, for lanes of `width` bits: the lane reached after t steps of the
+/// walk (x, y) → (y, 2x + 3y) starting from (1, 0) is rotated by (t + 1)(t + 2) / 2 mod `width`
+/// ```
+/// # use TD1::keccak::rho_offsets;
+/// assert_eq!(rho_offsets(64)[..5], [0, 1, 62, 28, 27]);
+/// assert_eq!(rho_offsets(8)[..5], [0, 1, 6, 4, 3]);
+/// ```
+///
+/// Panics if `width` is not a [lane width](is_lane_width).
+pub const fn rho_offsets(width: u32) -> [u32; STATE_SIZE] {
+    assert!(is_lane_width(width), "not a lane width");
+    let mut offsets = [0; STATE_SIZE];
+    let (mut x, mut y) = (1, 0);
+    let mut t = 0;
+    while t < 24 {
+        offsets[from_coordinates(x, y)] = ((t + 1) * (t + 2) / 2) % width;
+        (x, y) = (y, (2 * x + 3 * y) % ROW_SIZE);
+        t += 1;
+    }
+    offsets
+}
 
+/// narrower lanes use these offsets modulo their width
+const RHO_OFFSET: [u32; STATE_SIZE] = rho_offsets(u64::BITS);
+pub fn rho<L: Lane>(mut state: [L; STATE_SIZE]) -> [L; STATE_SIZE] {
     for i in 0..STATE_SIZE {
-        state[i] = state[i].rotate_left(RHO_OFFSET[i]);
+        state[i] = state[i].rotate_left(RHO_OFFSET[i] % L::WIDTH);
     }
     state
 }
 
-fn pi(state: [u64; STATE_SIZE]) -> [u64; STATE_SIZE] {
-    #[cfg(test)]
-    state.map(|lane| {
-        eprint!(
-            "{lane:0>width$X} ",
-            width = crate::DIGITS_PER_BYTE * crate::BYTES_PER_CHUNK
-        )
-    });
-    #[cfg(test)]
-    eprintln!("");
+/// inverse of [`rho`]
+pub fn rho_inv<L: Lane>(mut state: [L; STATE_SIZE]) -> [L; STATE_SIZE] {
+    for i in 0..STATE_SIZE {
+        state[i] = state[i].rotate_left(L::WIDTH - RHO_OFFSET[i] % L::WIDTH);
+    }
+    state
+}
 
-    let mut cp = state.clone();
+pub fn pi<L: Lane>(state: [L; STATE_SIZE]) -> [L; STATE_SIZE] {
+    let mut cp = state;
     for x in 0..ROW_SIZE {
         for y in 0..ROW_SIZE {
             cp[from_coordinates(x, y)] = state[from_coordinates((x + 3 * y) % ROW_SIZE, x)];
-            #[cfg(test)]
-            //let (u, v) = get_coordinates(from_coordinates((x + 3 * y) % ROW_SIZE, x));
-            let (u, v) = ((x + 3 * y) % ROW_SIZE, x);
-            #[cfg(test)]
-            eprintln!("({x}, {y}) was mapped to ({u},{v})")
         }
     }
     cp
 }
-fn chi(state: [u64; STATE_SIZE]) -> [u64; STATE_SIZE] {
-    let mut cp = state.clone();
+
+/// inverse of [`pi`]
+pub fn pi_inv<L: Lane>(state: [L; STATE_SIZE]) -> [L; STATE_SIZE] {
+    let mut cp = state;
+    for x in 0..ROW_SIZE {
+        for y in 0..ROW_SIZE {
+            cp[from_coordinates((x + 3 * y) % ROW_SIZE, x)] = state[from_coordinates(x, y)];
+        }
+    }
+    cp
+}
+
+pub fn chi<L: Lane>(state: [L; STATE_SIZE]) -> [L; STATE_SIZE] {
+    let mut cp = state;
     for x in 0..ROW_SIZE {
         for y in 0..ROW_SIZE {
             cp[from_coordinates(x, y)] ^= !state[from_coordinates((x + 1) % ROW_SIZE, y)]
@@ -96,50 +160,173 @@ fn chi(state: [u64; STATE_SIZE]) -> [u64; STATE_SIZE] {
     cp
 }
 
-const IOTA_CONSTANT: [u64; N_ROUNDS] = [
-    0x0000000000000001,
-    0x0000000000008082,
-    0x800000000000808A,
-    0x8000000080008000,
-    0x000000000000808B,
-    0x0000000080000001,
-    0x8000000080008081,
-    0x8000000000008009,
-    0x000000000000008A,
-    0x0000000000000088,
-    0x0000000080008009,
-    0x000000008000000A,
-    0x000000008000808B,
-    0x800000000000008B,
-    0x8000000000008089,
-    0x8000000000008003,
-    0x8000000000008002,
-    0x8000000000000080,
-    0x000000000000800A,
-    0x800000008000000A,
-    0x8000000080008081,
-    0x8000000000008080,
-    0x0000000080000001,
-    0x8000000080008008,
-];
-fn iota(mut state: [u64; STATE_SIZE], round: usize) -> [u64; STATE_SIZE] {
-    state[0] ^= IOTA_CONSTANT[round];
+/// inverse of [`chi`], which is a permutation of each 5-bit row:
+/// a\[x\] = b\[x\] ^ !b\[x + 1\] & (b\[x + 2\] ^ !b\[x + 3\] & b\[x + 4\])
+pub fn chi_inv<L: Lane>(state: [L; STATE_SIZE]) -> [L; STATE_SIZE] {
+    let mut cp = state;
+    for x in 0..ROW_SIZE {
+        for y in 0..ROW_SIZE {
+            let lane = |offset: usize| state[from_coordinates((x + offset) % ROW_SIZE, y)];
+            cp[from_coordinates(x, y)] ^= !lane(1) & (lane(2) ^ (!lane(3) & lane(4)));
+        }
+    }
+    cp
+}
+
+/// output bit of the LFSR x^8 + x^6 + x^5 + x^4 + 1 after `t` steps, starting from 1
+/// ```
+/// # use TD1::keccak::rc;
+/// assert!(rc(0));
+/// assert_eq!((1..=8).filter(|&t| rc(t)).collect::<Vec<_>>(), [8]);
+/// assert_eq!(rc(255), rc(0));
+/// ```
+pub const fn rc(t: usize) -> bool {
+    let mut register: u16 = 1;
+    let mut i = 0;
+    while i < t % 255 {
+        register <<= 1;
+        if register & 0x100 != 0 {
+            register ^= 0x171;
+        }
+        i += 1;
+    }
+    register & 1 == 1
+}
+
+/// round constant of round index `round`: bit 2^j - 1 is given by rc(j + 7 `round`)
+///
+/// Keccak-p with more rounds than Keccak-f starts from negative round indices, which are taken
+/// modulo the period of [`rc`].
+/// ```
+/// # use TD1::keccak::round_constant;
+/// assert_eq!(round_constant(1), 0x0000000000008082);
+/// assert_eq!(round_constant(23), 0x8000000080008008);
+/// assert_eq!(round_constant(-1), round_constant(254));
+/// ```
+pub const fn round_constant(round: i64) -> u64 {
+    let mut constant = 0;
+    let mut j = 0;
+    while j <= 6 {
+        if rc((j + 7 * round).rem_euclid(255) as usize) {
+            constant |= 1 << ((1 << j) - 1);
+        }
+        j += 1;
+    }
+    constant
+}
+
+/// round constants of Keccak-p\[25 `width`, `n_rounds`\], truncated to `width` bits
+/// ```
+/// # use TD1::keccak::round_constants;
+/// assert_eq!(round_constants(8, 18)[..4], [0x01, 0x82, 0x8A, 0x00]);
+/// // Keccak-p[1600, 12], as in KangarooTwelve and TurboSHAKE, uses the last 12 constants
+/// assert_eq!(round_constants(64, 12)[0], 0x000000008000808B);
+/// ```
+///
+/// Panics if `width` is not a [lane width](is_lane_width).
+pub fn round_constants(width: u32, n_rounds: usize) -> Vec<u64> {
+    let last = rounds(width) as i64;
+    (last - n_rounds as i64..last)
+        .map(|round| round_constant(round) & (u64::MAX >> (u64::BITS - width)))
+        .collect()
+}
+
+const fn keccak_f1600_round_constants() -> [u64; N_ROUNDS] {
+    let mut constants = [0; N_ROUNDS];
+    let mut round = 0;
+    while round < N_ROUNDS {
+        constants[round] = round_constant(round as i64);
+        round += 1;
+    }
+    constants
+}
+
+/// the round constants of narrower lanes are these, truncated to the lane width
+const IOTA_CONSTANT: [u64; N_ROUNDS] = keccak_f1600_round_constants();
+pub fn iota<L: Lane>(mut state: [L; STATE_SIZE], round: i64) -> [L; STATE_SIZE] {
+    state[0] ^= L::truncate(match usize::try_from(round) {
+        Ok(round) if round < N_ROUNDS => IOTA_CONSTANT[round],
+        _ => round_constant(round),
+    });
     state
 }
 
-pub fn shake128(mut state: [u64; STATE_SIZE]) -> [u64; STATE_SIZE] {
-    for round in 0..N_ROUNDS {
-        state = iota(chi(pi(rho(theta(state)))), round)
+/// inverse of [`iota`], which is an involution
+pub fn iota_inv<L: Lane>(state: [L; STATE_SIZE], round: i64) -> [L; STATE_SIZE] {
+    iota(state, round)
+}
+
+/// Keccak-p\[25 w, `n_rounds`\] permutation: the last `n_rounds` rounds of Keccak-f\[25 w\], or
+/// extra rounds before them when `n_rounds` is larger than [`rounds`]
+pub fn keccak_p<L: Lane>(state: [L; STATE_SIZE], n_rounds: usize) -> [L; STATE_SIZE] {
+    keccak_p_observed(state, n_rounds, &mut ())
+}
+
+/// [`keccak_p`], reporting every round and step to `observer`
+pub fn keccak_p_observed<L: Lane>(
+    mut state: [L; STATE_SIZE],
+    n_rounds: usize,
+    observer: &mut impl Observer<L>,
+) -> [L; STATE_SIZE] {
+    let last = rounds(L::WIDTH) as i64;
+    for round in last - n_rounds as i64..last {
+        observer.round(round, &state);
+        state = theta(state);
+        observer.step(round, Step::Theta, &state);
+        state = rho(state);
+        observer.step(round, Step::Rho, &state);
+        state = pi(state);
+        observer.step(round, Step::Pi, &state);
+        state = chi(state);
+        observer.step(round, Step::Chi, &state);
+        state = iota(state, round);
+        observer.step(round, Step::Iota, &state);
+    }
+    state
+}
+
+/// Keccak-f\[25 w\] permutation, for any lane of w bits (see [`crate::lane`])
+pub fn keccak_f<L: Lane>(state: [L; STATE_SIZE]) -> [L; STATE_SIZE] {
+    keccak_p(state, rounds(L::WIDTH))
+}
+
+/// inverse of [`keccak_p`]
+pub fn keccak_p_inv<L: Lane>(mut state: [L; STATE_SIZE], n_rounds: usize) -> [L; STATE_SIZE] {
+    let last = rounds(L::WIDTH) as i64;
+    for round in (last - n_rounds as i64..last).rev() {
+        state = theta_inv(rho_inv(pi_inv(chi_inv(iota_inv(state, round)))))
     }
     state
 }
 
+/// inverse of [`keccak_f`]
+pub fn keccak_f_inv<L: Lane>(state: [L; STATE_SIZE]) -> [L; STATE_SIZE] {
+    keccak_p_inv(state, rounds(L::WIDTH))
+}
+
+pub fn shake128(state: [u64; STATE_SIZE]) -> [u64; STATE_SIZE] {
+    keccak_f(state)
+}
+
+/// [`shake128`], reporting every round and step to `observer`
+pub fn shake128_observed(
+    state: [u64; STATE_SIZE],
+    observer: &mut impl Observer<u64>,
+) -> [u64; STATE_SIZE] {
+    keccak_p_observed(state, N_ROUNDS, observer)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::hex::{encode_lanes, Format, Order};
+    use crate::lane::{Bit1, Bit2, Bit4};
     use crate::*;
     use paste::paste;
 
+    /// lanes as printed by the step tests
+    const LANES: Format<'static> = Format::UPPER.grouped(" ", BYTES_PER_CHUNK);
+
     /// credit to Florian Chivé for the testing framework
     /// Macro to test a permutation function over a given input and expected output.
     macro_rules! test_permut {
@@ -150,22 +337,10 @@ mod test {
                 #[doc = "Test that " $function " applied to " $previous " gives " $after]
                 fn [<$previous _ $after>]() {
                     eprintln!("Before permutation");
-                    $after.map(|lane| {
-                        eprint!(
-                            "{lane:0>width$X} ",
-                            width = DIGITS_PER_BYTE * BYTES_PER_CHUNK
-                        )
-                    });
-                    eprintln!("");
+                    eprintln!("{}", encode_lanes(&$previous, Order::Lanes, LANES));
 
                     eprintln!("Expected");
-                    $after.map(|lane| {
-                        eprint!(
-                            "{lane:0>width$X} ",
-                            width = DIGITS_PER_BYTE * BYTES_PER_CHUNK
-                        )
-                    });
-                    eprintln!("");
+                    eprintln!("{}", encode_lanes(&$after, Order::Lanes, LANES));
 
                     eprintln!("After permutation");
                     assert_eq!($after, $function($previous));
@@ -182,22 +357,10 @@ mod test {
                 #[doc = "Test that " $function " applied to " $previous " gives " $after]
                 fn [<$previous _ $after>]() {
                     eprintln!("Before permutation");
-                    $after.map(|lane| {
-                        eprint!(
-                            "{lane:0>width$X} ",
-                            width = DIGITS_PER_BYTE * BYTES_PER_CHUNK
-                        )
-                    });
-                    eprintln!("");
+                    eprintln!("{}", encode_lanes(&$previous, Order::Lanes, LANES));
 
                     eprintln!("Expected");
-                    $after.map(|lane| {
-                        eprint!(
-                            "{lane:0>width$X} ",
-                            width = DIGITS_PER_BYTE * BYTES_PER_CHUNK
-                        )
-                    });
-                    eprintln!("");
+                    eprintln!("{}", encode_lanes(&$after, Order::Lanes, LANES));
 
                     eprintln!("After permutation");
                     assert_eq!($after, $function($previous,$round));
@@ -333,6 +496,411 @@ mod test {
 
     test_permut!(shake128, START, IOTA_23);
 
+    test_permut!(theta_inv, THETA_0, START);
+    test_permut!(theta_inv, THETA_1, IOTA_0);
+    test_permut!(theta_inv, THETA_2, IOTA_1);
+    test_permut!(theta_inv, THETA_3, IOTA_2);
+    test_permut!(theta_inv, THETA_4, IOTA_3);
+    test_permut!(theta_inv, THETA_5, IOTA_4);
+    test_permut!(theta_inv, THETA_6, IOTA_5);
+    test_permut!(theta_inv, THETA_7, IOTA_6);
+    test_permut!(theta_inv, THETA_8, IOTA_7);
+    test_permut!(theta_inv, THETA_9, IOTA_8);
+    test_permut!(theta_inv, THETA_10, IOTA_9);
+    test_permut!(theta_inv, THETA_11, IOTA_10);
+    test_permut!(theta_inv, THETA_12, IOTA_11);
+    test_permut!(theta_inv, THETA_13, IOTA_12);
+    test_permut!(theta_inv, THETA_14, IOTA_13);
+    test_permut!(theta_inv, THETA_15, IOTA_14);
+    test_permut!(theta_inv, THETA_16, IOTA_15);
+    test_permut!(theta_inv, THETA_17, IOTA_16);
+    test_permut!(theta_inv, THETA_18, IOTA_17);
+    test_permut!(theta_inv, THETA_19, IOTA_18);
+    test_permut!(theta_inv, THETA_20, IOTA_19);
+    test_permut!(theta_inv, THETA_21, IOTA_20);
+    test_permut!(theta_inv, THETA_22, IOTA_21);
+    test_permut!(theta_inv, THETA_23, IOTA_22);
+
+    test_permut!(rho_inv, RHO_0, THETA_0);
+    test_permut!(rho_inv, RHO_1, THETA_1);
+    test_permut!(rho_inv, RHO_2, THETA_2);
+    test_permut!(rho_inv, RHO_3, THETA_3);
+    test_permut!(rho_inv, RHO_4, THETA_4);
+    test_permut!(rho_inv, RHO_5, THETA_5);
+    test_permut!(rho_inv, RHO_6, THETA_6);
+    test_permut!(rho_inv, RHO_7, THETA_7);
+    test_permut!(rho_inv, RHO_8, THETA_8);
+    test_permut!(rho_inv, RHO_9, THETA_9);
+    test_permut!(rho_inv, RHO_10, THETA_10);
+    test_permut!(rho_inv, RHO_11, THETA_11);
+    test_permut!(rho_inv, RHO_12, THETA_12);
+    test_permut!(rho_inv, RHO_13, THETA_13);
+    test_permut!(rho_inv, RHO_14, THETA_14);
+    test_permut!(rho_inv, RHO_15, THETA_15);
+    test_permut!(rho_inv, RHO_16, THETA_16);
+    test_permut!(rho_inv, RHO_17, THETA_17);
+    test_permut!(rho_inv, RHO_18, THETA_18);
+    test_permut!(rho_inv, RHO_19, THETA_19);
+    test_permut!(rho_inv, RHO_20, THETA_20);
+    test_permut!(rho_inv, RHO_21, THETA_21);
+    test_permut!(rho_inv, RHO_22, THETA_22);
+    test_permut!(rho_inv, RHO_23, THETA_23);
+
+    test_permut!(pi_inv, PI_0, RHO_0);
+    test_permut!(pi_inv, PI_1, RHO_1);
+    test_permut!(pi_inv, PI_2, RHO_2);
+    test_permut!(pi_inv, PI_3, RHO_3);
+    test_permut!(pi_inv, PI_4, RHO_4);
+    test_permut!(pi_inv, PI_5, RHO_5);
+    test_permut!(pi_inv, PI_6, RHO_6);
+    test_permut!(pi_inv, PI_7, RHO_7);
+    test_permut!(pi_inv, PI_8, RHO_8);
+    test_permut!(pi_inv, PI_9, RHO_9);
+    test_permut!(pi_inv, PI_10, RHO_10);
+    test_permut!(pi_inv, PI_11, RHO_11);
+    test_permut!(pi_inv, PI_12, RHO_12);
+    test_permut!(pi_inv, PI_13, RHO_13);
+    test_permut!(pi_inv, PI_14, RHO_14);
+    test_permut!(pi_inv, PI_15, RHO_15);
+    test_permut!(pi_inv, PI_16, RHO_16);
+    test_permut!(pi_inv, PI_17, RHO_17);
+    test_permut!(pi_inv, PI_18, RHO_18);
+    test_permut!(pi_inv, PI_19, RHO_19);
+    test_permut!(pi_inv, PI_20, RHO_20);
+    test_permut!(pi_inv, PI_21, RHO_21);
+    test_permut!(pi_inv, PI_22, RHO_22);
+    test_permut!(pi_inv, PI_23, RHO_23);
+
+    test_permut!(chi_inv, CHI_0, PI_0);
+    test_permut!(chi_inv, CHI_1, PI_1);
+    test_permut!(chi_inv, CHI_2, PI_2);
+    test_permut!(chi_inv, CHI_3, PI_3);
+    test_permut!(chi_inv, CHI_4, PI_4);
+    test_permut!(chi_inv, CHI_5, PI_5);
+    test_permut!(chi_inv, CHI_6, PI_6);
+    test_permut!(chi_inv, CHI_7, PI_7);
+    test_permut!(chi_inv, CHI_8, PI_8);
+    test_permut!(chi_inv, CHI_9, PI_9);
+    test_permut!(chi_inv, CHI_10, PI_10);
+    test_permut!(chi_inv, CHI_11, PI_11);
+    test_permut!(chi_inv, CHI_12, PI_12);
+    test_permut!(chi_inv, CHI_13, PI_13);
+    test_permut!(chi_inv, CHI_14, PI_14);
+    test_permut!(chi_inv, CHI_15, PI_15);
+    test_permut!(chi_inv, CHI_16, PI_16);
+    test_permut!(chi_inv, CHI_17, PI_17);
+    test_permut!(chi_inv, CHI_18, PI_18);
+    test_permut!(chi_inv, CHI_19, PI_19);
+    test_permut!(chi_inv, CHI_20, PI_20);
+    test_permut!(chi_inv, CHI_21, PI_21);
+    test_permut!(chi_inv, CHI_22, PI_22);
+    test_permut!(chi_inv, CHI_23, PI_23);
+
+    test_permut_round!(iota_inv, 0, IOTA_0, CHI_0);
+    test_permut_round!(iota_inv, 1, IOTA_1, CHI_1);
+    test_permut_round!(iota_inv, 2, IOTA_2, CHI_2);
+    test_permut_round!(iota_inv, 3, IOTA_3, CHI_3);
+    test_permut_round!(iota_inv, 4, IOTA_4, CHI_4);
+    test_permut_round!(iota_inv, 5, IOTA_5, CHI_5);
+    test_permut_round!(iota_inv, 6, IOTA_6, CHI_6);
+    test_permut_round!(iota_inv, 7, IOTA_7, CHI_7);
+    test_permut_round!(iota_inv, 8, IOTA_8, CHI_8);
+    test_permut_round!(iota_inv, 9, IOTA_9, CHI_9);
+    test_permut_round!(iota_inv, 10, IOTA_10, CHI_10);
+    test_permut_round!(iota_inv, 11, IOTA_11, CHI_11);
+    test_permut_round!(iota_inv, 12, IOTA_12, CHI_12);
+    test_permut_round!(iota_inv, 13, IOTA_13, CHI_13);
+    test_permut_round!(iota_inv, 14, IOTA_14, CHI_14);
+    test_permut_round!(iota_inv, 15, IOTA_15, CHI_15);
+    test_permut_round!(iota_inv, 16, IOTA_16, CHI_16);
+    test_permut_round!(iota_inv, 17, IOTA_17, CHI_17);
+    test_permut_round!(iota_inv, 18, IOTA_18, CHI_18);
+    test_permut_round!(iota_inv, 19, IOTA_19, CHI_19);
+    test_permut_round!(iota_inv, 20, IOTA_20, CHI_20);
+    test_permut_round!(iota_inv, 21, IOTA_21, CHI_21);
+    test_permut_round!(iota_inv, 22, IOTA_22, CHI_22);
+    test_permut_round!(iota_inv, 23, IOTA_23, CHI_23);
+
+    test_permut!(keccak_f_inv, IOTA_23, START);
+
+    /// SplitMix64, to draw reproducible pseudo-random states
+    fn next_random(seed: &mut u64) -> u64 {
+        *seed = seed.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = *seed;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// checks that every inverse step undoes its step on pseudo-random states
+    fn inverses_are_identity<L: Lane>() {
+        let mut seed = L::WIDTH as u64;
+        for _ in 0..64 {
+            let state: [L; STATE_SIZE] =
+                std::array::from_fn(|_| L::truncate(next_random(&mut seed)));
+            assert_eq!(theta_inv(theta(state)), state);
+            assert_eq!(theta(theta_inv(state)), state);
+            assert_eq!(rho_inv(rho(state)), state);
+            assert_eq!(pi_inv(pi(state)), state);
+            assert_eq!(chi_inv(chi(state)), state);
+            assert_eq!(chi(chi_inv(state)), state);
+            assert_eq!(iota_inv(iota(state, 5), 5), state);
+            assert_eq!(keccak_f_inv(keccak_f(state)), state);
+            assert_eq!(keccak_p_inv(keccak_p(state, 30), 30), state);
+        }
+    }
+
+    #[test]
+    fn random_inverses() {
+        inverses_are_identity::<Bit1>();
+        inverses_are_identity::<Bit2>();
+        inverses_are_identity::<Bit4>();
+        inverses_are_identity::<u8>();
+        inverses_are_identity::<u16>();
+        inverses_are_identity::<u32>();
+        inverses_are_identity::<u64>();
+    }
+
+    /// rotation offsets as tabulated in FIPS 202 (table 2), in lane order
+    const FIPS_202_RHO_OFFSET: [u32; STATE_SIZE] = [
+        0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56,
+        14,
+    ];
+
+    /// round constants of Keccak-f[1600] as tabulated in the Keccak reference
+    const REFERENCE_IOTA_CONSTANT: [u64; N_ROUNDS] = [
+        0x0000000000000001,
+        0x0000000000008082,
+        0x800000000000808A,
+        0x8000000080008000,
+        0x000000000000808B,
+        0x0000000080000001,
+        0x8000000080008081,
+        0x8000000000008009,
+        0x000000000000008A,
+        0x0000000000000088,
+        0x0000000080008009,
+        0x000000008000000A,
+        0x000000008000808B,
+        0x800000000000008B,
+        0x8000000000008089,
+        0x8000000000008003,
+        0x8000000000008002,
+        0x8000000000000080,
+        0x000000000000800A,
+        0x800000008000000A,
+        0x8000000080008081,
+        0x8000000000008080,
+        0x0000000080000001,
+        0x8000000080008008,
+    ];
+
+    #[test]
+    fn rho_offsets_match_table() {
+        assert_eq!(RHO_OFFSET, FIPS_202_RHO_OFFSET);
+        for width in [1, 2, 4, 8, 16, 32] {
+            assert_eq!(
+                rho_offsets(width),
+                FIPS_202_RHO_OFFSET.map(|offset| offset % width)
+            );
+        }
+    }
+
+    #[test]
+    fn round_constants_match_table() {
+        assert_eq!(IOTA_CONSTANT, REFERENCE_IOTA_CONSTANT);
+        assert_eq!(round_constants(64, N_ROUNDS), REFERENCE_IOTA_CONSTANT);
+        assert_eq!(
+            round_constants(16, 20),
+            REFERENCE_IOTA_CONSTANT[..20]
+                .iter()
+                .map(|constant| constant & 0xFFFF)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(round_constants(64, 12), REFERENCE_IOTA_CONSTANT[12..]);
+    }
+
+    #[test]
+    fn lane_widths() {
+        let widths = (0..=128).filter(|&width| is_lane_width(width));
+        assert!(widths.eq([1, 2, 4, 8, 16, 32, 64]));
+        for width in [0, 3, 48, 128] {
+            assert!(std::panic::catch_unwind(|| rho_offsets(width)).is_err());
+            assert!(std::panic::catch_unwind(|| round_constants(width, 12)).is_err());
+        }
+    }
+
+    #[test]
+    fn round_constants_are_periodic() {
+        for round in -300..300 {
+            assert_eq!(round_constant(round), round_constant(round + 255));
+        }
+        assert_eq!(round_constants(64, 26)[..2], [-2, -1].map(round_constant));
+        assert_eq!(round_constants(64, 26)[2..], REFERENCE_IOTA_CONSTANT);
+    }
+
+    #[test]
+    fn keccak_p_rounds() {
+        let state = [0x0123456789ABCDEFu64; STATE_SIZE];
+        assert_eq!(keccak_p(state, 0), state);
+        assert_eq!(keccak_p(state, N_ROUNDS), keccak_f(state));
+        // the extra rounds of Keccak-p[1600, 26] come before the usual 24
+        let extra = (-2..0).fold(state, |state, round| {
+            iota(chi(pi(rho(theta(state)))), round)
+        });
+        assert_eq!(keccak_p(state, 26), keccak_f(extra));
+    }
+
+    /// applies Keccak-f twice to the zero state, checking the state after each permutation
+    fn zero_state_twice<L: Lane>(first: [L; STATE_SIZE], second: [L; STATE_SIZE]) {
+        let state = keccak_f([L::ZERO; STATE_SIZE]);
+        assert_eq!(state, first);
+        assert_eq!(keccak_f(state), second);
+    }
+
+    #[test]
+    fn round_counts() {
+        assert_eq!(
+            [1, 2, 4, 8, 16, 32, 64].map(rounds),
+            [12, 14, 16, 18, 20, 22, 24]
+        );
+    }
+
+    // Keccak-f[25], Keccak-f[50] and Keccak-f[100] are only checked against themselves: these
+    // vectors are outputs of this implementation (agreeing with a throwaway bit-level transcription
+    // of FIPS 202 section 3.2 that is not in the repository), not published KeccakTools vectors.
+    // They catch regressions, but do not show conformance; the widths from 200 bits on are checked
+    // against XKCP below.
+    #[test]
+    fn keccak_f25() {
+        zero_state_twice(
+            [
+                0, 0, 1, 1, 0, 1, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 1, 0, 1, 0,
+            ]
+            .map(Bit1::new),
+            [
+                0, 0, 1, 1, 1, 1, 0, 0, 1, 0, 1, 0, 0, 1, 1, 1, 1, 1, 1, 0, 1, 0, 1, 1, 1,
+            ]
+            .map(Bit1::new),
+        );
+    }
+
+    #[test]
+    fn keccak_f50() {
+        zero_state_twice(
+            [
+                0, 2, 3, 1, 1, 1, 0, 3, 3, 3, 1, 1, 1, 3, 1, 0, 1, 3, 2, 0, 3, 0, 1, 0, 2,
+            ]
+            .map(Bit2::new),
+            [
+                1, 0, 2, 3, 1, 2, 3, 0, 1, 2, 3, 1, 1, 2, 2, 3, 1, 0, 2, 3, 3, 1, 0, 2, 1,
+            ]
+            .map(Bit2::new),
+        );
+    }
+
+    #[test]
+    fn keccak_f100() {
+        zero_state_twice(
+            [
+                0x6, 0x6, 0x5, 0xC, 0xD, 0xE, 0xB, 0xA, 0xD, 0x6, 0x2, 0xF, 0x0, 0x2, 0x8, 0x5,
+                0x0, 0xD, 0x7, 0x7, 0xE, 0xA, 0xA, 0x0, 0x1,
+            ]
+            .map(Bit4::new),
+            [
+                0x2, 0x5, 0x5, 0x5, 0x1, 0xC, 0x2, 0x8, 0xE, 0x7, 0xB, 0xE, 0xA, 0xA, 0x9, 0x1,
+                0xD, 0x2, 0x4, 0x2, 0x6, 0x7, 0xD, 0x6, 0x2,
+            ]
+            .map(Bit4::new),
+        );
+    }
+
+    // https://github.com/XKCP/XKCP/blob/master/tests/TestVectors/KeccakF-200-IntermediateValues.txt
+    #[test]
+    fn keccak_f200() {
+        zero_state_twice::<u8>(
+            [
+                0x3C, 0x28, 0x26, 0x84, 0x1C, 0xB3, 0x5C, 0x17, 0x1E, 0xAA, 0xE9, 0xB8, 0x11, 0x13,
+                0x4C, 0xEA, 0xA3, 0x85, 0x2C, 0x69, 0xD2, 0xC5, 0xAB, 0xAF, 0xEA,
+            ],
+            [
+                0x1B, 0xEF, 0x68, 0x94, 0x92, 0xA8, 0xA5, 0x43, 0xA5, 0x99, 0x9F, 0xDB, 0x83, 0x4E,
+                0x31, 0x66, 0xA1, 0x4B, 0xE8, 0x27, 0xD9, 0x50, 0x40, 0x47, 0x9E,
+            ],
+        );
+    }
+
+    // https://github.com/XKCP/XKCP/blob/master/tests/TestVectors/KeccakF-400-IntermediateValues.txt
+    #[test]
+    fn keccak_f400() {
+        zero_state_twice::<u16>(
+            [
+                0x09F5, 0x40AC, 0x0FA9, 0x14F5, 0xE89F, 0xECA0, 0x5BD1, 0x7870, 0xEFF0, 0xBF8F,
+                0x0337, 0x6052, 0xDC75, 0x0EC9, 0xE776, 0x5246, 0x59A1, 0x5D81, 0x6D95, 0x6E14,
+                0x633E, 0x58EE, 0x71FF, 0x714C, 0xB38E,
+            ],
+            [
+                0xE537, 0xD5D6, 0xDBE7, 0xAAF3, 0x9BC7, 0xCA7D, 0x86B2, 0xFDEC, 0x692C, 0x4E5B,
+                0x67B1, 0x15AD, 0xA7F7, 0xA66F, 0x67FF, 0x3F8A, 0x2F99, 0xE2C2, 0x656B, 0x5F31,
+                0x5BA6, 0xCA29, 0xC224, 0xB85C, 0x097C,
+            ],
+        );
+    }
+
+    // https://github.com/XKCP/XKCP/blob/master/tests/TestVectors/KeccakF-800-IntermediateValues.txt
+    #[test]
+    fn keccak_f800() {
+        zero_state_twice::<u32>(
+            [
+                0xE531D45D, 0xF404C6FB, 0x23A0BF99, 0xF1F8452F, 0x51FFD042, 0xE539F578, 0xF00B80A7,
+                0xAF973664, 0xBF5AF34C, 0x227A2424, 0x88172715, 0x9F685884, 0xB15CD054, 0x1BF4FC0E,
+                0x6166FA91, 0x1A9E599A, 0xA3970A1F, 0xAB659687, 0xAFAB8D68, 0xE74B1015, 0x34001A98,
+                0x4119EFF3, 0x930A0E76, 0x87B28070, 0x11EFE996,
+            ],
+            [
+                0x75BF2D0D, 0x9B610E89, 0xC826AF40, 0x64CD84AB, 0xF905BDD6, 0xBC832835, 0x5F8001B9,
+                0x15662CCE, 0x8E38C95E, 0x701FE543, 0x1B544380, 0x89ACDEFF, 0x51EDB5DE, 0x0E9702D9,
+                0x6C19AA16, 0xA2913EEE, 0x60754E9A, 0x9819063C, 0xF4709254, 0xD09F9084, 0x772DA259,
+                0x1DB35DF7, 0x5AA60162, 0x358825D5, 0xB3783BAB,
+            ],
+        );
+    }
+
+    // https://github.com/XKCP/XKCP/blob/master/tests/TestVectors/KeccakF-1600-IntermediateValues.txt
+    #[test]
+    fn keccak_f1600() {
+        zero_state_twice::<u64>(
+            IOTA_23,
+            [
+                0x2D5C954DF96ECB3C,
+                0x6A332CD07057B56D,
+                0x093D8D1270D76B6C,
+                0x8A20D9B25569D094,
+                0x4F9C4F99E5E7F156,
+                0xF957B9A2DA65FB38,
+                0x85773DAE1275AF0D,
+                0xFAF4F247C3D810F7,
+                0x1F1B9EE6F79A8759,
+                0xE4FECC0FEE98B425,
+                0x68CE61B6B9CE68A1,
+                0xDEEA66C4BA8F974F,
+                0x33C43D836EAFB1F5,
+                0xE00654042719DBD9,
+                0x7CF8A9F009831265,
+                0xFD5449A6BF174743,
+                0x97DDAD33D8994B40,
+                0x48EAD5FC5D0BE774,
+                0xE3B8C8EE55B7B03C,
+                0x91A0226E649E42E9,
+                0x900E3129E7BADD7B,
+                0x202A9EC5FAA3CCE8,
+                0x5B3402464E1C3DB6,
+                0x609F4E62A44C1059,
+                0x20D06CD26A8FBF5C,
+            ],
+        );
+    }
+
     const START: [u64; 25] = [0; 25];
 
     const THETA_0: [u64; 25] = [0; 25];
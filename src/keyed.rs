@@ -0,0 +1,155 @@
+//! Keyed hashing of `HashMap` keys with TurboSHAKE128
+//!
+//! The hash of a key is the first 8 bytes of TurboSHAKE128(secret || key), so that an attacker who
+//! chooses the keys of a map cannot predict which of them collide. [`KeyedState`] draws the
+//! secret once and absorbs it, then every [`KeyedHasher`] is a [fork](Prefix::fork) of it which
+//! only absorbs the bytes written to it. The sponge keeps the partial block, so the many small
+//! `write_*` calls of a [`Hash`](std::hash::Hash) implementation are buffered, and the
+//! permutation only runs on complete blocks and when finishing.
+//! ```
+//! use std::collections::HashMap;
+//! use TD1::keyed::KeyedState;
+//!
+//! let mut map = HashMap::with_hasher(KeyedState::new());
+//! map.insert("attacker-controlled key", 1);
+//! assert_eq!(map["attacker-controlled key"], 1);
+//! ```
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+
+use crate::sponge::{Prefix, Shake128};
+
+/// number of bytes of the secret
+pub const KEY_BYTES: usize = 16;
+/// domain separation byte of TurboSHAKE128 for these hashes
+const DOMAIN: u8 = 0x0B;
+
+/// fills `bytes` with random bytes from the CSPRNG of the operating system, `/dev/urandom`
+///
+/// Where there is none, or it cannot be read, the bytes are the SipHash outputs of new
+/// [`RandomState`]s instead. Their keys are drawn from the operating system once per thread and
+/// then incremented, so the outputs are unpredictable to whoever does not know those keys, but
+/// they are not meant as cryptographic randomness.
+pub(crate) fn random_bytes(bytes: &mut [u8]) {
+    #[cfg(unix)]
+    {
+        use std::io::Read;
+        let urandom = std::fs::File::open("/dev/urandom");
+        if urandom.and_then(|mut file| file.read_exact(bytes)).is_ok() {
+            return;
+        }
+    }
+    for (i, part) in bytes.chunks_mut(8).enumerate() {
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_usize(i);
+        part.copy_from_slice(&hasher.finish().to_le_bytes()[..part.len()]);
+    }
+}
+
+fn random_key() -> [u8; KEY_BYTES] {
+    let mut key = [0; KEY_BYTES];
+    random_bytes(&mut key);
+    key
+}
+
+/// [`BuildHasher`] whose hashers share a secret
+///
+/// Clones share the secret too, so that maps built from the same state hash keys the same way.
+#[derive(Clone, Debug)]
+pub struct KeyedState {
+    prefix: Prefix,
+}
+
+impl Default for KeyedState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl KeyedState {
+    /// state with a new random secret
+    pub fn new() -> Self {
+        Self::with_key(&random_key())
+    }
+
+    /// state with a given secret, for reproducible hashes
+    /// ```
+    /// # use TD1::keyed::KeyedState;
+    /// use std::hash::BuildHasher;
+    ///
+    /// let state = KeyedState::with_key(&[0x42; 16]);
+    /// assert_eq!(state.hash_one(7u32), KeyedState::with_key(&[0x42; 16]).hash_one(7u32));
+    /// assert_ne!(state.hash_one(7u32), KeyedState::with_key(&[0x43; 16]).hash_one(7u32));
+    /// ```
+    pub fn with_key(key: &[u8; KEY_BYTES]) -> Self {
+        let mut hasher = Shake128::turbo(DOMAIN);
+        hasher.update(key);
+        KeyedState {
+            prefix: hasher.freeze(),
+        }
+    }
+}
+
+impl BuildHasher for KeyedState {
+    type Hasher = KeyedHasher;
+
+    fn build_hasher(&self) -> KeyedHasher {
+        KeyedHasher(self.prefix.fork())
+    }
+}
+
+/// [`Hasher`] built by [`KeyedState`], which already absorbed the secret
+#[derive(Clone, Debug)]
+pub struct KeyedHasher(Shake128);
+
+impl Hasher for KeyedHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.update(bytes);
+    }
+
+    /// the first 8 bytes of the output, as a little-endian number
+    fn finish(&self) -> u64 {
+        u64::from_le_bytes(self.0.clone().finalize().squeeze_array())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn hash_is_turbo_shake_of_key_and_message() {
+        let key = [0x5A; KEY_BYTES];
+        let mut hasher = KeyedState::with_key(&key).build_hasher();
+        hasher.write(b"partial ");
+        hasher.write(&[0x13; 300]);
+
+        let mut direct = Shake128::turbo(DOMAIN);
+        direct.update(&key);
+        direct.update(b"partial ");
+        direct.update(&[0x13; 300]);
+        let mut output = [0; 8];
+        direct.finalize().squeeze(&mut output);
+        assert_eq!(hasher.finish(), u64::from_le_bytes(output));
+    }
+
+    #[test]
+    fn finish_does_not_consume() {
+        let mut hasher = KeyedState::new().build_hasher();
+        hasher.write_u32(7);
+        let first = hasher.finish();
+        assert_eq!(hasher.finish(), first);
+        hasher.write_u8(0);
+        assert_ne!(hasher.finish(), first);
+    }
+
+    #[test]
+    fn random_keys_differ() {
+        let keys = (0..16).map(|_| random_key()).collect::<HashSet<_>>();
+        assert_eq!(keys.len(), 16);
+        let state = KeyedState::new();
+        assert_eq!(state.hash_one("key"), state.clone().hash_one("key"));
+        assert_ne!(state.hash_one("key"), KeyedState::new().hash_one("key"));
+    }
+}
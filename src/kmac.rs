@@ -0,0 +1,240 @@
+//! cSHAKE128 and KMAC128 of SP 800-185, and key derivation with them
+//!
+//! Every string given to these functions is [encoded](encode_string) with its length first, so
+//! the inputs can be read back unambiguously from what is absorbed: [`derive_key`] gives distinct
+//! keys to distinct `(label, context)` pairs, whatever bytes they contain.
+//! ```
+//! use TD1::kmac::derive_key;
+//!
+//! let master = [0x42; 32];
+//! let a = derive_key(&master, b"service", b"ab", 32);
+//! let b = derive_key(&master, b"servicea", b"b", 32);
+//! assert_ne!(a, b);
+//! ```
+use crate::{
+    keccak::rounds,
+    sponge::{Shake128, Squeezer, RATE_BYTES},
+    BITS_PER_BYTE,
+};
+
+/// domain separation suffix of cSHAKE (`00`), with the first bit of the `10*1` padding
+const CSHAKE_SUFFIX: u8 = 0x04;
+/// customization of the KMAC128 of [`derive_key`]
+const DERIVE_CUSTOMIZATION: &[u8] = b"TD1 derive";
+
+/// `x` in big endian on as few bytes as possible (at least one), preceded by that number of
+/// bytes
+/// ```
+/// # use TD1::kmac::left_encode;
+/// assert_eq!(left_encode(0), [0x01, 0x00]);
+/// assert_eq!(left_encode(168), [0x01, 0xA8]);
+/// assert_eq!(left_encode(0x1234), [0x02, 0x12, 0x34]);
+/// ```
+pub fn left_encode(x: usize) -> Vec<u8> {
+    let mut encoded = right_encode(x);
+    encoded.rotate_right(1);
+    encoded
+}
+
+/// `x` in big endian on as few bytes as possible (at least one), followed by that number of
+/// bytes
+/// ```
+/// # use TD1::kmac::right_encode;
+/// assert_eq!(right_encode(0), [0x00, 0x01]);
+/// assert_eq!(right_encode(256), [0x01, 0x00, 0x02]);
+/// ```
+pub fn right_encode(x: usize) -> Vec<u8> {
+    let bytes = x.to_be_bytes();
+    let skipped = bytes
+        .iter()
+        .position(|&byt| byt != 0)
+        .unwrap_or(bytes.len() - 1);
+    let mut encoded = bytes[skipped..].to_vec();
+    encoded.push(encoded.len() as u8);
+    encoded
+}
+
+/// `string` preceded by its length in bits
+/// ```
+/// # use TD1::kmac::encode_string;
+/// assert_eq!(encode_string(b""), [0x01, 0x00]);
+/// assert_eq!(encode_string(b"KMAC"), [0x01, 0x20, b'K', b'M', b'A', b'C']);
+/// ```
+pub fn encode_string(string: &[u8]) -> Vec<u8> {
+    let mut encoded = left_encode(string.len() * BITS_PER_BYTE);
+    encoded.extend_from_slice(string);
+    encoded
+}
+
+/// `strings` preceded by the encoding of `width`, padded with zeros to a multiple of `width`
+/// bytes
+/// ```
+/// # use TD1::kmac::bytepad;
+/// assert_eq!(bytepad(&[b"ab"], 4), [0x01, 0x04, b'a', b'b']);
+/// assert_eq!(bytepad(&[b"abc"], 4).len(), 8);
+/// ```
+pub fn bytepad(strings: &[&[u8]], width: usize) -> Vec<u8> {
+    let mut padded = left_encode(width);
+    for string in strings {
+        padded.extend_from_slice(string);
+    }
+    padded.resize(padded.len().next_multiple_of(width), 0);
+    padded
+}
+
+/// cSHAKE128 hasher with the function name `name` and the customization string
+/// `customization`, which is SHAKE128 when both are empty
+/// ```
+/// # use TD1::{kmac::cshake128, sponge::Shake128};
+/// let mut hasher = cshake128(b"", b"Email Signature");
+/// hasher.update(&[0x00, 0x01, 0x02, 0x03]);
+/// assert_eq!(hasher.finalize().squeeze_vec(4), [0xC1, 0xC3, 0x69, 0x25]);
+///
+/// let shake = cshake128(b"", b"").finalize().squeeze_vec(32);
+/// assert_eq!(shake, Shake128::new().finalize().squeeze_vec(32));
+/// ```
+pub fn cshake128(name: &[u8], customization: &[u8]) -> Shake128 {
+    if name.is_empty() && customization.is_empty() {
+        return Shake128::new();
+    }
+    let mut hasher = Shake128::with_parameters(RATE_BYTES, rounds(u64::BITS), CSHAKE_SUFFIX);
+    hasher.update(&bytepad(
+        &[&encode_string(name), &encode_string(customization)],
+        RATE_BYTES,
+    ));
+    hasher
+}
+
+/// KMAC128 under a key, with a customization string
+/// ```
+/// # use TD1::kmac::Kmac128;
+/// let mut mac = Kmac128::new(b"key", b"application");
+/// mac.update(b"message");
+/// assert_eq!(mac.finalize(32).len(), 32);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Kmac128(Shake128);
+
+impl Kmac128 {
+    pub fn new(key: &[u8], customization: &[u8]) -> Self {
+        let mut hasher = cshake128(b"KMAC", customization);
+        hasher.update(&bytepad(&[&encode_string(key)], RATE_BYTES));
+        Kmac128(hasher)
+    }
+
+    /// absorbs `data` into the message
+    pub fn update(&mut self, data: &[u8]) {
+        self.0.update(data)
+    }
+
+    /// the tag of `size` bytes, which depends on `size`: a shorter tag is not a prefix of a
+    /// longer one
+    pub fn finalize(mut self, size: usize) -> Vec<u8> {
+        self.0.update(&right_encode(size * BITS_PER_BYTE));
+        self.0.finalize().squeeze_vec(size)
+    }
+
+    /// KMACXOF128, whose output does not depend on its length
+    pub fn finalize_xof(mut self) -> Squeezer {
+        self.0.update(&right_encode(0));
+        self.0.finalize()
+    }
+}
+
+/// derives `size` bytes for the pair `(label, context)` from the secret `master`
+///
+/// This is KMAC128 keyed by `master`, with the customization string `TD1 derive`, of the message
+/// `encode_string(label) || encode_string(context)`.
+pub fn derive_key(master: &[u8], label: &[u8], context: &[u8], size: usize) -> Vec<u8> {
+    let mut mac = Kmac128::new(master, DERIVE_CUSTOMIZATION);
+    mac.update(&encode_string(label));
+    mac.update(&encode_string(context));
+    mac.finalize(size)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hex::{encode, Format};
+
+    /// `00 01 02 ..`, `length` bytes long, as in the samples of SP 800-185
+    fn data(length: usize) -> Vec<u8> {
+        (0..length).map(|i| i as u8).collect()
+    }
+
+    #[test]
+    fn cshake_samples() {
+        for (length, expected) in [
+            (
+                4,
+                "C1C36925B6409A04F1B504FCBCA9D82B4017277CB5ED2B2065FC1D3814D5AAF5",
+            ),
+            (
+                200,
+                "C5221D50E4F822D96A2E8881A961420F294B7B24FE3D2094BAED2C6524CC166B",
+            ),
+        ] {
+            let mut hasher = cshake128(b"", b"Email Signature");
+            hasher.update(&data(length));
+            let output = hasher.finalize().squeeze_vec(32);
+            assert_eq!(encode(&output, Format::UPPER), expected);
+        }
+    }
+
+    #[test]
+    fn kmac_samples() {
+        let key = (0x40..0x60).collect::<Vec<u8>>();
+        for (length, customization, expected) in [
+            (
+                4,
+                &b""[..],
+                "E5780B0D3EA6F7D3A429C5706AA43A00FADBD7D49628839E3187243F456EE14E",
+            ),
+            (
+                4,
+                b"My Tagged Application",
+                "3B1FBA963CD8B0B59E8C1A6D71888B7143651AF8BA0A7070C0979E2811324AA5",
+            ),
+        ] {
+            let mut mac = Kmac128::new(&key, customization);
+            mac.update(&data(length));
+            assert_eq!(encode(&mac.finalize(32), Format::UPPER), expected);
+        }
+    }
+
+    #[test]
+    fn tag_depends_on_its_length() {
+        let tag = |size| {
+            let mut mac = Kmac128::new(b"key", b"");
+            mac.update(b"message");
+            mac.finalize(size)
+        };
+        assert_ne!(tag(32)[..16], tag(16));
+
+        let mut mac = Kmac128::new(b"key", b"");
+        mac.update(b"message");
+        let mut xof = mac.finalize_xof();
+        assert_eq!(xof.squeeze_vec(16).len(), 16);
+    }
+
+    #[test]
+    fn derived_keys_are_separated() {
+        let master = b"master secret";
+        let pairs: [(&[u8], &[u8]); 5] = [
+            (b"ab", b"c"),
+            (b"a", b"bc"),
+            (b"abc", b""),
+            (b"", b"abc"),
+            (b"abc", b"\x00"),
+        ];
+        let keys = pairs
+            .iter()
+            .map(|(label, context)| derive_key(master, label, context, 32))
+            .collect::<std::collections::HashSet<_>>();
+        assert_eq!(keys.len(), pairs.len());
+        assert_ne!(
+            derive_key(master, b"a", b"b", 32),
+            derive_key(b"other secret", b"a", b"b", 32)
+        );
+    }
+}
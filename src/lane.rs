@@ -0,0 +1,150 @@
+//! Lanes of a Keccak-f\[b\] state
+//!
+//! A state is always made of [`STATE_SIZE`](crate::STATE_SIZE) lanes of `w` bits, with
+//! `b = 25 w` and `w = 2^ℓ` for `ℓ` in `0..=6`. `u8`, `u16`, `u32` and `u64` cover
+//! Keccak-f\[200\] to Keccak-f\[1600\], and [`Bit1`], [`Bit2`], [`Bit4`] the toy widths
+//! Keccak-f\[25\], Keccak-f\[50\] and Keccak-f\[100\].
+use std::fmt::{self, Debug, UpperHex};
+use std::ops::{BitAnd, BitXor, BitXorAssign, Not};
+
+pub trait Lane:
+    Copy
+    + Debug
+    + UpperHex
+    + PartialEq
+    + BitXor<Output = Self>
+    + BitXorAssign
+    + BitAnd<Output = Self>
+    + Not<Output = Self>
+{
+    /// `w`, the number of bits in a lane
+    const WIDTH: u32;
+    const ZERO: Self;
+
+    /// cyclic shift towards the most significant bit, within the `w` bits of the lane
+    fn rotate_left(self, n: u32) -> Self;
+
+    /// keeps the `w` least significant bits of `value`, which is how the 64-bit round
+    /// constants are applied to narrower lanes
+    fn truncate(value: u64) -> Self;
+}
+
+macro_rules! impl_lane {
+    ($($lane:ty),*) => {
+        $(
+            impl Lane for $lane {
+                const WIDTH: u32 = <$lane>::BITS;
+                const ZERO: Self = 0;
+
+                fn rotate_left(self, n: u32) -> Self {
+                    <$lane>::rotate_left(self, n)
+                }
+
+                fn truncate(value: u64) -> Self {
+                    value as $lane
+                }
+            }
+        )*
+    };
+}
+
+impl_lane!(u8, u16, u32, u64);
+
+/// lane of fewer than 8 bits, stored in the `W` least significant bits of a byte
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Narrow<const W: u32>(u8);
+
+/// lane of Keccak-f\[25\]
+pub type Bit1 = Narrow<1>;
+/// lane of Keccak-f\[50\]
+pub type Bit2 = Narrow<2>;
+/// lane of Keccak-f\[100\]
+pub type Bit4 = Narrow<4>;
+
+impl<const W: u32> Narrow<W> {
+    const MASK: u8 = ((1u16 << W) - 1) as u8;
+
+    /// keeps the `W` least significant bits of `bits`
+    pub const fn new(bits: u8) -> Self {
+        Narrow(bits & Self::MASK)
+    }
+
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+}
+
+impl<const W: u32> UpperHex for Narrow<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        UpperHex::fmt(&self.0, f)
+    }
+}
+
+impl<const W: u32> BitXor for Narrow<W> {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self {
+        Narrow(self.0 ^ rhs.0)
+    }
+}
+
+impl<const W: u32> BitXorAssign for Narrow<W> {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        self.0 ^= rhs.0
+    }
+}
+
+impl<const W: u32> BitAnd for Narrow<W> {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        Narrow(self.0 & rhs.0)
+    }
+}
+
+impl<const W: u32> Not for Narrow<W> {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Narrow::new(!self.0)
+    }
+}
+
+impl<const W: u32> Lane for Narrow<W> {
+    const WIDTH: u32 = W;
+    const ZERO: Self = Narrow(0);
+
+    fn rotate_left(self, n: u32) -> Self {
+        let n = n % W;
+        if n == 0 {
+            self
+        } else {
+            Narrow::new((self.0 << n) | (self.0 >> (W - n)))
+        }
+    }
+
+    fn truncate(value: u64) -> Self {
+        Narrow::new(value as u8)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn narrow_rotation() {
+        assert_eq!(Bit1::new(1).rotate_left(5), Bit1::new(1));
+        assert_eq!(Bit2::new(0b01).rotate_left(1), Bit2::new(0b10));
+        assert_eq!(Bit2::new(0b10).rotate_left(1), Bit2::new(0b01));
+        assert_eq!(Bit4::new(0b1001).rotate_left(1), Bit4::new(0b0011));
+        assert_eq!(Bit4::new(0b1001).rotate_left(6), Bit4::new(0b0110));
+    }
+
+    #[test]
+    fn narrow_stays_masked() {
+        assert_eq!(!Bit2::ZERO, Bit2::new(0b11));
+        assert_eq!((!Bit4::ZERO).bits(), 0x0F);
+        assert_eq!(Bit1::truncate(0x8000000000008083), Bit1::new(1));
+    }
+}
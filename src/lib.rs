@@ -13,5 +13,33 @@ pub const CAPACITY: usize = 4;
 /// in chunks ([`BYTES_PER_CHUNK`])
 pub const RATE: usize = STATE_SIZE - CAPACITY;
 
+pub mod balloon;
+pub mod bench;
+pub mod cdc;
 pub mod convert;
+pub mod dir;
+pub mod encoding;
+pub mod error;
+pub mod example;
+pub mod explain;
+pub mod hex;
+pub mod hkdf;
+pub mod hmac;
+pub mod input;
+pub mod kat;
 pub mod keccak;
+pub mod keyed;
+pub mod kmac;
+pub mod lane;
+pub mod merkle;
+pub mod parallel;
+#[cfg(feature = "digest")]
+pub mod rustcrypto;
+pub mod sha3;
+pub mod sponge;
+pub mod store;
+#[cfg(test)]
+mod testing;
+pub mod trace;
+
+pub use error::{Error, Result};
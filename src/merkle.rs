@@ -0,0 +1,502 @@
+//! Merkle trees of SHAKE128-256 hashes over chunked streams, with inclusion proofs
+//!
+//! A stream is cut into chunks of [`Params::chunk_size`] bytes (the last one may be shorter, and
+//! an empty stream is a single empty chunk). Every chunk is a leaf hashed as
+//! `SHAKE128(00 || chunk, 256)`, then each level is grouped into nodes of up to
+//! [`Params::fanout`] consecutive children, hashed as `SHAKE128(01 || children, 256)`, until a
+//! single hash remains: the root. The prefix bytes keep leaves and nodes apart, as in RFC 6962.
+//!
+//! A [`Proof`] holds the siblings of a chunk on its way to the root, so that the chunk can be
+//! checked against a trusted root without the rest of the stream. The index of the chunk and the
+//! parameters of the tree are trusted as well, rather than taken from the proof:
+//! ```
+//! use TD1::merkle::{prove, root, Params};
+//!
+//! let params = Params { chunk_size: 4, fanout: 3 };
+//! let artifact = b"a large artifact, downloaded chunk by chunk";
+//! let trusted_root = root(&artifact[..], &params).unwrap();
+//!
+//! let proof = prove(&artifact[..], &params, 5).unwrap();
+//! assert!(proof.verify(&params, 5, &artifact[20..24], &trusted_root));
+//! assert!(!proof.verify(&params, 5, b"evil", &trusted_root));
+//! assert!(!proof.verify(&params, 4, &artifact[20..24], &trusted_root));
+//! ```
+use std::fmt;
+use std::io::{self, Read};
+use std::str::FromStr;
+
+use crate::{
+    error::ParseError,
+    hex::{self, Format},
+    kat::key_values,
+    sponge::Shake128,
+    Error, Result,
+};
+
+/// number of bytes of the hashes of the tree
+pub const HASH_BYTES: usize = 32;
+const LEAF_PREFIX: u8 = 0x00;
+const NODE_PREFIX: u8 = 0x01;
+
+pub type Hash = [u8; HASH_BYTES];
+
+/// shape of a tree
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Params {
+    /// number of bytes of every chunk but the last one
+    pub chunk_size: usize,
+    /// largest number of children of a node
+    pub fanout: usize,
+}
+
+/// chunks of 64 KiB in a binary tree
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            chunk_size: 64 * 1024,
+            fanout: 2,
+        }
+    }
+}
+
+impl Params {
+    fn check(&self) -> Result<()> {
+        if self.chunk_size == 0 {
+            return Err(Error::InvalidParameter(
+                "the chunk size must be at least 1 byte".to_string(),
+            ));
+        }
+        if self.fanout < 2 {
+            return Err(Error::InvalidParameter(
+                "the fan-out must be at least 2".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+pub fn hash_leaf(chunk: &[u8]) -> Hash {
+    Shake128::digest(&[&[LEAF_PREFIX], chunk])
+}
+
+pub fn hash_node(children: &[Hash]) -> Hash {
+    let parts = std::iter::once(&[NODE_PREFIX][..])
+        .chain(children.iter().map(|child| &child[..]))
+        .collect::<Vec<_>>();
+    Shake128::digest(&parts)
+}
+
+/// reads until `buffer` is full or the stream ends, returning the number of bytes read
+fn read_chunk(reader: &mut impl Read, buffer: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buffer.len() {
+        match reader.read(&mut buffer[filled..]) {
+            Ok(0) => break,
+            Ok(read) => filled += read,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+            Err(error) => return Err(error),
+        }
+    }
+    Ok(filled)
+}
+
+/// calls `leaf` with the hash of every chunk of `reader`, in order
+fn for_each_leaf(mut reader: impl Read, params: &Params, mut leaf: impl FnMut(Hash)) -> Result<()> {
+    params.check()?;
+    let mut buffer = vec![0; params.chunk_size];
+    let mut first = true;
+    loop {
+        let read = read_chunk(&mut reader, &mut buffer)?;
+        if read > 0 || first {
+            leaf(hash_leaf(&buffer[..read]));
+        }
+        first = false;
+        if read < buffer.len() {
+            return Ok(());
+        }
+    }
+}
+
+/// root of the tree of `reader`, which is read once and never held in memory: only the
+/// incomplete group of every level is kept
+pub fn root(reader: impl Read, params: &Params) -> Result<Hash> {
+    // `levels[l]` holds the hashes of level `l` whose node is not complete yet
+    let mut levels: Vec<Vec<Hash>> = Vec::new();
+    fn push(levels: &mut Vec<Vec<Hash>>, level: usize, hash: Hash, fanout: usize) {
+        if level == levels.len() {
+            levels.push(Vec::with_capacity(fanout));
+        }
+        levels[level].push(hash);
+        if levels[level].len() == fanout {
+            let node = hash_node(&std::mem::take(&mut levels[level]));
+            push(levels, level + 1, node, fanout);
+        }
+    }
+    for_each_leaf(reader, params, |leaf| {
+        push(&mut levels, 0, leaf, params.fanout)
+    })?;
+
+    // the last groups are incomplete: they are hashed from the bottom up
+    let mut level = 0;
+    loop {
+        let top = level + 1 == levels.len();
+        match levels[level].len() {
+            1 if top => return Ok(levels[level][0]),
+            0 => {}
+            _ => {
+                let node = hash_node(&std::mem::take(&mut levels[level]));
+                push(&mut levels, level + 1, node, params.fanout);
+            }
+        }
+        level += 1;
+    }
+}
+
+/// siblings of a chunk on its way to the root
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Proof {
+    pub params: Params,
+    /// number of chunks of the stream
+    pub leaves: u64,
+    /// index of the chunk, from 0
+    pub index: u64,
+    /// the other children of each node from the leaf to the root, level by level
+    pub siblings: Vec<Hash>,
+}
+
+/// the inclusion proof of the chunk `index` of `reader`
+pub fn prove(reader: impl Read, params: &Params, index: u64) -> Result<Proof> {
+    let mut level = Vec::new();
+    for_each_leaf(reader, params, |leaf| level.push(leaf))?;
+    let leaves = level.len() as u64;
+    if index >= leaves {
+        return Err(Error::InvalidParameter(format!(
+            "chunk {index} is out of the {leaves} chunks of the stream"
+        )));
+    }
+
+    let mut siblings = Vec::new();
+    let mut position = index as usize;
+    while level.len() > 1 {
+        let start = position - position % params.fanout;
+        let group = &level[start..(start + params.fanout).min(level.len())];
+        siblings.extend(
+            group
+                .iter()
+                .enumerate()
+                .filter(|&(i, _)| start + i != position)
+                .map(|(_, sibling)| *sibling),
+        );
+        level = level.chunks(params.fanout).map(hash_node).collect();
+        position /= params.fanout;
+    }
+    Ok(Proof {
+        params: *params,
+        leaves,
+        index,
+        siblings,
+    })
+}
+
+impl Proof {
+    /// root of the tree of shape `params` in which `chunk` is the chunk `index`, or `None` if the
+    /// proof is malformed or made for another chunk or shape, or if `chunk` cannot be that chunk
+    fn root(&self, params: &Params, index: u64, chunk: &[u8]) -> Option<Hash> {
+        if self.params != *params || self.index != index || params.check().is_err() {
+            return None;
+        }
+        if self.index >= self.leaves {
+            return None;
+        }
+        // every chunk but the last one is full, and only an empty stream has an empty chunk
+        let last = self.index + 1 == self.leaves;
+        let fits = match chunk.len() {
+            0 => self.leaves == 1,
+            length if last => length <= params.chunk_size,
+            length => length == params.chunk_size,
+        };
+        if !fits {
+            return None;
+        }
+        let fanout = params.fanout as u64;
+        let mut hash = hash_leaf(chunk);
+        let mut siblings = &self.siblings[..];
+        let (mut position, mut count) = (self.index, self.leaves);
+        while count > 1 {
+            let start = position - position % fanout;
+            let size = fanout.min(count - start) as usize;
+            let taken = siblings.get(..size - 1)?;
+            siblings = &siblings[size - 1..];
+
+            let offset = (position - start) as usize;
+            let mut group = taken.to_vec();
+            group.insert(offset, hash);
+            hash = hash_node(&group);
+            position /= fanout;
+            count = count.div_ceil(fanout);
+        }
+        siblings.is_empty().then_some(hash)
+    }
+
+    /// whether `chunk` is the chunk `index` of the tree of shape `params` and root `root`
+    pub fn verify(&self, params: &Params, index: u64, chunk: &[u8], root: &Hash) -> bool {
+        self.root(params, index, chunk)
+            .is_some_and(|computed| computed == *root)
+    }
+}
+
+/// written as [`key_values`] lines, the parameters first and then one line per sibling
+impl fmt::Display for Proof {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "ChunkSize = {}", self.params.chunk_size)?;
+        writeln!(f, "Fanout = {}", self.params.fanout)?;
+        writeln!(f, "Leaves = {}", self.leaves)?;
+        writeln!(f, "Index = {}", self.index)?;
+        for sibling in &self.siblings {
+            writeln!(f, "Sibling = {}", hex::encode(sibling, Format::LOWER))?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Proof {
+    type Err = Error;
+
+    /// reads the lines written by [`Display`](fmt::Display), in the same order
+    fn from_str(text: &str) -> Result<Self> {
+        let mut lines = key_values(text);
+        let mut number = |expected: &str| -> Result<u64> {
+            let (line, key, value) = lines.next().ok_or_else(|| ParseError {
+                line: text.lines().count() + 1,
+                message: format!("missing `{expected}`"),
+            })??;
+            let error = |message| ParseError { line, message };
+            if key != expected {
+                return Err(error(format!("expected `{expected}`, found `{key}`")).into());
+            }
+            value
+                .parse()
+                .map_err(|_| error(format!("`{value}` is not a number")).into())
+        };
+        let params = Params {
+            chunk_size: number("ChunkSize")? as usize,
+            fanout: number("Fanout")? as usize,
+        };
+        let leaves = number("Leaves")?;
+        let index = number("Index")?;
+
+        let siblings = lines
+            .map(|line| {
+                let (line, key, value) = line?;
+                if key != "Sibling" {
+                    return Err(ParseError {
+                        line,
+                        message: format!("expected `Sibling`, found `{key}`"),
+                    }
+                    .into());
+                }
+                let bytes = hex::decode(value, Format::LOWER)?;
+                bytes.as_slice().try_into().map_err(|_| Error::WrongLength {
+                    expected: HASH_BYTES,
+                    found: bytes.len(),
+                })
+            })
+            .collect::<Result<Vec<Hash>>>()?;
+        Ok(Proof {
+            params,
+            leaves,
+            index,
+            siblings,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// root computed level by level, with every leaf in memory
+    fn naive_root(data: &[u8], params: &Params) -> Hash {
+        let mut level = if data.is_empty() {
+            vec![hash_leaf(b"")]
+        } else {
+            data.chunks(params.chunk_size).map(hash_leaf).collect()
+        };
+        while level.len() > 1 {
+            level = level.chunks(params.fanout).map(hash_node).collect();
+        }
+        level[0]
+    }
+
+    fn data(length: usize) -> Vec<u8> {
+        (0..length).map(|i| (i * 7 % 256) as u8).collect()
+    }
+
+    #[test]
+    fn streaming_root_matches_levels() {
+        for fanout in [2, 3, 4, 16] {
+            for length in [0, 1, 5, 8, 9, 27, 28, 64, 65, 100, 243] {
+                let params = Params {
+                    chunk_size: 1,
+                    fanout,
+                };
+                let data = data(length);
+                assert_eq!(
+                    root(&data[..], &params).unwrap(),
+                    naive_root(&data, &params),
+                    "fanout {fanout}, length {length}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn single_chunk_is_its_leaf() {
+        let params = Params::default();
+        assert_eq!(root(&b"small"[..], &params).unwrap(), hash_leaf(b"small"));
+        assert_eq!(root(&b""[..], &params).unwrap(), hash_leaf(b""));
+    }
+
+    #[test]
+    fn leaves_and_nodes_are_separated() {
+        // a node whose children are the bytes of a chunk does not hash like that chunk
+        let children = [[0x11; HASH_BYTES], [0x22; HASH_BYTES]];
+        assert_ne!(hash_node(&children), hash_leaf(&children.concat()));
+    }
+
+    #[test]
+    fn every_proof_verifies() {
+        for fanout in [2, 3, 5] {
+            for length in [1, 2, 7, 9, 26] {
+                let params = Params {
+                    chunk_size: 3,
+                    fanout,
+                };
+                let data = data(length);
+                let root = root(&data[..], &params).unwrap();
+                for (index, chunk) in data.chunks(3).enumerate() {
+                    let proof = prove(&data[..], &params, index as u64).unwrap();
+                    let index = index as u64;
+                    assert!(
+                        proof.verify(&params, index, chunk, &root),
+                        "{fanout} {length} {index}"
+                    );
+                    assert!(!proof.verify(&params, index, &[0xFF; 3], &root));
+                    let parsed = proof.to_string().parse::<Proof>().unwrap();
+                    assert_eq!(parsed, proof);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn tampered_proofs_fail() {
+        let params = Params {
+            chunk_size: 2,
+            fanout: 2,
+        };
+        let data = data(20);
+        let root = root(&data[..], &params).unwrap();
+        let proof = prove(&data[..], &params, 3).unwrap();
+        assert!(proof.verify(&params, 3, &data[6..8], &root));
+
+        let mut moved = proof.clone();
+        moved.index = 2;
+        assert!(!moved.verify(&params, 2, &data[6..8], &root));
+        let mut truncated = proof.clone();
+        truncated.siblings.pop();
+        assert_eq!(truncated.root(&params, 3, &data[6..8]), None);
+        let mut extended = proof.clone();
+        extended.siblings.push([0; HASH_BYTES]);
+        assert_eq!(extended.root(&params, 3, &data[6..8]), None);
+        let mut flipped = proof;
+        flipped.siblings[0][0] ^= 1;
+        assert!(!flipped.verify(&params, 3, &data[6..8], &root));
+    }
+
+    #[test]
+    fn proofs_must_match_the_expected_chunk() {
+        let params = Params {
+            chunk_size: 4,
+            fanout: 3,
+        };
+        let data = data(14);
+        let root = root(&data[..], &params).unwrap();
+        let proof = prove(&data[..], &params, 1).unwrap();
+        assert!(proof.verify(&params, 1, &data[4..8], &root));
+
+        // the index and the shape are the caller's, whatever the proof says
+        assert!(!proof.verify(&params, 0, &data[4..8], &root));
+        let mut relabelled = proof.clone();
+        relabelled.index = 0;
+        assert!(!relabelled.verify(&params, 1, &data[4..8], &root));
+        let other = Params {
+            chunk_size: 4,
+            fanout: 2,
+        };
+        assert!(!proof.verify(&other, 1, &data[4..8], &root));
+        let mut reshaped = proof.clone();
+        reshaped.params = other;
+        assert!(!reshaped.verify(&params, 1, &data[4..8], &root));
+
+        // only the last chunk may be short, and none may be longer than the chunk size
+        assert_eq!(proof.root(&params, 1, &data[4..7]), None);
+        assert_eq!(proof.root(&params, 1, &data[4..9]), None);
+        assert_eq!(proof.root(&params, 1, b""), None);
+        let last = prove(&data[..], &params, 3).unwrap();
+        assert!(last.verify(&params, 3, &data[12..], &root));
+        assert_eq!(last.root(&params, 3, &data[9..]), None);
+        let empty = prove(&b""[..], &params, 0).unwrap();
+        assert!(empty.verify(&params, 0, b"", &hash_leaf(b"")));
+    }
+
+    #[test]
+    fn rejects_bad_parameters_and_indices() {
+        let bad = Params {
+            chunk_size: 4,
+            fanout: 1,
+        };
+        assert!(matches!(
+            root(&b"data"[..], &bad),
+            Err(Error::InvalidParameter(_))
+        ));
+        assert!(matches!(
+            prove(&b"data"[..], &Params::default(), 1),
+            Err(Error::InvalidParameter(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_malformed_proofs() {
+        let proof = prove(
+            &data(10)[..],
+            &Params {
+                chunk_size: 3,
+                fanout: 2,
+            },
+            1,
+        )
+        .unwrap();
+        let text = proof.to_string();
+        assert!(matches!(
+            text.replace("Fanout", "FanOut").parse::<Proof>(),
+            Err(Error::Parse(ParseError { line: 2, .. }))
+        ));
+        assert!(matches!(
+            text.lines()
+                .take(3)
+                .collect::<Vec<_>>()
+                .join("\n")
+                .parse::<Proof>(),
+            Err(Error::Parse(ParseError { line: 4, .. }))
+        ));
+        let (head, _) = text.rsplit_once("Sibling").unwrap();
+        assert!(matches!(
+            format!("{head}Sibling = 00ff\n").parse::<Proof>(),
+            Err(Error::WrongLength {
+                expected: 32,
+                found: 2
+            })
+        ));
+    }
+}
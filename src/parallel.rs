@@ -0,0 +1,148 @@
+//! Hashing of many files on a pool of threads
+//!
+//! Every worker takes the next file that nobody hashes yet, and streams it through a sponge, so
+//! that it holds a single read buffer whatever the size of the file. The results come back in the
+//! order of the files, whichever worker finishes first:
+//! ```
+//! use TD1::{parallel::hash_files, sponge::Shake128};
+//!
+//! let paths = ["Cargo.toml", "README.md", "Cargo.toml"];
+//! let mut hashes = Vec::new();
+//! hash_files(&paths, 256, 2, |index, hash| {
+//!     hashes.push((index, hash.unwrap()));
+//!     true
+//! });
+//! assert_eq!(hashes.iter().map(|(index, _)| *index).collect::<Vec<_>>(), [0, 1, 2]);
+//! assert_eq!(hashes[0].1, hashes[2].1);
+//!
+//! let mut hasher = Shake128::new();
+//! hasher.update(&std::fs::read("README.md").unwrap());
+//! assert_eq!(hashes[1].1, hasher.finalize().squeeze_bits(256));
+//! ```
+use std::fs::File;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc;
+
+use crate::{input::absorb_file, sponge::Shake128, Result};
+
+/// the first `output_bits` bits of the SHAKE128 hash of the file at `path`
+pub fn hash_file(path: &Path, output_bits: usize) -> Result<Vec<u8>> {
+    let mut hasher = Shake128::new();
+    absorb_file(&mut hasher, &mut File::open(path)?)?;
+    Ok(hasher.finalize().squeeze_bits(output_bits))
+}
+
+/// hashes the files at `paths` on `jobs` threads (at least one), calling `done` with the index
+/// and the result of each file in the order of `paths`
+///
+/// When `done` returns `false`, the files that are not hashed yet are left out, and `done` is not
+/// called anymore.
+pub fn hash_files<P: AsRef<Path> + Sync>(
+    paths: &[P],
+    output_bits: usize,
+    jobs: usize,
+    mut done: impl FnMut(usize, Result<Vec<u8>>) -> bool,
+) {
+    let next = AtomicUsize::new(0);
+    let stop = AtomicBool::new(false);
+    // results that arrived before those of earlier files
+    let mut pending = (0..paths.len()).map(|_| None).collect::<Vec<_>>();
+    let mut emitted = 0;
+
+    std::thread::scope(|scope| {
+        let (sender, receiver) = mpsc::sync_channel(jobs.max(1));
+        for _ in 0..jobs.clamp(1, paths.len().max(1)) {
+            let sender = sender.clone();
+            let (next, stop) = (&next, &stop);
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                if index >= paths.len() || stop.load(Ordering::Relaxed) {
+                    return;
+                }
+                let hash = hash_file(paths[index].as_ref(), output_bits);
+                if sender.send((index, hash)).is_err() {
+                    return;
+                }
+            });
+        }
+        drop(sender);
+
+        for (index, hash) in receiver {
+            pending[index] = Some(hash);
+            while let Some(hash) = pending.get_mut(emitted).and_then(Option::take) {
+                if !done(emitted, hash) {
+                    stop.store(true, Ordering::Relaxed);
+                    return;
+                }
+                emitted += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{testing::TempDir, Error};
+    use std::path::PathBuf;
+
+    /// files of different sizes in a new temporary directory, removed when dropped
+    struct Files(TempDir, Vec<PathBuf>);
+
+    impl Files {
+        fn new(name: &str, count: usize) -> Self {
+            let directory = TempDir::new("parallel", name);
+            let paths = (0..count)
+                .map(|i| {
+                    // the first files are the largest, so they finish last
+                    directory.write(&i.to_string(), &vec![i as u8; (count - i) * 10_000])
+                })
+                .collect();
+            Files(directory, paths)
+        }
+    }
+
+    fn collect(paths: &[PathBuf], jobs: usize) -> Vec<(usize, Vec<u8>)> {
+        let mut results = Vec::new();
+        hash_files(paths, 128, jobs, |index, hash| {
+            results.push((index, hash.unwrap()));
+            true
+        });
+        results
+    }
+
+    #[test]
+    fn results_come_in_order() {
+        let files = Files::new("order", 20);
+        let sequential = files
+            .1
+            .iter()
+            .enumerate()
+            .map(|(i, path)| (i, hash_file(path, 128).unwrap()))
+            .collect::<Vec<_>>();
+        for jobs in [0, 1, 2, 3, 8, 64] {
+            assert_eq!(collect(&files.1, jobs), sequential, "{jobs} jobs");
+        }
+        assert!(collect(&[], 4).is_empty());
+    }
+
+    #[test]
+    fn errors_are_reported_in_place() {
+        let mut files = Files::new("errors", 3);
+        files.1.insert(1, files.0.join("missing"));
+        let mut results = Vec::new();
+        hash_files(&files.1, 64, 2, |index, hash| {
+            results.push((index, hash.is_ok()));
+            true
+        });
+        assert_eq!(results, [(0, true), (1, false), (2, true), (3, true)]);
+
+        let mut called = 0;
+        hash_files(&files.1, 64, 2, |_, hash| {
+            called += 1;
+            !matches!(hash, Err(Error::Io(_)))
+        });
+        assert_eq!(called, 2);
+    }
+}
@@ -0,0 +1,117 @@
+//! Implementations of the RustCrypto [`digest`] traits, with the `digest` feature
+//!
+//! [`Shake128`] is an [`Update`] and an [`ExtendableOutput`] whose reader is the [`Squeezer`], so
+//! it can be used by code that is generic over these traits:
+//! ```
+//! use digest::{ExtendableOutput, Update, XofReader};
+//! use TD1::sponge::Shake128;
+//!
+//! fn xof<D: Default + Update + ExtendableOutput>(message: &[u8]) -> [u8; 4] {
+//!     let mut hasher = D::default();
+//!     hasher.update(message);
+//!     let mut output = [0; 4];
+//!     hasher.finalize_xof().read(&mut output);
+//!     output
+//! }
+//!
+//! assert_eq!(xof::<Shake128>(b""), [0x7F, 0x9C, 0x2B, 0xA4]);
+//! ```
+//!
+//! The hashes of [`crate::sha3`] implement [`Digest`](digest::Digest), and their block size is
+//! given by [`BlockSizeUser`], as needed by the `hmac` crate.
+use digest::{
+    consts::{U104, U136, U144, U168, U28, U32, U48, U64, U72},
+    core_api::BlockSizeUser,
+    ExtendableOutput, ExtendableOutputReset, FixedOutput, FixedOutputReset, HashMarker, Output,
+    OutputSizeUser, Reset, Update, XofReader,
+};
+
+use crate::{
+    sha3::{Sha3_224, Sha3_256, Sha3_384, Sha3_512},
+    sponge::{Shake128, Squeezer},
+};
+
+impl HashMarker for Shake128 {}
+
+impl BlockSizeUser for Shake128 {
+    type BlockSize = U168;
+}
+
+impl Update for Shake128 {
+    fn update(&mut self, data: &[u8]) {
+        Shake128::update(self, data)
+    }
+}
+
+impl Reset for Shake128 {
+    fn reset(&mut self) {
+        Shake128::reset(self)
+    }
+}
+
+impl ExtendableOutput for Shake128 {
+    type Reader = Squeezer;
+
+    fn finalize_xof(self) -> Squeezer {
+        self.finalize()
+    }
+}
+
+impl ExtendableOutputReset for Shake128 {
+    fn finalize_xof_reset(&mut self) -> Squeezer {
+        let hasher = self.clone();
+        Shake128::reset(self);
+        hasher.finalize()
+    }
+}
+
+impl XofReader for Squeezer {
+    fn read(&mut self, buffer: &mut [u8]) {
+        self.squeeze(buffer)
+    }
+}
+
+macro_rules! impl_fixed_output {
+    ($name:ident, $output:ty, $block:ty) => {
+        impl HashMarker for $name {}
+
+        impl OutputSizeUser for $name {
+            type OutputSize = $output;
+        }
+
+        impl BlockSizeUser for $name {
+            type BlockSize = $block;
+        }
+
+        impl Update for $name {
+            fn update(&mut self, data: &[u8]) {
+                $name::update(self, data)
+            }
+        }
+
+        impl Reset for $name {
+            fn reset(&mut self) {
+                $name::reset(self)
+            }
+        }
+
+        impl FixedOutput for $name {
+            fn finalize_into(self, out: &mut Output<Self>) {
+                out.copy_from_slice(&self.finalize());
+            }
+        }
+
+        impl FixedOutputReset for $name {
+            fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+                let hasher = self.clone();
+                $name::reset(self);
+                FixedOutput::finalize_into(hasher, out);
+            }
+        }
+    };
+}
+
+impl_fixed_output!(Sha3_224, U28, U144);
+impl_fixed_output!(Sha3_256, U32, U136);
+impl_fixed_output!(Sha3_384, U48, U104);
+impl_fixed_output!(Sha3_512, U64, U72);
@@ -0,0 +1,199 @@
+//! Fixed-output SHA-3 hashes of FIPS 202
+//!
+//! SHA3-224, SHA3-256, SHA3-384 and SHA3-512 are the sponge of [`crate::sponge`] with a capacity
+//! of twice their output size, the domain separation suffix `01`, and a single squeeze. Their
+//! [`FixedHash`] implementation gives the block size used by [`crate::hmac`].
+//! ```
+//! use TD1::sha3::Sha3_256;
+//!
+//! let mut hasher = Sha3_256::new();
+//! hasher.update(b"abc");
+//! assert_eq!(hasher.finalize()[..4], [0x3A, 0x98, 0x5D, 0xA7]);
+//! ```
+use std::io::{self, Write};
+
+use crate::{keccak::rounds, sponge::Shake128, BYTES_PER_CHUNK, STATE_SIZE};
+
+/// domain separation suffix of SHA-3 (`01`), with the first bit of the `10*1` padding
+const SHA3_SUFFIX: u8 = 0x06;
+
+/// hash function with a fixed output size, absorbing blocks of [`FixedHash::BLOCK_BYTES`]
+pub trait FixedHash: Clone + Default {
+    /// number of output bytes
+    const OUTPUT_BYTES: usize;
+    /// number of bytes absorbed per permutation, which is the block size of HMAC
+    const BLOCK_BYTES: usize;
+
+    /// absorbs `data`
+    fn update(&mut self, data: &[u8]);
+
+    /// writes the digest to `out`, which is [`FixedHash::OUTPUT_BYTES`] long
+    fn finalize_into(self, out: &mut [u8]);
+
+    /// digest of `data`
+    fn digest(data: &[u8]) -> Vec<u8> {
+        let mut hasher = Self::default();
+        hasher.update(data);
+        let mut out = vec![0; Self::OUTPUT_BYTES];
+        hasher.finalize_into(&mut out);
+        out
+    }
+}
+
+macro_rules! impl_sha3 {
+    ($name:ident, $bits:literal) => {
+        #[doc = concat!("SHA3-", $bits, " hasher")]
+        #[derive(Clone, Debug)]
+        pub struct $name(Shake128);
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl $name {
+            pub fn new() -> Self {
+                $name(Shake128::with_parameters(
+                    <Self as FixedHash>::BLOCK_BYTES,
+                    rounds(u64::BITS),
+                    SHA3_SUFFIX,
+                ))
+            }
+
+            /// absorbs `data`
+            pub fn update(&mut self, data: &[u8]) {
+                self.0.update(data)
+            }
+
+            /// forgets everything absorbed
+            pub fn reset(&mut self) {
+                self.0.reset()
+            }
+
+            pub fn finalize(self) -> [u8; $bits / 8] {
+                let mut out = [0; $bits / 8];
+                self.0.finalize().squeeze(&mut out);
+                out
+            }
+        }
+
+        impl FixedHash for $name {
+            const OUTPUT_BYTES: usize = $bits / 8;
+            const BLOCK_BYTES: usize = STATE_SIZE * BYTES_PER_CHUNK - 2 * Self::OUTPUT_BYTES;
+
+            fn update(&mut self, data: &[u8]) {
+                self.0.update(data)
+            }
+
+            fn finalize_into(self, out: &mut [u8]) {
+                out.copy_from_slice(&self.finalize());
+            }
+        }
+
+        impl Write for $name {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.update(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_sha3!(Sha3_224, 224);
+impl_sha3!(Sha3_256, 256);
+impl_sha3!(Sha3_384, 384);
+impl_sha3!(Sha3_512, 512);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hex::{encode, Format};
+
+    fn check<H: FixedHash>(message: &[u8], expected: &str) {
+        assert_eq!(encode(&H::digest(message), Format::LOWER), expected);
+        // across block boundaries, one byte at a time
+        let mut hasher = H::default();
+        message.chunks(1).for_each(|byt| hasher.update(byt));
+        let mut out = vec![0; H::OUTPUT_BYTES];
+        hasher.finalize_into(&mut out);
+        assert_eq!(encode(&out, Format::LOWER), expected);
+    }
+
+    #[test]
+    fn empty_and_abc() {
+        check::<Sha3_224>(
+            b"",
+            "6b4e03423667dbb73b6e15454f0eb1abd4597f9a1b078e3f5b5a6bc7",
+        );
+        check::<Sha3_224>(
+            b"abc",
+            "e642824c3f8cf24ad09234ee7d3c766fc9a3a5168d0c94ad73b46fdf",
+        );
+        check::<Sha3_256>(
+            b"",
+            "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a",
+        );
+        check::<Sha3_256>(
+            b"abc",
+            "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532",
+        );
+        check::<Sha3_384>(
+            b"abc",
+            "ec01498288516fc926459f58e2c6ad8df9b473cb0fc08c2596da7cf0e49be4b2\
+             98d88cea927ac7f539f1edf228376d25",
+        );
+        check::<Sha3_512>(
+            b"abc",
+            "b751850b1a57168a5693cd924b6b096e08f621827444f70d884f5d0240d2712e\
+             10e116e9192af3c91a7ec57647e3934057340b4cf408d5a56592f8274eec53f0",
+        );
+    }
+
+    /// `0, 1, 2, ..` modulo 251, `length` bytes long
+    fn pattern(length: usize) -> Vec<u8> {
+        (0..length).map(|i| (i % 251) as u8).collect()
+    }
+
+    #[test]
+    fn around_the_rate() {
+        let vectors = [
+            (
+                135,
+                "fded8fd9d6551c601eeb3b7c6bc5e5cfd8aad1d015b7e9aaa9c9b9475231d5e2",
+            ),
+            (
+                136,
+                "cf3ccff92480a29160c2d38317c430e14749bfee1788106957dfe73f8c4930e5",
+            ),
+            (
+                137,
+                "ce9d7dc90913ee5d92745019479a5352c6d6279bef18ed07dc0a83ee8084daca",
+            ),
+            (
+                500,
+                "495689a003b0b1a4ec4572335ed2d96510cac163d6cc7e83daa73d9b555a2fd5",
+            ),
+        ];
+        for (length, expected) in vectors {
+            check::<Sha3_256>(&pattern(length), expected);
+        }
+        check::<Sha3_512>(
+            &pattern(72),
+            "5d63f2bbe971a983ac6847480106e4e1264ee3a0befd79954914e1d86e795b2e\
+             18238f12fc5e46cb9cc78efdec610a93647cc04e1c23d8caaa6a58c21dd26c07",
+        );
+    }
+
+    #[test]
+    fn block_sizes() {
+        assert_eq!(Sha3_224::BLOCK_BYTES, 144);
+        assert_eq!(Sha3_256::BLOCK_BYTES, 136);
+        assert_eq!(Sha3_384::BLOCK_BYTES, 104);
+        assert_eq!(Sha3_512::BLOCK_BYTES, 72);
+    }
+}
@@ -0,0 +1,569 @@
+//! Incremental SHAKE128 sponge
+//!
+//! Unlike [`crate::convert::bytes_to_chunks`], which needs the whole message up front, the
+//! [`Shake128`] hasher absorbs bytes as they come and only keeps the current partial block.
+//! Since it is [`Clone`], a common prefix can be absorbed once, [frozen](Shake128::freeze), and
+//! then [forked](Prefix::fork) for every message sharing it. The hasher is an [`io::Write`] and
+//! the [`Squeezer`] an [`io::Read`], so both plug into the std I/O adapters.
+//!
+//! The same sponge also computes TurboSHAKE128 (RFC 9861), which only differs by its number of
+//! rounds and its domain separation byte (see [`Shake128::turbo`]), and the fixed-output SHA-3
+//! hashes of [`crate::sha3`], which have a smaller rate.
+use std::io::{self, BufRead, Read, Write};
+
+use crate::{
+    convert::xor_block,
+    keccak::{keccak_p_observed, rounds},
+    trace::Observer,
+    BITS_PER_BYTE, BYTES_PER_CHUNK, RATE, STATE_SIZE,
+};
+
+/// number of bytes absorbed or squeezed per permutation call by SHAKE128, the largest rate of
+/// the sponges of this crate
+pub const RATE_BYTES: usize = RATE * BYTES_PER_CHUNK;
+
+/// rounds of Keccak-f\[1600\], as used by SHAKE128
+const SHAKE_ROUNDS: usize = rounds(u64::BITS);
+/// rounds of Keccak-p\[1600, 12\], as used by TurboSHAKE128
+pub const TURBO_ROUNDS: usize = 12;
+/// domain separation suffix of SHAKE (`1111`), with the first bit of the `10*1` padding
+const SHAKE_SUFFIX: u8 = 0x1F;
+/// last bit of the `10*1` padding, in the last byte of the block
+const LAST_PADDING_BIT: u8 = 0x80;
+
+/// XORs a full block of `rate` bytes into the rate part of the state, then permutes it with `rounds`
+/// rounds
+fn absorb_block(
+    state: &mut [u64; STATE_SIZE],
+    block: &[u8],
+    rounds: usize,
+    observer: &mut impl Observer<u64>,
+) {
+    xor_block(state, block);
+    *state = keccak_p_observed(*state, rounds, observer);
+}
+
+/// SHAKE128 hasher in its absorbing phase
+/// ```
+/// # use TD1::sponge::*;
+/// let mut hasher = Shake128::new();
+/// hasher.update(b"");
+/// assert_eq!(hasher.finalize().squeeze_vec(4), [0x7F, 0x9C, 0x2B, 0xA4]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Shake128 {
+    state: [u64; STATE_SIZE],
+    /// bytes of the current block that have not been absorbed yet
+    block: [u8; RATE_BYTES],
+    /// number of meaningful bytes in `block`
+    filled: usize,
+    /// number of bytes of `block` absorbed per permutation, at most [`RATE_BYTES`]
+    rate: usize,
+    /// rounds of every permutation
+    rounds: usize,
+    /// domain separation bits, followed by the first bit of the padding
+    suffix: u8,
+}
+
+impl Default for Shake128 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Shake128 {
+    pub fn new() -> Self {
+        Self::with_parameters(RATE_BYTES, SHAKE_ROUNDS, SHAKE_SUFFIX)
+    }
+
+    /// sponge absorbing `rate` bytes per permutation of `rounds` rounds, whose message is followed
+    /// by `suffix` when padding
+    pub(crate) fn with_parameters(rate: usize, rounds: usize, suffix: u8) -> Self {
+        debug_assert!(rate <= RATE_BYTES && rate.is_multiple_of(BYTES_PER_CHUNK));
+        Shake128 {
+            state: [0; STATE_SIZE],
+            block: [0; RATE_BYTES],
+            filled: 0,
+            rate,
+            rounds,
+            suffix,
+        }
+    }
+
+    /// TurboSHAKE128 hasher with the domain separation byte `domain`, between `0x01` and `0x7F`
+    /// ```
+    /// # use TD1::sponge::*;
+    /// let mut hasher = Shake128::turbo(0x06);
+    /// hasher.update(&[0xFF]);
+    /// assert_eq!(hasher.finalize().squeeze_vec(4), [0x8E, 0xC9, 0xC6, 0x64]);
+    /// ```
+    pub fn turbo(domain: u8) -> Self {
+        assert!(
+            (0x01..=0x7F).contains(&domain),
+            "TurboSHAKE domain separation byte {domain:#04X} is not between 0x01 and 0x7F"
+        );
+        Self::with_parameters(RATE_BYTES, TURBO_ROUNDS, domain)
+    }
+
+    /// forgets everything absorbed, but keeps the rate, the rounds and the domain separation
+    pub fn reset(&mut self) {
+        *self = Self::with_parameters(self.rate, self.rounds, self.suffix);
+    }
+
+    /// absorbs `data`, permuting the state each time a block is complete
+    pub fn update(&mut self, data: &[u8]) {
+        self.update_observed(data, &mut ())
+    }
+
+    /// [`Shake128::update`], reporting the steps of every permutation to `observer`
+    pub fn update_observed(&mut self, mut data: &[u8], observer: &mut impl Observer<u64>) {
+        while !data.is_empty() {
+            // complete blocks are absorbed from `data` itself, without a copy into `block`
+            if self.filled == 0 && data.len() >= self.rate {
+                let (block, rest) = data.split_at(self.rate);
+                absorb_block(&mut self.state, block, self.rounds, observer);
+                data = rest;
+                continue;
+            }
+            let taken = data.len().min(self.rate - self.filled);
+            self.block[self.filled..self.filled + taken].copy_from_slice(&data[..taken]);
+            self.filled += taken;
+            data = &data[taken..];
+
+            if self.filled == self.rate {
+                absorb_block(
+                    &mut self.state,
+                    &self.block[..self.rate],
+                    self.rounds,
+                    observer,
+                );
+                self.filled = 0;
+            }
+        }
+    }
+
+    /// stops absorbing: every complete block of the prefix has already gone through the
+    /// permutation, so each [`Prefix::fork`] only pays for what is absorbed afterwards
+    pub fn freeze(self) -> Prefix {
+        Prefix(self)
+    }
+
+    /// the first `N` bytes of the hash of the concatenation of `parts`
+    /// ```
+    /// # use TD1::sponge::*;
+    /// assert_eq!(Shake128::digest::<4>(&[b"", b""]), [0x7F, 0x9C, 0x2B, 0xA4]);
+    /// ```
+    pub fn digest<const N: usize>(parts: &[&[u8]]) -> [u8; N] {
+        let mut hasher = Self::new();
+        for part in parts {
+            hasher.update(part);
+        }
+        hasher.finalize().squeeze_array()
+    }
+
+    /// pads the last block and switches to the squeezing phase
+    pub fn finalize(self) -> Squeezer {
+        self.finalize_observed(&mut ())
+    }
+
+    /// [`Shake128::finalize`], reporting the steps of the last permutations to `observer`
+    pub fn finalize_observed(mut self, observer: &mut impl Observer<u64>) -> Squeezer {
+        self.block[self.filled..].fill(0);
+        self.block[self.filled] ^= self.suffix;
+        // a suffix whose first bit of padding is the last bit of the block leaves no room for the
+        // last bit, which then needs a block of its own
+        if self.suffix >= LAST_PADDING_BIT && self.filled == self.rate - 1 {
+            absorb_block(
+                &mut self.state,
+                &self.block[..self.rate],
+                self.rounds,
+                observer,
+            );
+            self.block.fill(0);
+        }
+        self.block[self.rate - 1] ^= LAST_PADDING_BIT;
+        absorb_block(
+            &mut self.state,
+            &self.block[..self.rate],
+            self.rounds,
+            observer,
+        );
+        Squeezer::new(self.state, self.rate, self.rounds)
+    }
+}
+
+/// absorbs everything written, so that any reader can be hashed with [`io::copy`]
+/// ```
+/// # use TD1::sponge::*;
+/// let mut hasher = Shake128::new();
+/// std::io::copy(&mut &b"message"[..], &mut hasher).unwrap();
+///
+/// let mut direct = Shake128::new();
+/// direct.update(b"message");
+/// assert_eq!(hasher.finalize().squeeze_vec(32), direct.finalize().squeeze_vec(32));
+/// ```
+impl Write for Shake128 {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.update(buf);
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// SHAKE128 hasher that absorbed a common prefix and can no longer be updated itself
+/// ```
+/// # use TD1::sponge::*;
+/// let mut prefix = Shake128::new();
+/// prefix.update(b"protocol label");
+/// let prefix = prefix.freeze();
+///
+/// let mut forked = prefix.fork();
+/// forked.update(b"message");
+///
+/// let mut direct = Shake128::new();
+/// direct.update(b"protocol labelmessage");
+/// assert_eq!(forked.finalize().squeeze_vec(32), direct.finalize().squeeze_vec(32));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Prefix(Shake128);
+
+impl Prefix {
+    /// returns a hasher that already absorbed the prefix
+    pub fn fork(&self) -> Shake128 {
+        self.0.clone()
+    }
+}
+
+/// SHAKE128 hasher in its squeezing phase, producing an arbitrarily long output
+///
+/// It is an [`io::Read`] and an [`io::BufRead`] whose buffer is the rest of the current block:
+/// ```
+/// # use TD1::sponge::*;
+/// use std::io::Read;
+///
+/// let mut output = [0; 4];
+/// Shake128::new().finalize().read_exact(&mut output).unwrap();
+/// assert_eq!(output, [0x7F, 0x9C, 0x2B, 0xA4]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Squeezer {
+    state: [u64; STATE_SIZE],
+    /// rate part of `state`, as bytes
+    block: [u8; RATE_BYTES],
+    /// number of bytes of the rate already output since the last permutation
+    offset: usize,
+    /// number of bytes of `block` output per permutation
+    rate: usize,
+    /// rounds of every permutation
+    rounds: usize,
+}
+
+impl Squeezer {
+    fn new(state: [u64; STATE_SIZE], rate: usize, rounds: usize) -> Self {
+        let mut squeezer = Squeezer {
+            state,
+            block: [0; RATE_BYTES],
+            offset: 0,
+            rate,
+            rounds,
+        };
+        squeezer.unpack_block();
+        squeezer
+    }
+
+    fn unpack_block(&mut self) {
+        for (bytes, lane) in self.block.chunks_exact_mut(BYTES_PER_CHUNK).zip(self.state) {
+            bytes.copy_from_slice(&lane.to_le_bytes());
+        }
+    }
+
+    /// permutes the state once the whole block has been output
+    fn refill(&mut self, observer: &mut impl Observer<u64>) {
+        if self.offset == self.rate {
+            self.state = keccak_p_observed(self.state, self.rounds, observer);
+            self.unpack_block();
+            self.offset = 0;
+        }
+    }
+
+    /// fills `out` with the next bytes of the output
+    pub fn squeeze(&mut self, out: &mut [u8]) {
+        self.squeeze_observed(out, &mut ())
+    }
+
+    /// [`Squeezer::squeeze`], reporting the steps of every permutation to `observer`
+    pub fn squeeze_observed(&mut self, mut out: &mut [u8], observer: &mut impl Observer<u64>) {
+        while !out.is_empty() {
+            self.refill(observer);
+            let taken = out.len().min(self.rate - self.offset);
+            out[..taken].copy_from_slice(&self.block[self.offset..self.offset + taken]);
+            self.offset += taken;
+            out = &mut out[taken..];
+        }
+    }
+
+    /// returns the next `N` bytes of the output
+    pub fn squeeze_array<const N: usize>(&mut self) -> [u8; N] {
+        let mut out = [0; N];
+        self.squeeze(&mut out);
+        out
+    }
+
+    /// returns the next `size` bytes of the output
+    pub fn squeeze_vec(&mut self, size: usize) -> Vec<u8> {
+        let mut out = vec![0; size];
+        self.squeeze(&mut out);
+        out
+    }
+
+    /// returns the next `bits` bits of the output, SHAKE128(M, d) with `d = bits` for a fresh
+    /// squeezer, in `⌈bits / 8⌉` bytes masked by [`truncate_bits`]
+    ///
+    /// The rest of the last byte is dropped: the next squeeze starts at the following byte.
+    /// ```
+    /// # use TD1::sponge::*;
+    /// let output = Shake128::new().finalize().squeeze_bits(12);
+    /// assert_eq!(output, [0x7F, 0x0C]);
+    /// ```
+    pub fn squeeze_bits(&mut self, bits: usize) -> Vec<u8> {
+        let mut out = self.squeeze_vec(bits.div_ceil(BITS_PER_BYTE));
+        truncate_bits(&mut out, bits);
+        out
+    }
+}
+
+/// the output never ends, so reads always fill `buf`
+impl Read for Squeezer {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.squeeze(buf);
+        Ok(buf.len())
+    }
+}
+
+impl BufRead for Squeezer {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        self.refill(&mut ());
+        Ok(&self.block[self.offset..self.rate])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.offset = (self.offset + amt).min(self.rate);
+    }
+}
+
+/// keeps the first `bits` bits of `output`, dropping its extra bytes
+///
+/// FIPS 202 numbers the bits of a byte from the least significant one, so the first bits of the
+/// last byte are its low bits and its high bits are cleared.
+/// ```
+/// # use TD1::sponge::truncate_bits;
+/// let mut output = vec![0x7F, 0x9C, 0x2B];
+/// truncate_bits(&mut output, 13);
+/// assert_eq!(output, [0x7F, 0x1C]);
+/// ```
+pub fn truncate_bits(output: &mut Vec<u8>, bits: usize) {
+    output.truncate(bits.div_ceil(BITS_PER_BYTE));
+    let partial = bits % BITS_PER_BYTE;
+    if partial != 0 {
+        if let Some(last) = output.last_mut() {
+            *last &= (1 << partial) - 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::convert::{example_to_state, pack_bytes};
+
+    const INIT:&str = "1F 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 80 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 ";
+    const SPONGE:&str="7F 9C 2B A4 E8 8F 82 7D 61 60 45 50 76 05 85 3E D7 3B 80 93 F6 EF BC 88 EB 1A 6E AC FA 66 EF 26 3C B1 EE A9 88 00 4B 93 10 3C FB 0A EE FD 2A 68 6E 01 FA 4A 58 E8 A3 63 9C A8 A1 E3 F9 AE 57 E2 35 B8 CC 87 3C 23 DC 62 B8 D2 60 16 9A FA 2F 75 AB 91 6A 58 D9 74 91 88 35 D2 5E 6A 43 50 85 B2 BA DF D6 DF AA C3 59 A5 EF BB 7B CC 4B 59 D5 38 DF 9A 04 30 2E 10 C8 BC 1C BF 1A 0B 3A 51 20 EA 17 CD A7 CF AD 76 5F 56 23 47 4D 36 8C CC A8 AF 00 07 CD 9F 5E 4C 84 9F 16 7A 58 0B 14 AA BD EF AE E7 EE F4 7C B0 FC A9 4C CA AE BA 77 4E C2 0C FF 6A 94 85 A9 7B FC 65 AA 93 AA 4F C9 58 D1 ED B5 27 C0 2E 3A E5 7B CC ";
+
+    #[test]
+    fn init_to_sponge() {
+        let mut state = [0; STATE_SIZE];
+        let mut block = [0; RATE_BYTES];
+        block[0] = SHAKE_SUFFIX;
+        block[RATE_BYTES - 1] = LAST_PADDING_BIT;
+        assert_eq!(
+            example_to_state(INIT).unwrap()[..RATE],
+            block
+                .chunks(BYTES_PER_CHUNK)
+                .map(|lane| pack_bytes(lane.try_into().unwrap()))
+                .collect::<Vec<u64>>()
+        );
+
+        absorb_block(&mut state, &block, SHAKE_ROUNDS, &mut ());
+        assert_eq!(example_to_state(SPONGE).unwrap(), state);
+        assert_eq!(
+            example_to_state(SPONGE).unwrap(),
+            Shake128::new().finalize().state
+        );
+    }
+
+    #[test]
+    fn sponge_to_truncated() {
+        let mut squeezer =
+            Squeezer::new(example_to_state(SPONGE).unwrap(), RATE_BYTES, SHAKE_ROUNDS);
+        assert_eq!(
+            squeezer
+                .squeeze_vec(32)
+                .iter()
+                .map(|byt| format!("{byt:02X}"))
+                .collect::<String>(),
+            HASH
+        );
+    }
+    const HASH: &str = "7F9C2BA4E88F827D616045507605853ED73B8093F6EFBC88EB1A6EACFA66EF26";
+
+    #[test]
+    fn squeeze_across_blocks() {
+        let mut squeezer = Shake128::new().finalize();
+        let mut by_pieces = squeezer.squeeze_vec(100);
+        by_pieces.extend(squeezer.squeeze_vec(100));
+        assert_eq!(by_pieces, Shake128::new().finalize().squeeze_vec(200));
+    }
+
+    #[test]
+    fn buffered_reads() {
+        let expected = Shake128::new().finalize().squeeze_vec(400);
+        let mut squeezer = Shake128::new().finalize();
+        let mut output = Vec::new();
+        while output.len() < 400 {
+            let buffer = squeezer.fill_buf().unwrap();
+            // blocks are output whole, and the buffer stops at their end
+            assert!(buffer.len() <= RATE_BYTES);
+            let taken = buffer.len().min(400 - output.len()).min(50);
+            output.extend_from_slice(&buffer[..taken]);
+            squeezer.consume(taken);
+        }
+        assert_eq!(output, expected);
+
+        let mut by_reads = vec![0; 400];
+        Shake128::new()
+            .finalize()
+            .take(400)
+            .read_exact(&mut by_reads)
+            .unwrap();
+        assert_eq!(by_reads, expected);
+    }
+
+    #[test]
+    fn squeeze_bits_is_masked_prefix() {
+        let bytes = Shake128::new().finalize().squeeze_vec(4);
+        for bits in 0..=32 {
+            let output = Shake128::new().finalize().squeeze_bits(bits);
+            assert_eq!(output.len(), bits.div_ceil(BITS_PER_BYTE));
+            for (i, byt) in output.iter().enumerate() {
+                let kept = (bits - i * BITS_PER_BYTE).min(BITS_PER_BYTE);
+                assert_eq!(*byt, bytes[i] & ((1u16 << kept) - 1) as u8, "{bits} bits");
+            }
+        }
+    }
+
+    /// hashes `message` by feeding it in pieces of `piece` bytes
+    fn hash_in_pieces(message: &[u8], piece: usize) -> Vec<u8> {
+        let mut hasher = Shake128::new();
+        message.chunks(piece).for_each(|data| hasher.update(data));
+        hasher.finalize().squeeze_vec(32)
+    }
+
+    #[test]
+    fn update_is_incremental() {
+        let message = [0xA3; 3 * RATE_BYTES + 5];
+        let whole = hash_in_pieces(&message, message.len());
+        for piece in [1, 7, RATE_BYTES - 1, RATE_BYTES, RATE_BYTES + 1] {
+            assert_eq!(hash_in_pieces(&message, piece), whole);
+        }
+    }
+
+    /// `ptn(n)` of RFC 9861: `00 01 .. F9 FA 00 01 ..`, `n` bytes long
+    fn pattern(n: usize) -> Vec<u8> {
+        (0..n).map(|i| (i % 0xFB) as u8).collect()
+    }
+
+    #[test]
+    fn turbo_shake() {
+        // vectors of RFC 9861, section 5
+        let vectors = [
+            (
+                0x07,
+                vec![],
+                "5A223AD30B3B8C66A243048CFCED430F54E7529287D15150B973133ADFAC6A2F",
+            ),
+            (
+                0x07,
+                pattern(1),
+                "1AC2D450FC3B4205D19DA7BFCA1B37513C0803577AC7167F06FE2CE1F0EF39E5",
+            ),
+            (
+                0x07,
+                pattern(17),
+                "ACBD4AA57507043BCEE55AD3F48504D815E707FE82EE3DAD6D5852C8920B905E",
+            ),
+            (
+                0x07,
+                pattern(17 * 17),
+                "7A4DE8B1D927A682B929610103F0E964559BD74542CFAD740EE3D9B036469E0A",
+            ),
+            (
+                0x06,
+                vec![0xFF; 3],
+                "3D03988BB59E681851A192F429AE03988E8F444BC06036A3F1A7D2CCD758D174",
+            ),
+        ];
+        for (domain, message, expected) in vectors {
+            let mut hasher = Shake128::turbo(domain);
+            hasher.update(&message);
+            let output = hasher.finalize().squeeze_vec(32);
+            assert_eq!(
+                crate::hex::encode(&output, crate::hex::Format::UPPER),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn reset_keeps_parameters() {
+        let mut hasher = Shake128::turbo(0x07);
+        hasher.update(b"absorbed then forgotten");
+        hasher.reset();
+        assert_eq!(
+            hasher.finalize().squeeze_vec(32),
+            Shake128::turbo(0x07).finalize().squeeze_vec(32)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn turbo_domain_without_padding_bit() {
+        Shake128::turbo(0x80);
+    }
+
+    #[test]
+    fn fork_prefix() {
+        let mut prefix = Shake128::new();
+        prefix.update(&[0xA3; RATE_BYTES + 3]);
+        let prefix = prefix.freeze();
+
+        for suffix in [&b""[..], b"a", &[0x13; 2 * RATE_BYTES]] {
+            let mut forked = prefix.fork();
+            forked.update(suffix);
+
+            let mut message = vec![0xA3; RATE_BYTES + 3];
+            message.extend_from_slice(suffix);
+            assert_eq!(
+                forked.finalize().squeeze_vec(32),
+                hash_in_pieces(&message, 1)
+            );
+        }
+    }
+}
@@ -1,3097 +0,0 @@
-//! Test vectors for the permutation steps of the SHAKE128 permutation.
-//! This corresponds to a permutation of the empty input.
-
-const START: [u64; 25] = [0; 25];
-
-const THETA_0: [u64; 25] = [0; 25];
-const RHO_0: [u64; 25] = [0; 25];
-const PI_0: [u64; 25] = [0; 25];
-const CHI_0: [u64; 25] = [0; 25];
-const IOTA_0: [u64; 25] = [
-    1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-];
-
-const THETA_1: [u64; 25] = [
-    1, 1, 0, 0, 2, 0, 1, 0, 0, 2, 0, 1, 0, 0, 2, 0, 1, 0, 0, 2, 0, 1, 0, 0, 2,
-];
-const RHO_1: [u64; 25] = [
-    1,
-    2,
-    0,
-    0,
-    0x0000000010000000,
-    0,
-    0x0000100000000000,
-    0,
-    0,
-    0x0000000000200000,
-    0,
-    0x0000000000000400,
-    0,
-    0,
-    0x0000010000000000,
-    0,
-    0x0000200000000000,
-    0,
-    0,
-    0x0000000000000200,
-    0,
-    4,
-    0,
-    0,
-    0x0000000000008000,
-];
-const PI_1: [u64; 25] = [
-    1,
-    0x0000100000000000,
-    0,
-    0,
-    0x0000000000008000,
-    0,
-    0x0000000000200000,
-    0,
-    0x0000200000000000,
-    0,
-    2,
-    0,
-    0,
-    0x0000000000000200,
-    0,
-    0x0000000010000000,
-    0,
-    0x0000000000000400,
-    0,
-    0,
-    0,
-    0,
-    0x0000010000000000,
-    0,
-    4,
-];
-const CHI_1: [u64; 25] = [
-    1,
-    0x0000100000000000,
-    0x0000000000008000,
-    1,
-    0x0000100000008000,
-    0,
-    0x0000200000200000,
-    0,
-    0x0000200000000000,
-    0x0000000000200000,
-    2,
-    0x0000000000000200,
-    0,
-    0x0000000000000202,
-    0,
-    0x0000000010000400,
-    0,
-    0x0000000000000400,
-    0x0000000010000000,
-    0,
-    0x0000010000000000,
-    0,
-    0x0000010000000004,
-    0,
-    4,
-];
-const IOTA_1: [u64; 25] = [
-    0x0000000000008083,
-    0x0000100000000000,
-    0x0000000000008000,
-    1,
-    0x0000100000008000,
-    0,
-    0x0000200000200000,
-    0,
-    0x0000200000000000,
-    0x0000000000200000,
-    2,
-    0x0000000000000200,
-    0,
-    0x0000000000000202,
-    0,
-    0x0000000010000400,
-    0,
-    0x0000000000000400,
-    0x0000000010000000,
-    0,
-    0x0000010000000000,
-    0,
-    0x0000010000000004,
-    0,
-    4,
-];
-
-const THETA_2: [u64; 25] = [
-    0x0000700000600487,
-    0x0000130010018C89,
-    0x0000700020208606,
-    0x000021000041840D,
-    0x0000320030018B01,
-    0x0000700000608404,
-    0x0000230010218C89,
-    0x0000700020200606,
-    0x000001000041840C,
-    0x0000220030210B01,
-    0x0000700000608406,
-    0x0000030010018E89,
-    0x0000700020200606,
-    0x000021000041860E,
-    0x0000220030010B01,
-    0x0000700010608004,
-    0x0000030010018C89,
-    0x0000700020200206,
-    0x000021001041840C,
-    0x0000220030010B01,
-    0x0000710000608404,
-    0x0000030010018C89,
-    0x0000710020200602,
-    0x000021000041840C,
-    0x0000220030010B05,
-];
-const RHO_2: [u64; 25] = [
-    0x0000700000600487,
-    0x0000260020031912,
-    0x80001C0008082181,
-    0x00041840D0000210,
-    0x01800C5808000190,
-    0x0608404000070000,
-    0x18C8900002300102,
-    0x001C000808018180,
-    0x06000000800020C2,
-    0x20030210B0100002,
-    0x0003800003042030,
-    0x000C0040063A2400,
-    0x0030300003800101,
-    0x0000830C1C000042,
-    0x0085808000110018,
-    0xC100080000E00020,
-    0x3191200000600200,
-    0x3800101001030000,
-    0x2002083081800004,
-    0x00220030010B0100,
-    0xC400018210100001,
-    0x00000C0040063224,
-    0x40000E20040400C0,
-    0x0C00002100004184,
-    0x08800C0042C14000,
-];
-const PI_2: [u64; 25] = [
-    0x0000700000600487,
-    0x18C8900002300102,
-    0x0030300003800101,
-    0x2002083081800004,
-    0x08800C0042C14000,
-    0x00041840D0000210,
-    0x20030210B0100002,
-    0x0003800003042030,
-    0x3191200000600200,
-    0x40000E20040400C0,
-    0x0000260020031912,
-    0x001C000808018180,
-    0x0000830C1C000042,
-    0x00220030010B0100,
-    0xC400018210100001,
-    0x01800C5808000190,
-    0x0608404000070000,
-    0x000C0040063A2400,
-    0x3800101001030000,
-    0x0C00002100004184,
-    0x80001C0008082181,
-    0x06000000800020C2,
-    0x0085808000110018,
-    0xC100080000E00020,
-    0x00000C0040063224,
-];
-const CHI_2: [u64; 25] = [
-    0x0030500001E00486,
-    0x38CA983082300106,
-    0x08B0340041C14101,
-    0x2002783081A00483,
-    0x10488C0040D14100,
-    0x00049840D3042220,
-    0x11932210B0700202,
-    0x40038E20070020F0,
-    0x31953040D0600010,
-    0x60030C30241400C2,
-    0x0000A50434031950,
-    0x003E0038090A8080,
-    0xC400828E0C100043,
-    0x0022263021081812,
-    0xC41C018A18108081,
-    0x01840C580E382590,
-    0x3E08505001060000,
-    0x040C0061063A6584,
-    0x39801C4809030010,
-    0x0A08402100074184,
-    0x80859C8008192199,
-    0xC700080080E020E2,
-    0x008584804017321C,
-    0x4100180008E801A1,
-    0x06000C00C0063266,
-];
-const IOTA_2: [u64; 25] = [
-    0x8030500001E0840C,
-    0x38CA983082300106,
-    0x08B0340041C14101,
-    0x2002783081A00483,
-    0x10488C0040D14100,
-    0x00049840D3042220,
-    0x11932210B0700202,
-    0x40038E20070020F0,
-    0x31953040D0600010,
-    0x60030C30241400C2,
-    0x0000A50434031950,
-    0x003E0038090A8080,
-    0xC400828E0C100043,
-    0x0022263021081812,
-    0xC41C018A18108081,
-    0x01840C580E382590,
-    0x3E08505001060000,
-    0x040C0061063A6584,
-    0x39801C4809030010,
-    0x0A08402100074184,
-    0x80859C8008192199,
-    0xC700080080E020E2,
-    0x008584804017321C,
-    0x4100180008E801A1,
-    0x06000C00C0063266,
-];
-
-const THETA_3: [u64; 25] = [
-    0x98B0590AC86D7060,
-    0x298A1D327B0ED626,
-    0x0AB50258192BD807,
-    0xD8875F48F4F557EA,
-    0x7B161D31F07F2ADA,
-    0x1884914A1A89D64C,
-    0x00D3A712494ED522,
-    0x4206B8785FEAB9F6,
-    0xC9101738A5355379,
-    0x0B5D9D0194BA6B18,
-    0x1880AC0EFD8EED3C,
-    0x117E853AF03457A0,
-    0xC605B4D654FA9945,
-    0xF8A70148545D4B7B,
-    0xAF4290BBA8BEEB5B,
-    0x19040552C7B5D1FC,
-    0x2F48D552F838D720,
-    0x060936395ED0FC82,
-    0xC1053B307C565379,
-    0x6156D110B0A92A5E,
-    0x9805958AC194D5F5,
-    0xD6408D0279DEF7C2,
-    0x0280B2D818FDAB1A,
-    0xB9853F787DBD52C8,
-    0x6D5E9D3170A859BC,
-];
-const RHO_3: [u64; 25] = [
-    0x98B0590AC86D7060,
-    0x53143A64F61DAC4C,
-    0xC2AD4096064AF601,
-    0x8F4F557EAD8875F4,
-    0x8F83F956D3D8B0E9,
-    0xA89D64C1884914A1,
-    0xED52200D3A712494,
-    0x81AE1E17FAAE7D90,
-    0xBCE4880B9C529AA9,
-    0xD0194BA6B180B5D9,
-    0xC4056077EC7769E0,
-    0xFA14EBC0D15E8045,
-    0xD4CA2E302DA6B2A7,
-    0x90A8BA96F7F14E02,
-    0x5F75ADD7A1485DD4,
-    0x6BA3F832080AA58F,
-    0x1AE405E91AAA5F07,
-    0x9B1CAF687E410304,
-    0x660F8ACA6F3820A7,
-    0x56D110B0A92A5E61,
-    0x562B065357D66016,
-    0x59023409E77BDF0B,
-    0x4050165B031FB563,
-    0xC8B9853F787DBD52,
-    0xA74C5C2A166F1B57,
-];
-const PI_3: [u64; 25] = [
-    0x98B0590AC86D7060,
-    0xED52200D3A712494,
-    0xD4CA2E302DA6B2A7,
-    0x660F8ACA6F3820A7,
-    0xA74C5C2A166F1B57,
-    0x8F4F557EAD8875F4,
-    0xD0194BA6B180B5D9,
-    0xC4056077EC7769E0,
-    0x1AE405E91AAA5F07,
-    0x4050165B031FB563,
-    0x53143A64F61DAC4C,
-    0x81AE1E17FAAE7D90,
-    0x90A8BA96F7F14E02,
-    0x56D110B0A92A5E61,
-    0x562B065357D66016,
-    0x8F83F956D3D8B0E9,
-    0xA89D64C1884914A1,
-    0xFA14EBC0D15E8045,
-    0x9B1CAF687E410304,
-    0xC8B9853F787DBD52,
-    0xC2AD4096064AF601,
-    0xBCE4880B9C529AA9,
-    0x5F75ADD7A1485DD4,
-    0x6BA3F832080AA58F,
-    0x59023409E77BDF0B,
-];
-const CHI_3: [u64; 25] = [
-    0x8838573ACDEBE243,
-    0xCF57A0C778692494,
-    0x558A7A103DE1A9F7,
-    0x7EBF8BCAA7384087,
-    0xC20E7C2F247F1FC3,
-    0x8B4B752FE1FF3DD4,
-    0xCAF94E2EA308A3DE,
-    0x84157265ED62C980,
-    0x95EB44CDB62A1F93,
-    0x10401CDB131F356A,
-    0x43149AE4F34CAE4E,
-    0xC7FF1E37F2A46DF1,
-    0x9082BCD5A1256E14,
-    0x57C528940923D229,
-    0xD68102405F743186,
-    0xDD83725682CE30AD,
-    0xA99560E9A64817A1,
-    0xBAB5EBD7D1623C17,
-    0x9C1ED728FDC103AD,
-    0xE8A581BE707CB952,
-    0x81BC65422742B355,
-    0x9C66D82B94503AA2,
-    0x4F75A9DE463907D4,
-    0xE90EB8A4080A858F,
-    0x6542BC007F6BD7A3,
-];
-const IOTA_3: [u64; 25] = [
-    0x0838573A4DEB6243,
-    0xCF57A0C778692494,
-    0x558A7A103DE1A9F7,
-    0x7EBF8BCAA7384087,
-    0xC20E7C2F247F1FC3,
-    0x8B4B752FE1FF3DD4,
-    0xCAF94E2EA308A3DE,
-    0x84157265ED62C980,
-    0x95EB44CDB62A1F93,
-    0x10401CDB131F356A,
-    0x43149AE4F34CAE4E,
-    0xC7FF1E37F2A46DF1,
-    0x9082BCD5A1256E14,
-    0x57C528940923D229,
-    0xD68102405F743186,
-    0xDD83725682CE30AD,
-    0xA99560E9A64817A1,
-    0xBAB5EBD7D1623C17,
-    0x9C1ED728FDC103AD,
-    0xE8A581BE707CB952,
-    0x81BC65422742B355,
-    0x9C66D82B94503AA2,
-    0x4F75A9DE463907D4,
-    0xE90EB8A4080A858F,
-    0x6542BC007F6BD7A3,
-];
-
-const THETA_4: [u64; 25] = [
-    0x6E5498081D5398EC,
-    0x3AB4E2714F473DF4,
-    0x312B2233FDC87870,
-    0xD832C3778FC39E9A,
-    0x333EABFB3C2DF09F,
-    0xED27BA1DB147C77B,
-    0x3F1A0C989426BABE,
-    0xE0B42A462D4B1807,
-    0x33660C709ED1C18E,
-    0xE170CB0F0B4DDA36,
-    0x257855D6A3F454E1,
-    0x321C5C81C58A7491,
-    0xF423E4F6610CBF93,
-    0xF148602921D80C34,
-    0x27B1D5944726DEDA,
-    0xBBEFBD64D276CA02,
-    0x5C76225F91660EC1,
-    0xDE14B3F4114BED90,
-    0x3A939F95D53ADDB0,
-    0x1995566A682E560E,
-    0xE7D0AA7077FA49FA,
-    0x69859A9DA37E23C2,
-    0x2BD4F1FD8610D653,
-    0x4F83F01920F15B92,
-    0x94726BD4673938FF,
-];
-const RHO_4: [u64; 25] = [
-    0x6E5498081D5398EC,
-    0x7569C4E29E8E7BE8,
-    0x0C4AC88CFF721E1C,
-    0x78FC39E9AD832C37,
-    0xD9E16F84F999F55F,
-    0x147C77BED27BA1DB,
-    0x6BABE3F1A0C98942,
-    0x2D0A918B52C601F8,
-    0xC719B306384F68E0,
-    0xB0F0B4DDA36E170C,
-    0x2BC2AEB51FA2A709,
-    0x7172071629D244C8,
-    0x65FC9FA11F27B308,
-    0x5243B01869E290C0,
-    0x936F6D13D8EACA23,
-    0xED940577DF7AC9A4,
-    0xC1D82B8EC44BF22C,
-    0x59FA08A5F6C86F0A,
-    0xF2BAA75BB6075273,
-    0x95566A682E560E19,
-    0xA9C1DFE927EB9F42,
-    0xA6166A768DF88F09,
-    0x657A9E3FB0C21ACA,
-    0x924F83F01920F15B,
-    0x9AF519CE4E3FE51C,
-];
-const PI_4: [u64; 25] = [
-    0x6E5498081D5398EC,
-    0x6BABE3F1A0C98942,
-    0x65FC9FA11F27B308,
-    0xF2BAA75BB6075273,
-    0x9AF519CE4E3FE51C,
-    0x78FC39E9AD832C37,
-    0xB0F0B4DDA36E170C,
-    0x2BC2AEB51FA2A709,
-    0xC1D82B8EC44BF22C,
-    0x657A9E3FB0C21ACA,
-    0x7569C4E29E8E7BE8,
-    0x2D0A918B52C601F8,
-    0x5243B01869E290C0,
-    0x95566A682E560E19,
-    0xA9C1DFE927EB9F42,
-    0xD9E16F84F999F55F,
-    0x147C77BED27BA1DB,
-    0x7172071629D244C8,
-    0x59FA08A5F6C86F0A,
-    0x924F83F01920F15B,
-    0x0C4AC88CFF721E1C,
-    0xC719B306384F68E0,
-    0x936F6D13D8EACA23,
-    0xED940577DF7AC9A4,
-    0xA6166A768DF88F09,
-];
-const CHI_4: [u64; 25] = [
-    0x6A0084080275AAE4,
-    0xF9A9C3AB00C9C931,
-    0x6DB98725571F1604,
-    0x96BA275BA7474A93,
-    0x9B5E7A3FEEB7E41E,
-    0x73FE33C9B1038C36,
-    0x70E8B5D763274728,
-    0x0FE03A842F22AFCB,
-    0xD95C0A4EC94AD619,
-    0xE57A1A2BB2AE09C2,
-    0x2728E4F2B7AEEBE8,
-    0xA81EDBEB54D20FE1,
-    0x7AC22599684B0182,
-    0xC17E6A6AB6526EB1,
-    0xA1C3CEE067AB9F52,
-    0xB8E36F84D019B15F,
-    0x1CF47F1F04738AD9,
-    0xF377844620F2D499,
-    0x105A64A116516B0E,
-    0x965393CA1B42F1DB,
-    0x1C2C849D3FD29C1F,
-    0xAB89B3623F5F6964,
-    0x916D0713D86ACC2A,
-    0xE5DC85FFAD78D9B0,
-    0x650759748DF5EFE9,
-];
-const IOTA_4: [u64; 25] = [
-    0x6A00840802752A6F,
-    0xF9A9C3AB00C9C931,
-    0x6DB98725571F1604,
-    0x96BA275BA7474A93,
-    0x9B5E7A3FEEB7E41E,
-    0x73FE33C9B1038C36,
-    0x70E8B5D763274728,
-    0x0FE03A842F22AFCB,
-    0xD95C0A4EC94AD619,
-    0xE57A1A2BB2AE09C2,
-    0x2728E4F2B7AEEBE8,
-    0xA81EDBEB54D20FE1,
-    0x7AC22599684B0182,
-    0xC17E6A6AB6526EB1,
-    0xA1C3CEE067AB9F52,
-    0xB8E36F84D019B15F,
-    0x1CF47F1F04738AD9,
-    0xF377844620F2D499,
-    0x105A64A116516B0E,
-    0x965393CA1B42F1DB,
-    0x1C2C849D3FD29C1F,
-    0xAB89B3623F5F6964,
-    0x916D0713D86ACC2A,
-    0xE5DC85FFAD78D9B0,
-    0x650759748DF5EFE9,
-];
-const THETA_5: [u64; 25] = [
-    0x6AF72396B7508258,
-    0x96B24D5A3A07E83C,
-    0x0DA6AA8D9DE3F54B,
-    0xB55DF4A315A33315,
-    0xD473AC4B5BE76578,
-    0x7309945704262401,
-    0x1FF33B2659E96625,
-    0x6FFF172CE5DE4C84,
-    0xFABBD9B67BAEAF9F,
-    0xAA57CC5F07FE88A4,
-    0x27DF436C028B43DF,
-    0xC705551A6E1C2EEC,
-    0x1ADD0831A2B7E2CD,
-    0xE299B99204B61737,
-    0xEEEE1894D2FB1E34,
-    0xB814C81A653C1968,
-    0x73EFF1EE3EBDABD4,
-    0x9368A9EEEA0E37D6,
-    0x33BDB759A4B51288,
-    0xD97E45BEAE1270BD,
-    0x1CDB23038AF73428,
-    0xC4923D9305914869,
-    0xF1722ABB12962F65,
-    0xC63B56071F9CA036,
-    0x2A2A8F0038A56E8F,
-];
-const RHO_5: [u64; 25] = [
-    0x6AF72396B7508258,
-    0x2D649AB4740FD079,
-    0xC369AAA36778FD52,
-    0x315A33315B55DF4A,
-    0x5ADF3B2BC6A39D62,
-    0x4262401730994570,
-    0x966251FF33B2659E,
-    0xFFC5CB397793211B,
-    0xCFFD5DECDB3DD757,
-    0xC5F07FE88A4AA57C,
-    0x3EFA1B60145A1EF9,
-    0x155469B870BBB31C,
-    0xBF1668D6E8418D15,
-    0x24096C2E6FC53373,
-    0x7D8F1A77770C4A69,
-    0x7832D170299034CA,
-    0xB57A8E7DFE3DC7D7,
-    0x54F775071BEB49B4,
-    0xEB3496A2510677B6,
-    0x7E45BEAE1270BDD9,
-    0x8C0E2BDCD0A0736C,
-    0x1248F64C164521A7,
-    0xBE2E45576252C5EC,
-    0x36C63B56071F9CA0,
-    0xA3C00E295BA3CA8A,
-];
-const PI_5: [u64; 25] = [
-    0x6AF72396B7508258,
-    0x966251FF33B2659E,
-    0xBF1668D6E8418D15,
-    0xEB3496A2510677B6,
-    0xA3C00E295BA3CA8A,
-    0x315A33315B55DF4A,
-    0xC5F07FE88A4AA57C,
-    0x3EFA1B60145A1EF9,
-    0xB57A8E7DFE3DC7D7,
-    0xBE2E45576252C5EC,
-    0x2D649AB4740FD079,
-    0xFFC5CB397793211B,
-    0x24096C2E6FC53373,
-    0x7E45BEAE1270BDD9,
-    0x8C0E2BDCD0A0736C,
-    0x5ADF3B2BC6A39D62,
-    0x4262401730994570,
-    0x155469B870BBB31C,
-    0x54F775071BEB49B4,
-    0x36C63B56071F9CA0,
-    0xC369AAA36778FD52,
-    0xCFFD5DECDB3DD757,
-    0x7D8F1A77770C4A69,
-    0x7832D170299034CA,
-    0x1248F64C164521A7,
-];
-const CHI_5: [u64; 25] = [
-    0x43E30B967F110A59,
-    0xD642C7DF22B4173C,
-    0xBFD660DFE2E0051D,
-    0xA303B734F55677E6,
-    0x37C05E405B01AF0C,
-    0x0B5033314F45C5CB,
-    0x44F0FBF5606F647A,
-    0x34FE5A6214181ED1,
-    0xB42ABC5DE738DDD5,
-    0x7A8E099FE258E5D8,
-    0x2D6CBEB27C4BC219,
-    0xA58159B967A3AD93,
-    0xA4036D7EAF457157,
-    0x5F252E8E367F3DC8,
-    0x5E8F6AD5D330526E,
-    0x4FCB128386812F6E,
-    0x02C154103BD90DD0,
-    0x375463E874AF271C,
-    0x1CEE752EDB4B48F6,
-    0x36E67B423707DCB0,
-    0xF36BA8B04378F57A,
-    0xCFCD9CECD3ADE3D5,
-    0x7FC73C7B61494B4C,
-    0xB913D9D348A8E89A,
-    0x1EDCA3008E4023A2,
-];
-const IOTA_5: [u64; 25] = [
-    0x43E30B96FF110A58,
-    0xD642C7DF22B4173C,
-    0xBFD660DFE2E0051D,
-    0xA303B734F55677E6,
-    0x37C05E405B01AF0C,
-    0x0B5033314F45C5CB,
-    0x44F0FBF5606F647A,
-    0x34FE5A6214181ED1,
-    0xB42ABC5DE738DDD5,
-    0x7A8E099FE258E5D8,
-    0x2D6CBEB27C4BC219,
-    0xA58159B967A3AD93,
-    0xA4036D7EAF457157,
-    0x5F252E8E367F3DC8,
-    0x5E8F6AD5D330526E,
-    0x4FCB128386812F6E,
-    0x02C154103BD90DD0,
-    0x375463E874AF271C,
-    0x1CEE752EDB4B48F6,
-    0x36E67B423707DCB0,
-    0xF36BA8B04378F57A,
-    0xCFCD9CECD3ADE3D5,
-    0x7FC73C7B61494B4C,
-    0xB913D9D348A8E89A,
-    0x1EDCA3008E4023A2,
-];
-const THETA_6: [u64; 25] = [
-    0x8C67B401B6278C51,
-    0xC04DEB59B3E4CD34,
-    0x9E0ADF8540085AE2,
-    0xB34C75F51F50BE7D,
-    0x68CFAF16FF3E37A6,
-    0xC4D48CA6067343C2,
-    0x52FFD773F13FBE72,
-    0x1522E538B6F0412E,
-    0xA4657E9C0D3E144E,
-    0x2581F8C946677D72,
-    0xE2E80125357D4410,
-    0xB38E753FF6F3779B,
-    0x85DFD2240DAD2EA8,
-    0x4F6AEC4FDC79F453,
-    0x01809B83770FCAC4,
-    0x804FAD14CFB7A967,
-    0x14CE7896AA89D7D8,
-    0x1688DCB2D64778E3,
-    0x0CA1B7EF314D816D,
-    0x69E98A149338441A,
-    0x3CEF17270A4E7373,
-    0xD9C2B06A42FD39DD,
-    0x5E1B8321C3A114B3,
-    0xA95C1B12A2AE2101,
-    0x41D352562A7FBB08,
-];
-const RHO_6: [u64; 25] = [
-    0x8C67B401B6278C51,
-    0x809BD6B367C99A69,
-    0xA782B7E1500216B8,
-    0x51F50BE7DB34C75F,
-    0xB7F9F1BD33467D78,
-    0x67343C2C4D48CA60,
-    0xFBE7252FFD773F13,
-    0x48B94E2DBC104B85,
-    0x275232BF4E069F0A,
-    0x8C946677D722581F,
-    0x17400929ABEA2087,
-    0x39D4FFDBCDDE6ECE,
-    0x6975442EFE91206D,
-    0x9FB8F3E8A69ED5D8,
-    0x87E56200C04DC1BB,
-    0x6F52CF009F5A299F,
-    0x3AFB0299CF12D551,
-    0x6E596B23BC718B44,
-    0xFDE629B02DA19436,
-    0xE98A149338441A69,
-    0x5C9C2939CDCCF3BC,
-    0x670AC1A90BF4E777,
-    0x6BC3706438742296,
-    0x01A95C1B12A2AE21,
-    0xD4958A9FEEC21074,
-];
-const PI_6: [u64; 25] = [
-    0x8C67B401B6278C51,
-    0xFBE7252FFD773F13,
-    0x6975442EFE91206D,
-    0xFDE629B02DA19436,
-    0xD4958A9FEEC21074,
-    0x51F50BE7DB34C75F,
-    0x8C946677D722581F,
-    0x17400929ABEA2087,
-    0x3AFB0299CF12D551,
-    0x6BC3706438742296,
-    0x809BD6B367C99A69,
-    0x48B94E2DBC104B85,
-    0x9FB8F3E8A69ED5D8,
-    0xE98A149338441A69,
-    0x5C9C2939CDCCF3BC,
-    0xB7F9F1BD33467D78,
-    0x67343C2C4D48CA60,
-    0x39D4FFDBCDDE6ECE,
-    0x6E596B23BC718B44,
-    0x01A95C1B12A2AE21,
-    0xA782B7E1500216B8,
-    0x275232BF4E069F0A,
-    0x87E56200C04DC1BB,
-    0x6F52CF009F5A299F,
-    0x670AC1A90BF4E777,
-];
-const CHI_6: [u64; 25] = [
-    0x8C77F401B4A78C3D,
-    0x6F650CBFFC57AB01,
-    0x6964C6213CD3202D,
-    0xF5841DB03D841837,
-    0xA7158BB1A7922376,
-    0x42B502EFF3FCE7DF,
-    0xA42F64E793328D4F,
-    0x5640794D9B8E0201,
-    0x2ACF091A0C121018,
-    0xE7C314743C763A96,
-    0x179B677365470E31,
-    0x28BB4A3EA45041A4,
-    0x8BACDAC06316344C,
-    0x6989C2111A451228,
-    0x14BC213555DCB238,
-    0xAF39326EB3D059F6,
-    0x213D3C0C7D694B60,
-    0x3874EBC3CF5C4AEF,
-    0xD809CA879D35DA1C,
-    0x41AD501B5EAA2C21,
-    0x2727F7E1D04B5609,
-    0x4F40BFBF5114B70E,
-    0x87ED62A9C0E907DB,
-    0xEFD2F940CF583917,
-    0x675AC1B705F06E75,
-];
-const IOTA_6: [u64; 25] = [
-    0x0C77F40134A70CBC,
-    0x6F650CBFFC57AB01,
-    0x6964C6213CD3202D,
-    0xF5841DB03D841837,
-    0xA7158BB1A7922376,
-    0x42B502EFF3FCE7DF,
-    0xA42F64E793328D4F,
-    0x5640794D9B8E0201,
-    0x2ACF091A0C121018,
-    0xE7C314743C763A96,
-    0x179B677365470E31,
-    0x28BB4A3EA45041A4,
-    0x8BACDAC06316344C,
-    0x6989C2111A451228,
-    0x14BC213555DCB238,
-    0xAF39326EB3D059F6,
-    0x213D3C0C7D694B60,
-    0x3874EBC3CF5C4AEF,
-    0xD809CA879D35DA1C,
-    0x41AD501B5EAA2C21,
-    0x2727F7E1D04B5609,
-    0x4F40BFBF5114B70E,
-    0x87ED62A9C0E907DB,
-    0xEFD2F940CF583917,
-    0x675AC1B705F06E75,
-];
-const THETA_7: [u64; 25] = [
-    0x65F398F66F54D239,
-    0xA8018120AA2CF704,
-    0xE6DBAD0C28E649B0,
-    0x1BAFAFCFDCBF907B,
-    0x8482C6E85D230F21,
-    0x2B316E18A80F395A,
-    0x634BE978C549D14A,
-    0xD9FF12608FBB6B9C,
-    0xC4E4BB65ED299854,
-    0xC454592DC6C716C1,
-    0x7E1F0B843EB4D0B4,
-    0xEFDFC7A1F22B1DA1,
-    0x0413B1ED77235DD1,
-    0x87A2706EFB7E9A64,
-    0x372B6C6CAF6D9E6F,
-    0xC6BD5E99E8238773,
-    0xE659B1932B121765,
-    0xB7CB80EEDB692372,
-    0x362278F87C0E5250,
-    0x623A1D42A41B0076,
-    0x4EA39B168BB8888C,
-    0x88243220076FEB0B,
-    0x08520984D4DC6E46,
-    0x01F94B3F2E63B15B,
-    0x44CD8CEEFF414222,
-];
-const RHO_7: [u64; 25] = [
-    0x65F398F66F54D239,
-    0x500302415459EE09,
-    0x39B6EB430A39926C,
-    0xFDCBF907B1BAFAFC,
-    0x42E918790C241637,
-    0x80F395A2B316E18A,
-    0x9D14A634BE978C54,
-    0x7FC49823EEDAE736,
-    0x2A62725DB2F694CC,
-    0x92DC6C716C1C4545,
-    0xF0F85C21F5A685A3,
-    0x7F1E87C8AC7687BF,
-    0x1AEE88209D8F6BB9,
-    0xDDF6FD34C90F44E0,
-    0xB6CF379B95B63657,
-    0x470EE78D7ABD33D0,
-    0x42ECBCCB36326562,
-    0xC0776DB491B95BE5,
-    0x1F0F81CA4A06C44F,
-    0x3A1D42A41B007662,
-    0x6C5A2EE222313A8E,
-    0x2090C8801DBFAC2E,
-    0xC10A41309A9B8DC8,
-    0x5B01F94B3F2E63B1,
-    0x633BBFD050889133,
-];
-const PI_7: [u64; 25] = [
-    0x65F398F66F54D239,
-    0x9D14A634BE978C54,
-    0x1AEE88209D8F6BB9,
-    0x1F0F81CA4A06C44F,
-    0x633BBFD050889133,
-    0xFDCBF907B1BAFAFC,
-    0x92DC6C716C1C4545,
-    0xF0F85C21F5A685A3,
-    0x42ECBCCB36326562,
-    0xC10A41309A9B8DC8,
-    0x500302415459EE09,
-    0x7FC49823EEDAE736,
-    0xDDF6FD34C90F44E0,
-    0x3A1D42A41B007662,
-    0x6C5A2EE222313A8E,
-    0x42E918790C241637,
-    0x80F395A2B316E18A,
-    0x7F1E87C8AC7687BF,
-    0xC0776DB491B95BE5,
-    0x5B01F94B3F2E63B1,
-    0x39B6EB430A39926C,
-    0x2A62725DB2F694CC,
-    0xB6CF379B95B63657,
-    0x470EE78D7ABD33D0,
-    0x2090C8801DBFAC2E,
-];
-const CHI_7: [u64; 25] = [
-    0x671990F66E5CB190,
-    0x9815A7FEFC970812,
-    0x7ADEB6308D077A89,
-    0x1BCF81EC65528647,
-    0xFB3F99D0C00B9D77,
-    0x9DEBE90720187A5E,
-    0x90D8CCBB6E0C2505,
-    0x71FA1D117D2F0D2B,
-    0x7E2D04CC17121756,
-    0xC31E4540D69F88C9,
-    0xD0316755555CEEC9,
-    0x5DCD9AA3FCDAD534,
-    0x99B4D176E93E4C6C,
-    0x2A1C42A54F48B263,
-    0x439EB6C088B33BB8,
-    0x3DE51A3100441002,
-    0x0092FD96A29FB9CA,
-    0x641E17838270A7AF,
-    0xC09F6D8491B94FE3,
-    0xDB137CC98C3C8239,
-    0xAD3BEEC10F39B07F,
-    0x6B62B259D8FF954C,
-    0x965F3F9B90B4BA79,
-    0x5E28C4CE78BD2190,
-    0x22D0D89CAD79A8AE,
-];
-const IOTA_7: [u64; 25] = [
-    0xE71990F66E5C3199,
-    0x9815A7FEFC970812,
-    0x7ADEB6308D077A89,
-    0x1BCF81EC65528647,
-    0xFB3F99D0C00B9D77,
-    0x9DEBE90720187A5E,
-    0x90D8CCBB6E0C2505,
-    0x71FA1D117D2F0D2B,
-    0x7E2D04CC17121756,
-    0xC31E4540D69F88C9,
-    0xD0316755555CEEC9,
-    0x5DCD9AA3FCDAD534,
-    0x99B4D176E93E4C6C,
-    0x2A1C42A54F48B263,
-    0x439EB6C088B33BB8,
-    0x3DE51A3100441002,
-    0x0092FD96A29FB9CA,
-    0x641E17838270A7AF,
-    0xC09F6D8491B94FE3,
-    0xDB137CC98C3C8239,
-    0xAD3BEEC10F39B07F,
-    0x6B62B259D8FF954C,
-    0x965F3F9B90B4BA79,
-    0x5E28C4CE78BD2190,
-    0x22D0D89CAD79A8AE,
-];
-const THETA_8: [u64; 25] = [
-    0x188422A1F97D9C42,
-    0x63AAE934FF564151,
-    0xE6BCD586313E342F,
-    0x7FE74FA81044A97C,
-    0x5E4D23B73CCDDA90,
-    0x62765B50B739D785,
-    0x6B6782716DCD6C46,
-    0xED987EA7C116438D,
-    0x1A05CA886204386D,
-    0x666CFF272A59CF2E,
-    0x2FACD502C27D4312,
-    0xA672D469FF1B9C77,
-    0x05D6B2C0550702CA,
-    0x4E348CE13A5E9D58,
-    0xE6EC0CA774757C5F,
-    0xC278A8669765BDD9,
-    0xFB2DB35CA15EF089,
-    0xF87C74353E49E909,
-    0xA4B7A3C0E4AF60D8,
-    0x7E61C6AE70FAC5DE,
-    0x52A65C9698181DA4,
-    0x90DDFC93DB3EDC0F,
-    0x0A3D5C2D2C8DF4DF,
-    0x3A000A8A0DAB0EAB,
-    0x87A262FB51BFEF49,
-];
-const RHO_8: [u64; 25] = [
-    0x188422A1F97D9C42,
-    0xC755D269FEAC82A2,
-    0xF9AF35618C4F8D0B,
-    0x81044A97C7FE74FA,
-    0xB9E66ED482F2691D,
-    0x739D78562765B50B,
-    0xD6C466B6782716DC,
-    0x661FA9F04590E37B,
-    0x368D02E54431021C,
-    0xF272A59CF2E666CF,
-    0x7D66A81613EA1891,
-    0xCB51A7FC6E71DE99,
-    0x3816502EB59602A8,
-    0xC274BD3AB09C6919,
-    0x3ABE2FF3760653BA,
-    0xCB7BB384F150CD2E,
-    0xDE113F65B66B942B,
-    0x3A1A9F24F484FC3E,
-    0x781C95EC1B1496F4,
-    0x61C6AE70FAC5DE7E,
-    0x725A606076914A99,
-    0x4377F24F6CFB703E,
-    0xE147AB85A591BE9B,
-    0xAB3A000A8A0DAB0E,
-    0x98BED46FFBD261E8,
-];
-const PI_8: [u64; 25] = [
-    0x188422A1F97D9C42,
-    0xD6C466B6782716DC,
-    0x3816502EB59602A8,
-    0x781C95EC1B1496F4,
-    0x98BED46FFBD261E8,
-    0x81044A97C7FE74FA,
-    0xF272A59CF2E666CF,
-    0x7D66A81613EA1891,
-    0xDE113F65B66B942B,
-    0xE147AB85A591BE9B,
-    0xC755D269FEAC82A2,
-    0x661FA9F04590E37B,
-    0xC274BD3AB09C6919,
-    0x61C6AE70FAC5DE7E,
-    0x725A606076914A99,
-    0xB9E66ED482F2691D,
-    0x739D78562765B50B,
-    0xCB51A7FC6E71DE99,
-    0x3A1A9F24F484FC3E,
-    0xAB3A000A8A0DAB0E,
-    0xF9AF35618C4F8D0B,
-    0x368D02E54431021C,
-    0x3ABE2FF3760653BA,
-    0xCB7BB384F150CD2E,
-    0x4377F24F6CFB703E,
-];
-const CHI_8: [u64; 25] = [
-    0x309632A97CED9C62,
-    0x96CCE37672278288,
-    0xB8B4102D555463A0,
-    0x781CB76C1B390AF6,
-    0x5EFE9079FBD06374,
-    0x8C004295C6F66CEA,
-    0x7063B2FD56E7E2E5,
-    0x5C202896127A3201,
-    0xDE117F77F405D44B,
-    0x93350E8D9591BC9E,
-    0x4735C6634EA08AA2,
-    0x479DABB00FD1751D,
-    0xD06CFD3AB48C6998,
-    0xE4C33C7972E95E5C,
-    0x525049F077812BC0,
-    0x31A6E97CCAE2238D,
-    0x43976056B7E1952D,
-    0x4A71A7F66478DD99,
-    0x2ADEF1F0F476BC2F,
-    0xE9231008AF083F0C,
-    0xF19D1873BE49DCA9,
-    0xF7CC92E1C5618E18,
-    0x3ABA6FB87AAD63AA,
-    0x73F3B6A47154402F,
-    0x4577F0CB2CCB722A,
-];
-const IOTA_8: [u64; 25] = [
-    0x309632A97CED9CE8,
-    0x96CCE37672278288,
-    0xB8B4102D555463A0,
-    0x781CB76C1B390AF6,
-    0x5EFE9079FBD06374,
-    0x8C004295C6F66CEA,
-    0x7063B2FD56E7E2E5,
-    0x5C202896127A3201,
-    0xDE117F77F405D44B,
-    0x93350E8D9591BC9E,
-    0x4735C6634EA08AA2,
-    0x479DABB00FD1751D,
-    0xD06CFD3AB48C6998,
-    0xE4C33C7972E95E5C,
-    0x525049F077812BC0,
-    0x31A6E97CCAE2238D,
-    0x43976056B7E1952D,
-    0x4A71A7F66478DD99,
-    0x2ADEF1F0F476BC2F,
-    0xE9231008AF083F0C,
-    0xF19D1873BE49DCA9,
-    0xF7CC92E1C5618E18,
-    0x3ABA6FB87AAD63AA,
-    0x73F3B6A47154402F,
-    0x4577F0CB2CCB722A,
-];
-const THETA_9: [u64; 25] = [
-    0x298B147655CC396E,
-    0x2532BFB928D80B18,
-    0x9A1A7ECD3D2B9427,
-    0x5BB1D52CC249FEE4,
-    0x322DADEEE306149D,
-    0x951D644AEFD7C96C,
-    0xC39DEE320C186B75,
-    0x7E8E46767A05C586,
-    0xFDBC1D372D752059,
-    0xFFE6331A8D47CB77,
-    0x5E28E0BC67812F24,
-    0xF463F77F552EFC8D,
-    0xF2C293DADCF39E1F,
-    0xC76E5E39AB99AA4E,
-    0x3E8374676F575C29,
-    0x28BBCFA3E3C3860B,
-    0xF0693C99ED1E1CBD,
-    0x68DFC9160C072A1E,
-    0x097393B02D06483D,
-    0x85F02D9FB7DE48E5,
-    0xE8803EAC9768792F,
-    0x4432CE2E9F9E0788,
-    0x1814015812D2942D,
-    0x505ED4E4A824B43D,
-    0x29A4CD5C341D05C3,
-];
-const RHO_9: [u64; 25] = [
-    0x298B147655CC396E,
-    0x4A657F7251B01630,
-    0xE6869FB34F4AE509,
-    0xCC249FEE45BB1D52,
-    0x771830A4E9916D6F,
-    0xFD7C96C951D644AE,
-    0x86B75C39DEE320C1,
-    0xA3919D9E8171619F,
-    0x2CFEDE0E9B96BA90,
-    0x31A8D47CB77FFE63,
-    0xF14705E33C097922,
-    0x8FDDFD54BBF237D1,
-    0x9CF0FF96149ED6E7,
-    0x735733549D8EDCBC,
-    0xABAE149F41BA33B7,
-    0x870C1651779F47C7,
-    0xC397BE0D27933DA3,
-    0xE48B0603950F346F,
-    0x7605A0C907A12E72,
-    0xF02D9FB7DE48E585,
-    0xFAB25DA1E4BFA200,
-    0x10CB38BA7E781E21,
-    0xA302802B025A5285,
-    0x3D505ED4E4A824B4,
-    0x33570D074170CA69,
-];
-const PI_9: [u64; 25] = [
-    0x298B147655CC396E,
-    0x86B75C39DEE320C1,
-    0x9CF0FF96149ED6E7,
-    0x7605A0C907A12E72,
-    0x33570D074170CA69,
-    0xCC249FEE45BB1D52,
-    0x31A8D47CB77FFE63,
-    0xF14705E33C097922,
-    0xC397BE0D27933DA3,
-    0xA302802B025A5285,
-    0x4A657F7251B01630,
-    0xA3919D9E8171619F,
-    0x735733549D8EDCBC,
-    0xF02D9FB7DE48E585,
-    0xFAB25DA1E4BFA200,
-    0x771830A4E9916D6F,
-    0xFD7C96C951D644AE,
-    0x8FDDFD54BBF237D1,
-    0xE48B0603950F346F,
-    0x3D505ED4E4A824B4,
-    0xE6869FB34F4AE509,
-    0x2CFEDE0E9B96BA90,
-    0xABAE149F41BA33B7,
-    0x870C1651779F47C7,
-    0x10CB38BA7E781E21,
-];
-const CHI_9: [u64; 25] = [
-    0x31CBB7F055D0EF48,
-    0xE4B25C70DDC208D1,
-    0x9DA2F29054CE16EE,
-    0x7E8DB0B9132D1F74,
-    0xB563450ECB53CAE8,
-    0x0C639E6D4DBB1C52,
-    0x33386E70B4EDFAE2,
-    0xD14705C13C413B26,
-    0x8FB3A1C9623230F1,
-    0x928AC03BB01EB0A4,
-    0x1A235D324D3E8A10,
-    0x23B9113DC331409E,
-    0x79C57354BD39DEBC,
-    0xF068BDE5CF48F1B5,
-    0x5B22DD2D64FEC38F,
-    0x759959B043B15E3E,
-    0x9D7E94CA55DB4480,
-    0x968DA580DB523741,
-    0xA68326239C1E7D24,
-    0xB534D89DF4EE2434,
-    0x65869F220F62E42E,
-    0x28FEDC4EAD93FED0,
-    0xBB6D3C3549DA2B97,
-    0x61089150769DA6CF,
-    0x18B378B6EEEC04B1,
-];
-const IOTA_9: [u64; 25] = [
-    0x31CBB7F055D0EFC0,
-    0xE4B25C70DDC208D1,
-    0x9DA2F29054CE16EE,
-    0x7E8DB0B9132D1F74,
-    0xB563450ECB53CAE8,
-    0x0C639E6D4DBB1C52,
-    0x33386E70B4EDFAE2,
-    0xD14705C13C413B26,
-    0x8FB3A1C9623230F1,
-    0x928AC03BB01EB0A4,
-    0x1A235D324D3E8A10,
-    0x23B9113DC331409E,
-    0x79C57354BD39DEBC,
-    0xF068BDE5CF48F1B5,
-    0x5B22DD2D64FEC38F,
-    0x759959B043B15E3E,
-    0x9D7E94CA55DB4480,
-    0x968DA580DB523741,
-    0xA68326239C1E7D24,
-    0xB534D89DF4EE2434,
-    0x65869F220F62E42E,
-    0x28FEDC4EAD93FED0,
-    0xBB6D3C3549DA2B97,
-    0x61089150769DA6CF,
-    0x18B378B6EEEC04B1,
-];
-const THETA_10: [u64; 25] = [
-    0x63E198B1F4CD677C,
-    0xE2A6D52D4A391407,
-    0x51ABAEE5AF3015A4,
-    0xC4D45D6F5F70C25B,
-    0x1C973A92AC8A4817,
-    0x5E49B12CECA694EE,
-    0x352CE72D2316E634,
-    0x1D4E59B4C7BF386C,
-    0x35EA4C1F2E6FEDDE,
-    0x3B7EBFA7D7C7325B,
-    0x48097273EC2302AC,
-    0x25AD986054CA5C48,
-    0xB5CC2F2146C7DDF6,
-    0x4A31503383152C9A,
-    0xF2D6A2B103274170,
-    0x27B376F1E2ACD682,
-    0x9B6A1D97C2205856,
-    0x5A84F9F520AC340B,
-    0x1CDACBF5D043A00B,
-    0x1CC0A7019337A6CB,
-    0x37ACB063AE7F6C92,
-    0x2EEA55133A68E206,
-    0x77646040B22428DD,
-    0xDB517C863AC07BE0,
-    0xB147072A8935864E,
-];
-const RHO_10: [u64; 25] = [
-    0x63E198B1F4CD677C,
-    0xC54DAA5A9472280F,
-    0x146AEBB96BCC0569,
-    0xF5F70C25BC4D45D6,
-    0x95645240B8E4B9D4,
-    0xCA694EE5E49B12CE,
-    0x6E634352CE72D231,
-    0x53966D31EFCE1B07,
-    0xEF1AF5260F9737F6,
-    0xFA7D7C7325B3B7EB,
-    0x404B939F61181562,
-    0xB661815329712096,
-    0x3EEFB5AE61790A36,
-    0x67062A59349462A0,
-    0x93A0B8796B515881,
-    0x59AD044F66EDE3C5,
-    0x0B0AD36D43B2F844,
-    0x7CFA90561A05AD42,
-    0x7EBA087401639B59,
-    0xC0A7019337A6CB1C,
-    0xC18EB9FDB248DEB2,
-    0xBBA9544CE9A38818,
-    0xAEEC8C081644851B,
-    0xE0DB517C863AC07B,
-    0xC1CAA24D6193AC51,
-];
-const PI_10: [u64; 25] = [
-    0x63E198B1F4CD677C,
-    0x6E634352CE72D231,
-    0x3EEFB5AE61790A36,
-    0x7EBA087401639B59,
-    0xC1CAA24D6193AC51,
-    0xF5F70C25BC4D45D6,
-    0xFA7D7C7325B3B7EB,
-    0x404B939F61181562,
-    0x0B0AD36D43B2F844,
-    0xAEEC8C081644851B,
-    0xC54DAA5A9472280F,
-    0x53966D31EFCE1B07,
-    0x67062A59349462A0,
-    0xC0A7019337A6CB1C,
-    0xC18EB9FDB248DEB2,
-    0x95645240B8E4B9D4,
-    0xCA694EE5E49B12CE,
-    0xB661815329712096,
-    0x7CFA90561A05AD42,
-    0xE0DB517C863AC07B,
-    0x146AEBB96BCC0569,
-    0xEF1AF5260F9737F6,
-    0x93A0B8796B515881,
-    0x59AD044F66EDE3C5,
-    0xBBA9544CE9A38818,
-];
-const CHI_10: [u64; 25] = [
-    0x736D2C1DD5C46F7A,
-    0x2E734B02CE704378,
-    0xBFAF17A701E92E36,
-    0x5C9B10C4952FD875,
-    0xCDC8E10F6BA13C50,
-    0xF5F58FA9FC4545D6,
-    0xF17D3C1327115FEF,
-    0xE4AF9F9F755C1079,
-    0x5A19D348EBBBB880,
-    0xA4E4FC5A17F63732,
-    0xE14DA812846248AF,
-    0xD3376CB3ECEC921B,
-    0x660E9235B4DC7602,
-    0xC4E603913394EB11,
-    0xD31CFCDCD9C4CDB2,
-    0xA164D352B18499C4,
-    0x82F35EE1F69F9F8E,
-    0x3660C07BAD4B60AF,
-    0x69DE925622C194C6,
-    0xAAD25DD9C221C271,
-    0x04CAE3E00B8C4D68,
-    0xA717F1200B3B94B2,
-    0x31A0E879E2535099,
-    0x5DEFAFFE64A1E6A4,
-    0x50B9404AEDB0BA8E,
-];
-const IOTA_10: [u64; 25] = [
-    0x736D2C1D55C4EF73,
-    0x2E734B02CE704378,
-    0xBFAF17A701E92E36,
-    0x5C9B10C4952FD875,
-    0xCDC8E10F6BA13C50,
-    0xF5F58FA9FC4545D6,
-    0xF17D3C1327115FEF,
-    0xE4AF9F9F755C1079,
-    0x5A19D348EBBBB880,
-    0xA4E4FC5A17F63732,
-    0xE14DA812846248AF,
-    0xD3376CB3ECEC921B,
-    0x660E9235B4DC7602,
-    0xC4E603913394EB11,
-    0xD31CFCDCD9C4CDB2,
-    0xA164D352B18499C4,
-    0x82F35EE1F69F9F8E,
-    0x3660C07BAD4B60AF,
-    0x69DE925622C194C6,
-    0xAAD25DD9C221C271,
-    0x04CAE3E00B8C4D68,
-    0xA717F1200B3B94B2,
-    0x31A0E879E2535099,
-    0x5DEFAFFE64A1E6A4,
-    0x50B9404AEDB0BA8E,
-];
-const THETA_11: [u64; 25] = [
-    0x608DB8C02F955A3C,
-    0x9994140947798528,
-    0x7AD958AEEF01588B,
-    0xE6E2DAFE0E5BDC50,
-    0xBF6B6A934F17A89B,
-    0xE6151B748614F099,
-    0x469A6318AE1899BF,
-    0x21D9D0969BB466C4,
-    0xE060197270CFBCA5,
-    0xD64777C63340A3F9,
-    0xF2AD3CCFFE33FDE0,
-    0x64D033B865E5544B,
-    0xA378DD3C5A3400BF,
-    0x7E9FC9ABA8E0EF34,
-    0xA1BF7740FD725979,
-    0xB284478FCBD52C8B,
-    0x351401EA7F9659DE,
-    0xF3168F7243A31612,
-    0xD3A7586CB9B590E3,
-    0xD871D645E69756BA,
-    0x172A773D71DDF827,
-    0x10F0AE2B823252E2,
-    0xF4D6A7700CBB2624,
-    0xE79665C4FFD5E281,
-    0x221ACBD6C9062E45,
-];
-const RHO_11: [u64; 25] = [
-    0x608DB8C02F955A3C,
-    0x332828128EF30A51,
-    0xDEB6562BBBC05622,
-    0xE0E5BDC50E6E2DAF,
-    0x9A78BD44DDFB5B54,
-    0x614F099E6151B748,
-    0x899BF469A6318AE1,
-    0x767425A6ED19B108,
-    0x52F0300CB93867DE,
-    0x7C63340A3F9D6477,
-    0x9569E67FF19FEF07,
-    0x40CEE19795512D93,
-    0xA005FD1BC6E9E2D1,
-    0x5751C1DE68FD3F93,
-    0xB92CBCD0DFBBA07E,
-    0xAA591765088F1F97,
-    0xCB3BC6A2803D4FF2,
-    0x47B921D18B09798B,
-    0x0D9736B21C7A74EB,
-    0x71D645E69756BAD8,
-    0xDCF5C777E09C5CA9,
-    0x43C2B8AE08C94B88,
-    0x9E9AD4EE019764C4,
-    0x81E79665C4FFD5E2,
-    0xB2F5B2418B914886,
-];
-const PI_11: [u64; 25] = [
-    0x608DB8C02F955A3C,
-    0x899BF469A6318AE1,
-    0xA005FD1BC6E9E2D1,
-    0x0D9736B21C7A74EB,
-    0xB2F5B2418B914886,
-    0xE0E5BDC50E6E2DAF,
-    0x7C63340A3F9D6477,
-    0x9569E67FF19FEF07,
-    0xCB3BC6A2803D4FF2,
-    0x9E9AD4EE019764C4,
-    0x332828128EF30A51,
-    0x767425A6ED19B108,
-    0x5751C1DE68FD3F93,
-    0x71D645E69756BAD8,
-    0xDCF5C777E09C5CA9,
-    0x9A78BD44DDFB5B54,
-    0x614F099E6151B748,
-    0x40CEE19795512D93,
-    0x47B921D18B09798B,
-    0x81E79665C4FFD5E2,
-    0xDEB6562BBBC05622,
-    0x52F0300CB93867DE,
-    0xB92CBCD0DFBBA07E,
-    0xAA591765088F1F97,
-    0x43C2B8AE08C94B88,
-];
-const CHI_11: [u64; 25] = [
-    0x4089B1D26F5D3A2C,
-    0x8409F6C9BE239ECB,
-    0x12657D5A4568EAD5,
-    0x4D9F3E32387E66D3,
-    0x3BE7F6680BB1C847,
-    0x61ED7FB0CE6CA6AF,
-    0x3671348A3FBD6487,
-    0x81E9F633F01DCF03,
-    0xAB5EEFA38E5546D9,
-    0x8298D4E430062494,
-    0x3229E84A8E1704C2,
-    0x56F221867A1B3140,
-    0xDB7043CF08757BB2,
-    0x52DE6DE69935B888,
-    0x98A1C2D38194EDA1,
-    0x9AF85D4549FB53C7,
-    0x667E09DE6B59E740,
-    0xC08877B3D1A7A9F3,
-    0x5DA108D19209739F,
-    0xE0E096FFE4FF71EA,
-    0x77BADAFBFD43D602,
-    0x50A13329B93C785F,
-    0xF8AE145ADFFBE076,
-    0x366D5164BB8F0BB5,
-    0x438298AA08F16A54,
-];
-const IOTA_11: [u64; 25] = [
-    0x4089B1D2EF5D3A26,
-    0x8409F6C9BE239ECB,
-    0x12657D5A4568EAD5,
-    0x4D9F3E32387E66D3,
-    0x3BE7F6680BB1C847,
-    0x61ED7FB0CE6CA6AF,
-    0x3671348A3FBD6487,
-    0x81E9F633F01DCF03,
-    0xAB5EEFA38E5546D9,
-    0x8298D4E430062494,
-    0x3229E84A8E1704C2,
-    0x56F221867A1B3140,
-    0xDB7043CF08757BB2,
-    0x52DE6DE69935B888,
-    0x98A1C2D38194EDA1,
-    0x9AF85D4549FB53C7,
-    0x667E09DE6B59E740,
-    0xC08877B3D1A7A9F3,
-    0x5DA108D19209739F,
-    0xE0E096FFE4FF71EA,
-    0x77BADAFBFD43D602,
-    0x50A13329B93C785F,
-    0xF8AE145ADFFBE076,
-    0x366D5164BB8F0BB5,
-    0x438298AA08F16A54,
-];
-const THETA_12: [u64; 25] = [
-    0x669EEDBCEAB088CD,
-    0x9BB301C0C305AC87,
-    0x7F976FEC61B97F97,
-    0x383C4969277844AB,
-    0x182B50863A1513F2,
-    0x47FA23DECB811444,
-    0x29CBC383429B56CB,
-    0xEC1BE485D4CC5A41,
-    0xDEFD98F8915364A1,
-    0xA154720A01A2FF21,
-    0x143EB4248BFAB629,
-    0x4948D68F073D030C,
-    0xB68251792CA4EEF0,
-    0x277D1ABD86339AF0,
-    0xBB6D643DB0303614,
-    0xBCEF012B4C16E12C,
-    0x79C4FED7167FD50C,
-    0xAD7A6505F5763CB1,
-    0x28027F8A8D0F51E7,
-    0xC32C3011D55BAA5F,
-    0x51AD8695F8AE64E9,
-    0x4F1BC420C41A4A13,
-    0x955C06ECFB2A7534,
-    0x43CE263FA48929CD,
-    0x604E3E443955B1E1,
-];
-const RHO_12: [u64; 25] = [
-    0x669EEDBCEAB088CD,
-    0x37660381860B590F,
-    0xDFE5DBFB186E5FE5,
-    0x9277844AB383C496,
-    0x31D0A89F90C15A84,
-    0xB81144447FA23DEC,
-    0xB56CB29CBC383429,
-    0x06F921753316907B,
-    0x50EF7ECC7C48A9B2,
-    0x20A01A2FF21A1547,
-    0xA1F5A1245FD5B148,
-    0x235A3C1CF40C3125,
-    0x277785B4128BC965,
-    0x7B0C6735E04EFA35,
-    0x181B0A5DB6B21ED8,
-    0x2DC25979DE025698,
-    0xFAA18F389FDAE2CF,
-    0x3282FABB1E58D6BD,
-    0xF151A1EA3CE5004F,
-    0x2C3011D55BAA5FC3,
-    0x1A57E2B993A546B6,
-    0x3C6F10831069284D,
-    0x92AB80DD9F654EA6,
-    0xCD43CE263FA48929,
-    0x8F910E556C785813,
-];
-const PI_12: [u64; 25] = [
-    0x669EEDBCEAB088CD,
-    0xB56CB29CBC383429,
-    0x277785B4128BC965,
-    0xF151A1EA3CE5004F,
-    0x8F910E556C785813,
-    0x9277844AB383C496,
-    0x20A01A2FF21A1547,
-    0xA1F5A1245FD5B148,
-    0xFAA18F389FDAE2CF,
-    0x92AB80DD9F654EA6,
-    0x37660381860B590F,
-    0x06F921753316907B,
-    0x7B0C6735E04EFA35,
-    0x2C3011D55BAA5FC3,
-    0x1A57E2B993A546B6,
-    0x31D0A89F90C15A84,
-    0xB81144447FA23DEC,
-    0x235A3C1CF40C3125,
-    0x3282FABB1E58D6BD,
-    0xCD43CE263FA48929,
-    0xDFE5DBFB186E5FE5,
-    0x50EF7ECC7C48A9B2,
-    0x181B0A5DB6B21ED8,
-    0x2DC25979DE025698,
-    0x3C6F10831069284D,
-];
-const CHI_12: [u64; 25] = [
-    0x648DE89CE8334189,
-    0x656C92D6905C3423,
-    0x29F78BA152939175,
-    0x915F4042BE658083,
-    0x1EF11C5578706C33,
-    0x1322254ABE46649E,
-    0x7AA01437721057C0,
-    0xA1FFA1E15FF0BD68,
-    0xFAF58B3ABF5862DF,
-    0xB22B9AF8DF7D5FE7,
-    0x4E6245814643330B,
-    0x02C931B528B695B9,
-    0x694B851D604BFA01,
-    0x091010D55FA046CA,
-    0x1ACEC2CDA2B1C6C6,
-    0x329A908710CD5A85,
-    0xA89186E775F2FB74,
-    0xEE1B3818D5A83825,
-    0x0212DA229E198439,
-    0x45428A665086AC41,
-    0xD7F5DBEA9ADC49AD,
-    0x752F2FEC3448E9B2,
-    0x08360ADFB6DB369D,
-    0xEE429201D6040138,
-    0x3C6534877469885F,
-];
-const IOTA_12: [u64; 25] = [
-    0x648DE89C6833C102,
-    0x656C92D6905C3423,
-    0x29F78BA152939175,
-    0x915F4042BE658083,
-    0x1EF11C5578706C33,
-    0x1322254ABE46649E,
-    0x7AA01437721057C0,
-    0xA1FFA1E15FF0BD68,
-    0xFAF58B3ABF5862DF,
-    0xB22B9AF8DF7D5FE7,
-    0x4E6245814643330B,
-    0x02C931B528B695B9,
-    0x694B851D604BFA01,
-    0x091010D55FA046CA,
-    0x1ACEC2CDA2B1C6C6,
-    0x329A908710CD5A85,
-    0xA89186E775F2FB74,
-    0xEE1B3818D5A83825,
-    0x0212DA229E198439,
-    0x45428A665086AC41,
-    0xD7F5DBEA9ADC49AD,
-    0x752F2FEC3448E9B2,
-    0x08360ADFB6DB369D,
-    0xEE429201D6040138,
-    0x3C6534877469885F,
-];
-const THETA_13: [u64; 25] = [
-    0x2AC82EA25FE1D937,
-    0xB7136AD896CC00D4,
-    0xF499B2E2F4D336C6,
-    0x085628DAF299FA3E,
-    0x295E09AF5ABF46DB,
-    0x5D67E37489947CAB,
-    0xA8DFEC3974806337,
-    0x7C9198A2F9B01ADB,
-    0x63FCE3A2F3A41862,
-    0x85848F02FDB2750F,
-    0x002783BF71912B3E,
-    0xD0B6C9BB2E26A14E,
-    0xB425BC5EC60B5DB2,
-    0x9019784D135C3C77,
-    0x2D61D737807EEC2E,
-    0x7CDF56B9271F42B0,
-    0x7AEE7EE97362CF83,
-    0x3375015B73E89F96,
-    0x9B1BB2BAD2E5FE84,
-    0x72ED9F9C724986A9,
-    0x99B01DD4AD0E5198,
-    0xA750D7E232D8DD45,
-    0xD558339C109B912E,
-    0x774BFA999AF87B85,
-    0x0BCA217D56A6A2B7,
-];
-const RHO_13: [u64; 25] = [
-    0x2AC82EA25FE1D937,
-    0x6E26D5B12D9801A9,
-    0xBD266CB8BD34CDB1,
-    0xAF299FA3E085628D,
-    0x7AD5FA36D94AF04D,
-    0x9947CAB5D67E3748,
-    0x06337A8DFEC39748,
-    0x246628BE6C06B6DF,
-    0x3131FE71D179D20C,
-    0xF02FDB2750F85848,
-    0x013C1DFB8C8959F0,
-    0xDB26ECB89A853B42,
-    0x5AED95A12DE2F630,
-    0x9A26B878EF2032F0,
-    0x3F761716B0EB9BC0,
-    0x3E8560F9BEAD724E,
-    0x59F06F5DCFDD2E6C,
-    0x80ADB9F44FCB19BA,
-    0x575A5CBFD0936376,
-    0xED9F9C724986A972,
-    0x7752B439466266C0,
-    0x9D435F88CB637516,
-    0xDAAB067382137225,
-    0x85774BFA999AF87B,
-    0x885F55A9A8ADC2F2,
-];
-const PI_13: [u64; 25] = [
-    0x2AC82EA25FE1D937,
-    0x06337A8DFEC39748,
-    0x5AED95A12DE2F630,
-    0x575A5CBFD0936376,
-    0x885F55A9A8ADC2F2,
-    0xAF299FA3E085628D,
-    0xF02FDB2750F85848,
-    0x013C1DFB8C8959F0,
-    0x59F06F5DCFDD2E6C,
-    0xDAAB067382137225,
-    0x6E26D5B12D9801A9,
-    0x246628BE6C06B6DF,
-    0x9A26B878EF2032F0,
-    0xED9F9C724986A972,
-    0x7752B439466266C0,
-    0x7AD5FA36D94AF04D,
-    0x9947CAB5D67E3748,
-    0xDB26ECB89A853B42,
-    0x80ADB9F44FCB19BA,
-    0x85774BFA999AF87B,
-    0xBD266CB8BD34CDB1,
-    0x3131FE71D179D20C,
-    0x3F761716B0EB9BC0,
-    0x3E8560F9BEAD724E,
-    0x9D435F88CB637516,
-];
-const CHI_13: [u64; 25] = [
-    0x7204AB825EC1B907,
-    0x032132932ED2960E,
-    0xD2E894A105CE76B0,
-    0x75DA76BD87D37A73,
-    0x8C6C05A408AFC4BA,
-    0xAE399B7B6C84633D,
-    0xA8EFB92313AC7E44,
-    0x83371DD98C8B09F1,
-    0x7CF0F6DDAF592EE4,
-    0x8AAD4677926B6A65,
-    0xF42645F1AEB80189,
-    0x41FF2CBC6C803FDD,
-    0x88669871E9407470,
-    0xE5BBDDF2601EA85B,
-    0x77129C370664D096,
-    0x38F5DE3ED1CBF84F,
-    0x99CEDBF1933437F0,
-    0xDE74AEB20A95DB03,
-    0xFA2D09F00F8B19BE,
-    0x04754B7B9FAEFF7B,
-    0xB3606DBE9DB6C471,
-    0x31B09E98DF7DB202,
-    0xBE340816F1A99ED0,
-    0x1EA140C98AB9FAEF,
-    0x9D52CDC98B2A671A,
-];
-const IOTA_13: [u64; 25] = [
-    0xF204AB825EC1B98C,
-    0x032132932ED2960E,
-    0xD2E894A105CE76B0,
-    0x75DA76BD87D37A73,
-    0x8C6C05A408AFC4BA,
-    0xAE399B7B6C84633D,
-    0xA8EFB92313AC7E44,
-    0x83371DD98C8B09F1,
-    0x7CF0F6DDAF592EE4,
-    0x8AAD4677926B6A65,
-    0xF42645F1AEB80189,
-    0x41FF2CBC6C803FDD,
-    0x88669871E9407470,
-    0xE5BBDDF2601EA85B,
-    0x77129C370664D096,
-    0x38F5DE3ED1CBF84F,
-    0x99CEDBF1933437F0,
-    0xDE74AEB20A95DB03,
-    0xFA2D09F00F8B19BE,
-    0x04754B7B9FAEFF7B,
-    0xB3606DBE9DB6C471,
-    0x31B09E98DF7DB202,
-    0xBE340816F1A99ED0,
-    0x1EA140C98AB9FAEF,
-    0x9D52CDC98B2A671A,
-];
-const THETA_14: [u64; 25] = [
-    0x9E6F361EED8BFB6E,
-    0x535C9B40C820ECCD,
-    0x809D5F9383351BEF,
-    0x1DCB73BD0CA3F8C0,
-    0xC36C9C1E6408152B,
-    0xC25206E7DFCE21DF,
-    0xF89210F0F55E0487,
-    0xD142D6EB0A7064AE,
-    0x14E1F3DD2429AC57,
-    0xC5ADDFCDFECCBBF4,
-    0x984DD86D1DF2436B,
-    0x1182856F8A72451E,
-    0xDA1353436FBB192F,
-    0x8DAAD8F2EB6E2AE8,
-    0x3812058D6AC30107,
-    0x549E43A26281BAAD,
-    0xC9B3722275C64D33,
-    0x8C0165808C6EB65C,
-    0x923C0CF084FB9B0D,
-    0x4B75D2C1F3092EEA,
-    0xDF0BF0222EFC8693,
-    0x61CD374B398FC8C1,
-    0xEC41C3247752F38F,
-    0x76B045C901C9785C,
-    0xD2525473E78DB68B,
-];
-const RHO_14: [u64; 25] = [
-    0x9E6F361EED8BFB6E,
-    0xA6B936819041D99A,
-    0xE02757E4E0CD46FB,
-    0xD0CA3F8C01DCB73B,
-    0xF32040A95E1B64E0,
-    0xFCE21DFC25206E7D,
-    0xE0487F89210F0F55,
-    0x50B5BAC29C192BB4,
-    0x2B8A70F9EE9214D6,
-    0xFCDFECCBBF4C5ADD,
-    0xC26EC368EF921B5C,
-    0x0A15BE29C9147846,
-    0xD8C97ED09A9A1B7D,
-    0xE5D6DC55D11B55B1,
-    0x6180839C0902C6B5,
-    0x03755AA93C8744C5,
-    0xC9A679366E444EB8,
-    0xB2C046375B2E4600,
-    0x9E109F7361B24781,
-    0x75D2C1F3092EEA4B,
-    0xC088BBF21A4F7C2F,
-    0x8734DD2CE63F2305,
-    0xFD8838648EEA5E71,
-    0x5C76B045C901C978,
-    0x951CF9E36DA2F494,
-];
-const PI_14: [u64; 25] = [
-    0x9E6F361EED8BFB6E,
-    0xE0487F89210F0F55,
-    0xD8C97ED09A9A1B7D,
-    0x9E109F7361B24781,
-    0x951CF9E36DA2F494,
-    0xD0CA3F8C01DCB73B,
-    0xFCDFECCBBF4C5ADD,
-    0xC26EC368EF921B5C,
-    0xC9A679366E444EB8,
-    0xFD8838648EEA5E71,
-    0xA6B936819041D99A,
-    0x50B5BAC29C192BB4,
-    0xE5D6DC55D11B55B1,
-    0x75D2C1F3092EEA4B,
-    0xC088BBF21A4F7C2F,
-    0xF32040A95E1B64E0,
-    0xFCE21DFC25206E7D,
-    0x0A15BE29C9147846,
-    0xB2C046375B2E4600,
-    0x5C76B045C901C978,
-    0xE02757E4E0CD46FB,
-    0x2B8A70F9EE9214D6,
-    0x6180839C0902C6B5,
-    0x03755AA93C8744C5,
-    0x8734DD2CE63F2305,
-];
-const CHI_14: [u64; 25] = [
-    0x86EE364E771BEB46,
-    0xE658FEAA402F4BD5,
-    0xD9C51E50969AAB69,
-    0x9473996FE1BB4CEB,
-    0xF51CB0626DA6F085,
-    0xD2EA3CAC414EB63B,
-    0xF55FD4DDBF081E7D,
-    0xF666C3286F380B1D,
-    0xC9E47EBE6F50EFB2,
-    0xD19DF82730EA16B5,
-    0x03FB7294D1438D9B,
-    0x40B5BB60943D81FE,
-    0x65DEE655C35A4195,
-    0x53E3C5F2892E6BDB,
-    0x908C33B016575E0B,
-    0xF135E2A8960F74E2,
-    0x4C225DEA370A687D,
-    0x46230E694915F13E,
-    0x11C0069F4D346280,
-    0x50B4AD11E821C365,
-    0xA027D4E0E1CD84DA,
-    0x29FF28D8DA171496,
-    0xE5800698CB3AE5B5,
-    0x637658693C47003F,
-    0x8CBCFD35E82D3301,
-];
-const IOTA_14: [u64; 25] = [
-    0x06EE364E771B6BCF,
-    0xE658FEAA402F4BD5,
-    0xD9C51E50969AAB69,
-    0x9473996FE1BB4CEB,
-    0xF51CB0626DA6F085,
-    0xD2EA3CAC414EB63B,
-    0xF55FD4DDBF081E7D,
-    0xF666C3286F380B1D,
-    0xC9E47EBE6F50EFB2,
-    0xD19DF82730EA16B5,
-    0x03FB7294D1438D9B,
-    0x40B5BB60943D81FE,
-    0x65DEE655C35A4195,
-    0x53E3C5F2892E6BDB,
-    0x908C33B016575E0B,
-    0xF135E2A8960F74E2,
-    0x4C225DEA370A687D,
-    0x46230E694915F13E,
-    0x11C0069F4D346280,
-    0x50B4AD11E821C365,
-    0xA027D4E0E1CD84DA,
-    0x29FF28D8DA171496,
-    0xE5800698CB3AE5B5,
-    0x637658693C47003F,
-    0x8CBCFD35E82D3301,
-];
-const THETA_15: [u64; 25] = [
-    0x0234D5D4300372EA,
-    0xB309D72DA1540157,
-    0x162E03DFFDF057AE,
-    0xADA7FD11CF42293F,
-    0x840450CA3AB91A17,
-    0xD630DF360656AF1E,
-    0xA00EFD5A5E7354FF,
-    0x398DDEA70452F7DA,
-    0xF0301AC041A98A66,
-    0xA085188F67F5FC27,
-    0x0721910E965B94BE,
-    0x15E492E77546CB7C,
-    0xAA35FBDAA830BD52,
-    0x6A37A18CA7D70E0F,
-    0xE194D3184148B499,
-    0xF5EF0132D1176DC7,
-    0x1973746DD67122FF,
-    0x89C813E6227F0DF9,
-    0x281462E163CD0754,
-    0x21AC4DB9BF3E29F7,
-    0xA4FD377AA6D59DFF,
-    0x7CAE015F3B6C5E14,
-    0x2A6B1B17A0501972,
-    0x5AA23C1712BE65EB,
-    0xFDA41D9DBF32D993,
-];
-const RHO_15: [u64; 25] = [
-    0x0234D5D4300372EA,
-    0x6613AE5B42A802AF,
-    0x858B80F7FF7C15EB,
-    0x1CF42293FADA7FD1,
-    0x51D5C8D0BC202286,
-    0x656AF1ED630DF360,
-    0x354FFA00EFD5A5E7,
-    0x6377A9C114BDF68E,
-    0x3378180D6020D4C5,
-    0x88F67F5FC27A0851,
-    0x390C8874B2DCA5F0,
-    0x924B9DD51B2DF057,
-    0x85EA9551AFDED541,
-    0x194FAE1C1ED46F43,
-    0xA45A4CF0CA698C20,
-    0x2EDB8FEBDE0265A2,
-    0x245FE32E6E8DBACE,
-    0x09F3113F86FCC4E4,
-    0x5C2C79A0EA85028C,
-    0xAC4DB9BF3E29F721,
-    0xDDEA9B5677FE93F4,
-    0xF2B8057CEDB17851,
-    0x454D6362F40A032E,
-    0xEB5AA23C1712BE65,
-    0x07676FCCB664FF69,
-];
-const PI_15: [u64; 25] = [
-    0x0234D5D4300372EA,
-    0x354FFA00EFD5A5E7,
-    0x85EA9551AFDED541,
-    0x5C2C79A0EA85028C,
-    0x07676FCCB664FF69,
-    0x1CF42293FADA7FD1,
-    0x88F67F5FC27A0851,
-    0x390C8874B2DCA5F0,
-    0x245FE32E6E8DBACE,
-    0x454D6362F40A032E,
-    0x6613AE5B42A802AF,
-    0x6377A9C114BDF68E,
-    0x194FAE1C1ED46F43,
-    0xAC4DB9BF3E29F721,
-    0xDDEA9B5677FE93F4,
-    0x51D5C8D0BC202286,
-    0x656AF1ED630DF360,
-    0x924B9DD51B2DF057,
-    0x09F3113F86FCC4E4,
-    0xEB5AA23C1712BE65,
-    0x858B80F7FF7C15EB,
-    0x3378180D6020D4C5,
-    0xA45A4CF0CA698C20,
-    0x2EDB8FEBDE0265A2,
-    0xF2B8057CEDB17851,
-];
-const CHI_15: [u64; 25] = [
-    0x8294D085300922EA,
-    0x6D4B92A0AFD4A76B,
-    0x86A9931DBBBE2820,
-    0x5C3CE9B0EA86020E,
-    0x322C45CC79B07A6C,
-    0x2DFCA2B3CA5EDA71,
-    0x8CA51C558E7B125F,
-    0x780C883422DEA4D0,
-    0x3CEFE3BF645DC61F,
-    0xC54F3E2EF42A032E,
-    0x7E1BA84748E80BEE,
-    0xC777B862349466AE,
-    0x48EDAC5C5F026F97,
-    0x8E5C9DB63E29F72A,
-    0xDC8E9AD663EB67F4,
-    0xC3D4C4C0A4002291,
-    0x6CDAF1C7E7DDF7C0,
-    0x70433FD50A2FCA56,
-    0x197659FF2EDCC466,
-    0xCF709311541F6F05,
-    0x0189C40775351DCB,
-    0x39F99B067422B547,
-    0x747A4CE4EBD89471,
-    0x2BD80F68CC4E6008,
-    0xC0C81D74EDB1B855,
-];
-const IOTA_15: [u64; 25] = [
-    0x0294D0853009A2E9,
-    0x6D4B92A0AFD4A76B,
-    0x86A9931DBBBE2820,
-    0x5C3CE9B0EA86020E,
-    0x322C45CC79B07A6C,
-    0x2DFCA2B3CA5EDA71,
-    0x8CA51C558E7B125F,
-    0x780C883422DEA4D0,
-    0x3CEFE3BF645DC61F,
-    0xC54F3E2EF42A032E,
-    0x7E1BA84748E80BEE,
-    0xC777B862349466AE,
-    0x48EDAC5C5F026F97,
-    0x8E5C9DB63E29F72A,
-    0xDC8E9AD663EB67F4,
-    0xC3D4C4C0A4002291,
-    0x6CDAF1C7E7DDF7C0,
-    0x70433FD50A2FCA56,
-    0x197659FF2EDCC466,
-    0xCF709311541F6F05,
-    0x0189C40775351DCB,
-    0x39F99B067422B547,
-    0x747A4CE4EBD89471,
-    0x2BD80F68CC4E6008,
-    0xC0C81D74EDB1B855,
-];
-const THETA_16: [u64; 25] = [
-    0xC1B507796A5F4935,
-    0x9A86C09E837591C6,
-    0x4D504D1799BB9796,
-    0xA6E7F35662AC2C82,
-    0xC850318EECC47560,
-    0xEEDD754F900831AD,
-    0x7B684E6BA2DA24F2,
-    0xB3F5563E00DB1B66,
-    0xC634F959EC77E893,
-    0x3F334A6C615E0C22,
-    0xBD3A7FBB12BEE032,
-    0x30BAEA5C18355003,
-    0x831472567D07D021,
-    0x74878750B603D9A6,
-    0x26F2EE94F69F68F8,
-    0x00F5133CFE56C94D,
-    0x9B17A3F9CB7CC16D,
-    0xBBBAE1DF282A75E0,
-    0xE3AD4319A6F6EAEA,
-    0x350CE753C16B6009,
-    0xC2A813FB2F63F617,
-    0xCE34C938588383EA,
-    0xBF8392EEC9DD2BC7,
-    0xD103158E44644E84,
-    0x3AB4693678C5B759,
-];
-const RHO_16: [u64; 25] = [
-    0xC1B507796A5F4935,
-    0x350D813D06EB238D,
-    0x93541345E66EE5E5,
-    0x662AC2C82A6E7F35,
-    0x776623AB0642818C,
-    0x00831ADEEDD754F9,
-    0xA24F27B684E6BA2D,
-    0xFD558F8036C6D9AC,
-    0x49E31A7CACF63BF4,
-    0xA6C615E0C223F334,
-    0xE9D3FDD895F70195,
-    0xEBA97060D5400CC2,
-    0x3E810C18A392B3E8,
-    0xA16C07B34CE90F0E,
-    0x4FB47C1379774A7B,
-    0xAD929A01EA2679FC,
-    0x982DB362F47F396F,
-    0x70EF94153AF05DDD,
-    0x6334DEDD5D5C75A8,
-    0x0CE753C16B600935,
-    0x4FECBD8FD85F0AA0,
-    0x38D324E1620E0FAB,
-    0xF7F0725DD93BA578,
-    0x84D103158E44644E,
-    0x1A4D9E316DD64EAD,
-];
-const PI_16: [u64; 25] = [
-    0xC1B507796A5F4935,
-    0xA24F27B684E6BA2D,
-    0x3E810C18A392B3E8,
-    0x6334DEDD5D5C75A8,
-    0x1A4D9E316DD64EAD,
-    0x662AC2C82A6E7F35,
-    0xA6C615E0C223F334,
-    0xE9D3FDD895F70195,
-    0x982DB362F47F396F,
-    0xF7F0725DD93BA578,
-    0x350D813D06EB238D,
-    0xFD558F8036C6D9AC,
-    0xA16C07B34CE90F0E,
-    0x0CE753C16B600935,
-    0x4FECBD8FD85F0AA0,
-    0x776623AB0642818C,
-    0x00831ADEEDD754F9,
-    0xEBA97060D5400CC2,
-    0x70EF94153AF05DDD,
-    0x84D103158E44644E,
-    0x93541345E66EE5E5,
-    0x49E31A7CACF63BF4,
-    0x4FB47C1379774A7B,
-    0xAD929A01EA2679FC,
-    0x38D324E1620E0FAB,
-];
-const CHI_16: [u64; 25] = [
-    0xDD350F71494F48F5,
-    0xE37BF573D8AAFE2D,
-    0x26C80C388310B9ED,
-    0xA284DF955F5574B8,
-    0x3807BEB7E976FCA5,
-    0x2F3B2AD03FBA7FB4,
-    0xB6EA17C2A22BCB5E,
-    0x8E03BDC59CF78585,
-    0x982733E2D63B636A,
-    0x7734677D193A2578,
-    0x3525810E4EC2258F,
-    0xF1D6DFC015C6D99D,
-    0xE264ABBDDCF60D8E,
-    0x3CE653F16DC02838,
-    0x87BCB30FE85BD280,
-    0x9C4E438B1642898E,
-    0x10C59ECBC76705E4,
-    0x6FB9736051442CC0,
-    0x03C9B4BF3AF2DC5D,
-    0x84501B4167D1303F,
-    0x95407746B76FA5EE,
-    0xE9E1987C2EF60A70,
-    0x5FF558F3797F4C78,
-    0x2E9689056E4699B8,
-    0x70702CD96A9E15BB,
-];
-const IOTA_16: [u64; 25] = [
-    0x5D350F71494FC8F7,
-    0xE37BF573D8AAFE2D,
-    0x26C80C388310B9ED,
-    0xA284DF955F5574B8,
-    0x3807BEB7E976FCA5,
-    0x2F3B2AD03FBA7FB4,
-    0xB6EA17C2A22BCB5E,
-    0x8E03BDC59CF78585,
-    0x982733E2D63B636A,
-    0x7734677D193A2578,
-    0x3525810E4EC2258F,
-    0xF1D6DFC015C6D99D,
-    0xE264ABBDDCF60D8E,
-    0x3CE653F16DC02838,
-    0x87BCB30FE85BD280,
-    0x9C4E438B1642898E,
-    0x10C59ECBC76705E4,
-    0x6FB9736051442CC0,
-    0x03C9B4BF3AF2DC5D,
-    0x84501B4167D1303F,
-    0x95407746B76FA5EE,
-    0xE9E1987C2EF60A70,
-    0x5FF558F3797F4C78,
-    0x2E9689056E4699B8,
-    0x70702CD96A9E15BB,
-];
-const THETA_17: [u64; 25] = [
-    0xDB5C25A151BA20DA,
-    0x589806B697E4E23D,
-    0x2D9E338765F2AE89,
-    0xA13954FC9ECF7854,
-    0x8F561C4E6B59FBF2,
-    0xA9520000274F9799,
-    0x0D09E407ED65D74E,
-    0x8555827A7A1592E1,
-    0x9B9AB88B17A16F86,
-    0xC065C5849B15222F,
-    0xB34CABDE5637CDA2,
-    0x4A352C055A88C58D,
-    0xE93294023A141AEA,
-    0x3F5BD898AC5A24D4,
-    0x30ED11F66A74D5D7,
-    0x1A27695B0EB761A3,
-    0xAB266D0E882919F4,
-    0x64EF4CDFB7A63BA4,
-    0x00743FD6FB68D0B1,
-    0x3301B9B8E5FE3768,
-    0x13295D96AF9A4DC3,
-    0x52026BB961B81660,
-    0x54A3674C9F9D5B1C,
-    0x2D2B026CAFDC9554,
-    0xC7218E20E8B112EC,
-];
-const RHO_17: [u64; 25] = [
-    0xDB5C25A151BA20DA,
-    0xB1300D6D2FC9C47A,
-    0x4B678CE1D97CABA2,
-    0xC9ECF7854A13954F,
-    0x735ACFDF947AB0E2,
-    0x74F9799A95200002,
-    0x5D74E0D09E407ED6,
-    0x55609E9E8564B861,
-    0xC34DCD5C458BD0B7,
-    0x5849B15222FC065C,
-    0x9A655EF2B1BE6D15,
-    0xD4B0156A23163528,
-    0xA0D7574994A011D0,
-    0x3158B449A87EB7B1,
-    0x3A6AEB987688FB35,
-    0x6EC346344ED2B61D,
-    0x233E9564CDA1D105,
-    0xA66FDBD31DD23277,
-    0xFADF6D1A16200E87,
-    0x01B9B8E5FE376833,
-    0x765ABE69370C4CA5,
-    0x4809AEE586E05981,
-    0x8A946CE993F3AB63,
-    0x542D2B026CAFDC95,
-    0x63883A2C44BB31C8,
-];
-const PI_17: [u64; 25] = [
-    0xDB5C25A151BA20DA,
-    0x5D74E0D09E407ED6,
-    0xA0D7574994A011D0,
-    0xFADF6D1A16200E87,
-    0x63883A2C44BB31C8,
-    0xC9ECF7854A13954F,
-    0x5849B15222FC065C,
-    0x9A655EF2B1BE6D15,
-    0x233E9564CDA1D105,
-    0x8A946CE993F3AB63,
-    0xB1300D6D2FC9C47A,
-    0x55609E9E8564B861,
-    0x3158B449A87EB7B1,
-    0x01B9B8E5FE376833,
-    0x765ABE69370C4CA5,
-    0x735ACFDF947AB0E2,
-    0x74F9799A95200002,
-    0xD4B0156A23163528,
-    0xA66FDBD31DD23277,
-    0x542D2B026CAFDC95,
-    0x4B678CE1D97CABA2,
-    0xC34DCD5C458BD0B7,
-    0x3A6AEB987688FB35,
-    0x6EC346344ED2B61D,
-    0x4809AEE586E05981,
-];
-const CHI_17: [u64; 25] = [
-    0x7BDF32A8511A21DA,
-    0x077CC8C29C4070D1,
-    0xA1D7456DD43B2098,
-    0x628B689B07200E95,
-    0x67A8FA7CCAFB6FCC,
-    0x4BC8B925DB11FC4E,
-    0x795330566EFD965C,
-    0x12E5367BA3EC4777,
-    0x6256066085A1C509,
-    0x9A956CBBB31FA973,
-    0x91282D2C07D3C3EA,
-    0x55C1963AD365F063,
-    0x471AB241A976B335,
-    0x8099B9E1F6F6E869,
-    0x321A2CFBB72874A4,
-    0xF35ACBBFB66C85CA,
-    0x56B6B30B89E00255,
-    0x84B0356A433BF9A8,
-    0x853D1F0E8D821215,
-    0x508C1B026DAFDC95,
-    0x7345AE61EB7C80A2,
-    0x87CCC9784DD9D4BF,
-    0x3A624359F6A8B2B5,
-    0x6DA5463417CE143F,
-    0xC801EFF982630994,
-];
-const IOTA_17: [u64; 25] = [
-    0xFBDF32A8511A215A,
-    0x077CC8C29C4070D1,
-    0xA1D7456DD43B2098,
-    0x628B689B07200E95,
-    0x67A8FA7CCAFB6FCC,
-    0x4BC8B925DB11FC4E,
-    0x795330566EFD965C,
-    0x12E5367BA3EC4777,
-    0x6256066085A1C509,
-    0x9A956CBBB31FA973,
-    0x91282D2C07D3C3EA,
-    0x55C1963AD365F063,
-    0x471AB241A976B335,
-    0x8099B9E1F6F6E869,
-    0x321A2CFBB72874A4,
-    0xF35ACBBFB66C85CA,
-    0x56B6B30B89E00255,
-    0x84B0356A433BF9A8,
-    0x853D1F0E8D821215,
-    0x508C1B026DAFDC95,
-    0x7345AE61EB7C80A2,
-    0x87CCC9784DD9D4BF,
-    0x3A624359F6A8B2B5,
-    0x6DA5463417CE143F,
-    0xC801EFF982630994,
-];
-const THETA_18: [u64; 25] = [
-    0x595D55D4BBD9C649,
-    0x33A965759AED54C9,
-    0x8AFA4DF1EDACAB22,
-    0x872542712E125F66,
-    0x4D35F2A385507D3E,
-    0xE94ADE5931D21B5D,
-    0x4D869DE16850B244,
-    0x39C83EE79A7BCCCD,
-    0x87F82C8AAC9394FA,
-    0xB0086464FCB4BB81,
-    0x33AA4A50ED1024F9,
-    0x61143B8DD5C8D47B,
-    0x6C37BADD90E1388F,
-    0x6537930BDFC4B99A,
-    0x18872424F8836656,
-    0x51D8ACC35CAF62D9,
-    0x62631EBC8F4D264D,
-    0xAF9D3DF67AAC7212,
-    0x609335E4A4B043E6,
-    0x7A1113DD2204CE67,
-    0xD1C7C91D01BF67B1,
-    0xB31964CF4B74F0A7,
-    0x114F4BC5CF3F390F,
-    0x880B6CDE3EFC45CC,
-    0xE29CE726CDC81B66,
-];
-const RHO_18: [u64; 25] = [
-    0x595D55D4BBD9C649,
-    0x6752CAEB35DAA992,
-    0xA2BE937C7B6B2AC8,
-    0x12E125F668725427,
-    0x1C2A83E9F269AF95,
-    0x1D21B5DE94ADE593,
-    0x0B2444D869DE1685,
-    0x720FB9E69EF3334E,
-    0x7D43FC16455649CA,
-    0x464FCB4BB81B0086,
-    0x9D525287688127C9,
-    0x50EE37572351ED84,
-    0x09C47B61BDD6EC87,
-    0x17BF897334CA6F26,
-    0x41B32B0C4392127C,
-    0x5EC5B2A3B15986B9,
-    0xA4C9AC4C63D791E9,
-    0x9EFB3D56390957CE,
-    0xBC9496087CCC1266,
-    0x1113DD2204CE677A,
-    0x247406FD9EC7471F,
-    0xCC65933D2DD3C29E,
-    0xE229E978B9E7E721,
-    0xCC880B6CDE3EFC45,
-    0x39C9B37206D9B8A7,
-];
-const PI_18: [u64; 25] = [
-    0x595D55D4BBD9C649,
-    0x0B2444D869DE1685,
-    0x09C47B61BDD6EC87,
-    0xBC9496087CCC1266,
-    0x39C9B37206D9B8A7,
-    0x12E125F668725427,
-    0x464FCB4BB81B0086,
-    0x9D525287688127C9,
-    0xA4C9AC4C63D791E9,
-    0xE229E978B9E7E721,
-    0x6752CAEB35DAA992,
-    0x720FB9E69EF3334E,
-    0x17BF897334CA6F26,
-    0x1113DD2204CE677A,
-    0x247406FD9EC7471F,
-    0x1C2A83E9F269AF95,
-    0x1D21B5DE94ADE593,
-    0x50EE37572351ED84,
-    0x9EFB3D56390957CE,
-    0xCC880B6CDE3EFC45,
-    0xA2BE937C7B6B2AC8,
-    0x7D43FC16455649CA,
-    0x41B32B0C4392127C,
-    0x5EC5B2A3B15986B9,
-    0xCC65933D2DD3C29E,
-];
-const CHI_18: [u64; 25] = [
-    0x599D6EF52FD92E4B,
-    0xBF34C0D029D604E5,
-    0x088D5A13BFC74406,
-    0xFC80D28CC5CC542E,
-    0x3BE9B37A46DFA823,
-    0x8BF1357228F2736E,
-    0x66C66703BB4D90A6,
-    0xDF7213B7F0A141C9,
-    0xB409A8CA23C781EF,
-    0xA627237129EEE7A1,
-    0x62E2CAFA15D2E5B2,
-    0x720FEDE69EF73316,
-    0x33DB8BAEAECB6F23,
-    0x5211152025D6CFFA,
-    0x347937F914E65553,
-    0x5CE481E8D139A791,
-    0x9330BDDE8CA5F7D9,
-    0x10EE357FE5674585,
-    0x8ED9BDD71948545E,
-    0xCD893F7ADABABC47,
-    0xA20E907479EB38FC,
-    0x63076CB5F51FCD4B,
-    0xC1932A104F10527A,
-    0x7C5FB2E3E371AEF9,
-    0x9124FF3F29C7839C,
-];
-const IOTA_18: [u64; 25] = [
-    0x599D6EF52FD9AE41,
-    0xBF34C0D029D604E5,
-    0x088D5A13BFC74406,
-    0xFC80D28CC5CC542E,
-    0x3BE9B37A46DFA823,
-    0x8BF1357228F2736E,
-    0x66C66703BB4D90A6,
-    0xDF7213B7F0A141C9,
-    0xB409A8CA23C781EF,
-    0xA627237129EEE7A1,
-    0x62E2CAFA15D2E5B2,
-    0x720FEDE69EF73316,
-    0x33DB8BAEAECB6F23,
-    0x5211152025D6CFFA,
-    0x347937F914E65553,
-    0x5CE481E8D139A791,
-    0x9330BDDE8CA5F7D9,
-    0x10EE357FE5674585,
-    0x8ED9BDD71948545E,
-    0xCD893F7ADABABC47,
-    0xA20E907479EB38FC,
-    0x63076CB5F51FCD4B,
-    0xC1932A104F10527A,
-    0x7C5FB2E3E371AEF9,
-    0x9124FF3F29C7839C,
-];
-const THETA_19: [u64; 25] = [
-    0x1B123FFE4CDEB0C5,
-    0x9BE3FAFB04495933,
-    0x837B01E9B9D818F8,
-    0x23EDC0869F426328,
-    0x4F3ED2EB0B6C075F,
-    0xC97E64794BF56DEA,
-    0x42115D2896D2CD70,
-    0x5484484DF6BE1D37,
-    0x6B64BAC07949B6E9,
-    0xD2F042E0645D48DD,
-    0x206D9BF176D5FB36,
-    0x56D8D7CDB3686EC0,
-    0xB82DD054A8D433DD,
-    0x8D7C072A7F58F8FC,
-    0x40AE56685955FA2F,
-    0x1E6BD0E3B23EB915,
-    0xB7E787F5A13AAA0F,
-    0x9B186E85E378197B,
-    0x51B4AFDD43C66358,
-    0xB95E5EEB9709133B,
-    0xE081C17F1AEC2678,
-    0x47D0569ED880909D,
-    0x4A6571EA490F0E84,
-    0xA332A0E9B9FF99FF,
-    0xE5F39EAE64742CE0,
-];
-const RHO_19: [u64; 25] = [
-    0x1B123FFE4CDEB0C5,
-    0x37C7F5F60892B267,
-    0x20DEC07A6E76063E,
-    0x69F42632823EDC08,
-    0x585B603AFA79F697,
-    0xBF56DEAC97E64794,
-    0x2CD7042115D2896D,
-    0x2112137DAF874DD5,
-    0x74B5B25D603CA4DB,
-    0x2E0645D48DDD2F04,
-    0x036CDF8BB6AFD9B1,
-    0x635F36CDA1BB015B,
-    0xA19EEDC16E82A546,
-    0x54FEB1F1F91AF80E,
-    0xAAFD17A0572B342C,
-    0x7D722A3CD7A1C764,
-    0x5541F6FCF0FEB427,
-    0x3742F1BC0CBDCD8C,
-    0xFBA878CC6B0A3695,
-    0x5E5EEB9709133BB9,
-    0x05FC6BB099E38207,
-    0x1F415A7B62024275,
-    0x894CAE3D4921E1D0,
-    0xFFA332A0E9B9FF99,
-    0xE7AB991D0B38397C,
-];
-const PI_19: [u64; 25] = [
-    0x1B123FFE4CDEB0C5,
-    0x2CD7042115D2896D,
-    0xA19EEDC16E82A546,
-    0xFBA878CC6B0A3695,
-    0xE7AB991D0B38397C,
-    0x69F42632823EDC08,
-    0x2E0645D48DDD2F04,
-    0x036CDF8BB6AFD9B1,
-    0x5541F6FCF0FEB427,
-    0x894CAE3D4921E1D0,
-    0x37C7F5F60892B267,
-    0x2112137DAF874DD5,
-    0x54FEB1F1F91AF80E,
-    0x5E5EEB9709133BB9,
-    0x05FC6BB099E38207,
-    0x585B603AFA79F697,
-    0xBF56DEAC97E64794,
-    0x635F36CDA1BB015B,
-    0x3742F1BC0CBDCD8C,
-    0xFFA332A0E9B9FF99,
-    0x20DEC07A6E76063E,
-    0x74B5B25D603CA4DB,
-    0xAAFD17A0572B342C,
-    0x7D722A3CD7A1C764,
-    0x1F415A7B62024275,
-];
-const CHI_19: [u64; 25] = [
-    0x9A1AD63E26DE94C7,
-    0x76F7142D14DA9BFC,
-    0xA59D6CD06EB2AC2E,
-    0xE3B85E2E2FCCB614,
-    0xC36E991C1A383054,
-    0x689CBC39B01C0CB9,
-    0x7A0765A0CD8D0B02,
-    0x8B60D78ABFAE9861,
-    0x35F1F6FE72E0A82F,
-    0x8F4EEFF944E0C2D4,
-    0x632B5576588A026D,
-    0x2B12597BAF864E64,
-    0x555EB1D169FA7808,
-    0x6C5D7FD109030BD9,
-    0x05EC69B93EE6CF97,
-    0x1852407BDA60F6DC,
-    0xAB561F9C9BE28B10,
-    0xABFE34CD40BB334A,
-    0x371AB1A61EFDCD8A,
-    0x58A7AC24EC3FFE99,
-    0xAA96C5DA7975161A,
-    0x21B79A41E0BC679B,
-    0xA8FC47E37729343D,
-    0x5DECAA3CDBD5C36E,
-    0x4B60687E620AE2B4,
-];
-const IOTA_19: [u64; 25] = [
-    0x1A1AD63EA6DE94CD,
-    0x76F7142D14DA9BFC,
-    0xA59D6CD06EB2AC2E,
-    0xE3B85E2E2FCCB614,
-    0xC36E991C1A383054,
-    0x689CBC39B01C0CB9,
-    0x7A0765A0CD8D0B02,
-    0x8B60D78ABFAE9861,
-    0x35F1F6FE72E0A82F,
-    0x8F4EEFF944E0C2D4,
-    0x632B5576588A026D,
-    0x2B12597BAF864E64,
-    0x555EB1D169FA7808,
-    0x6C5D7FD109030BD9,
-    0x05EC69B93EE6CF97,
-    0x1852407BDA60F6DC,
-    0xAB561F9C9BE28B10,
-    0xABFE34CD40BB334A,
-    0x371AB1A61EFDCD8A,
-    0x58A7AC24EC3FFE99,
-    0xAA96C5DA7975161A,
-    0x21B79A41E0BC679B,
-    0xA8FC47E37729343D,
-    0x5DECAA3CDBD5C36E,
-    0x4B60687E620AE2B4,
-];
-const THETA_20: [u64; 25] = [
-    0x1A16576E53CBD1D4,
-    0x24DC5DB6E76F7743,
-    0xA95B58CC4133A832,
-    0x2F0E91867CAEBF50,
-    0x555F20265185DEED,
-    0x68903D69450949A0,
-    0x282C2C3B3E38E7BD,
-    0x87A6E396902F9C7D,
-    0xF94739562182A16B,
-    0x197F56C30F5D2C6D,
-    0x6327D426AD9F4774,
-    0x793910E05C33A2DB,
-    0x599885CD467B7C14,
-    0xA0EBB0795A61029D,
-    0x93DDD083755B212E,
-    0x185EC12B2F75B3C5,
-    0xF97D5607685767AF,
-    0xA73800D16F3A3756,
-    0xFBAC7E0E4D9FC4CE,
-    0xCE96151EA7821020,
-    0xAA9A448A8C605303,
-    0x739CD3DA13098B24,
-    0xA43A73FF58A83021,
-    0x915A659488B7CA2A,
-    0xDD51D14429B70C0D,
-];
-const RHO_20: [u64; 25] = [
-    0x1A16576E53CBD1D4,
-    0x49B8BB6DCEDEEE86,
-    0xAA56D633104CEA0C,
-    0x67CAEBF502F0E918,
-    0x328C2EF76AAAF901,
-    0x50949A068903D694,
-    0x8E7BD282C2C3B3E3,
-    0xE9B8E5A40BE71F61,
-    0xB5FCA39CAB10C150,
-    0x6C30F5D2C6D197F5,
-    0x193EA1356CFA3BA3,
-    0xE4438170CE8B6DE4,
-    0xDBE0A2CCC42E6A33,
-    0xF2B4C2053B41D760,
-    0xAD909749EEE841BA,
-    0xEB678A30BD82565E,
-    0xECF5FF2FAAC0ED0A,
-    0x0068B79D1BAB539C,
-    0xC1C9B3F899DF758F,
-    0x96151EA7821020CE,
-    0x122A31814C0EAA69,
-    0xCE734F684C262C91,
-    0x34874E7FEB150604,
-    0x2A915A659488B7CA,
-    0x74510A6DC3037754,
-];
-const PI_20: [u64; 25] = [
-    0x1A16576E53CBD1D4,
-    0x8E7BD282C2C3B3E3,
-    0xDBE0A2CCC42E6A33,
-    0xC1C9B3F899DF758F,
-    0x74510A6DC3037754,
-    0x67CAEBF502F0E918,
-    0x6C30F5D2C6D197F5,
-    0x193EA1356CFA3BA3,
-    0xECF5FF2FAAC0ED0A,
-    0x34874E7FEB150604,
-    0x49B8BB6DCEDEEE86,
-    0xE9B8E5A40BE71F61,
-    0xF2B4C2053B41D760,
-    0x96151EA7821020CE,
-    0x122A31814C0EAA69,
-    0x328C2EF76AAAF901,
-    0x50949A068903D694,
-    0xE4438170CE8B6DE4,
-    0x0068B79D1BAB539C,
-    0x2A915A659488B7CA,
-    0xAA56D633104CEA0C,
-    0xB5FCA39CAB10C150,
-    0xAD909749EEE841BA,
-    0xEB678A30BD82565E,
-    0xCE734F684C262C91,
-];
-const CHI_20: [u64; 25] = [
-    0x4B96772257E799C4,
-    0x8E72C3B2DB12A66F,
-    0xEFF0AAC9862E6863,
-    0xCBCFE6FA8917F50F,
-    0xF0388AED43035577,
-    0x76C4EBD02ADAC11A,
-    0x88F1ABD844D153FD,
-    0x093CA1652DEF39A7,
-    0xAFBD5EAFAA200412,
-    0x3CB75A7D2F1410E1,
-    0x5BBCB96CFEDE2E86,
-    0xEDB9F9068BF73FEF,
-    0xF29EE305774F5D41,
-    0xDF8594CB00C06448,
-    0xB22A75014D2FBB08,
-    0x96CF2F872C22D061,
-    0x50BCAC8B9823C48C,
-    0xCED2C9104A8BC9A6,
-    0x1064930F71891B9D,
-    0x6A81CA651589B15E,
-    0xA256C27254A4EAA6,
-    0xF79BABACBA12D714,
-    0xA980D201AECC693B,
-    0xCB631A23ADCA9452,
-    0xDBDB6EE4E7362DC1,
-];
-const IOTA_20: [u64; 25] = [
-    0xCB967722D7E71945,
-    0x8E72C3B2DB12A66F,
-    0xEFF0AAC9862E6863,
-    0xCBCFE6FA8917F50F,
-    0xF0388AED43035577,
-    0x76C4EBD02ADAC11A,
-    0x88F1ABD844D153FD,
-    0x093CA1652DEF39A7,
-    0xAFBD5EAFAA200412,
-    0x3CB75A7D2F1410E1,
-    0x5BBCB96CFEDE2E86,
-    0xEDB9F9068BF73FEF,
-    0xF29EE305774F5D41,
-    0xDF8594CB00C06448,
-    0xB22A75014D2FBB08,
-    0x96CF2F872C22D061,
-    0x50BCAC8B9823C48C,
-    0xCED2C9104A8BC9A6,
-    0x1064930F71891B9D,
-    0x6A81CA651589B15E,
-    0xA256C27254A4EAA6,
-    0xF79BABACBA12D714,
-    0xA980D201AECC693B,
-    0xCB631A23ADCA9452,
-    0xDBDB6EE4E7362DC1,
-];
-const THETA_21: [u64; 25] = [
-    0x9C525AA4686BC88E,
-    0xBA04ECA9D1E43241,
-    0x620C77E74F4384B2,
-    0x2731176316D09D14,
-    0x3427BF894A7CD7D0,
-    0x2100C656955610D1,
-    0xBC8784C34E27C7D3,
-    0x84C07C4BE482D576,
-    0x4343AF3635E76C09,
-    0xF8A86F19266B9246,
-    0x0C7894EA4152FF4D,
-    0xD9CFD61D8101ABC1,
-    0x7F623E2BBE22B190,
-    0x337B65529F070C53,
-    0x76354065445039AF,
-    0xC10B020193AE01AA,
-    0x64CA839092D550A2,
-    0x432E143E83E62577,
-    0xFC9A6296EE4E7386,
-    0xAE9EFF011CF633F9,
-    0xF592EFF4EB283B6D,
-    0xC3ED84B7B0E4433A,
-    0x247C0F2F67A185EA,
-    0x279DEBBA320DFC49,
-    0x1FC45B80EE49AF66,
-];
-const RHO_21: [u64; 25] = [
-    0x9C525AA4686BC88E,
-    0x7409D953A3C86483,
-    0x98831DF9D3D0E12C,
-    0x316D09D142731176,
-    0x4A53E6BE81A13DFC,
-    0x55610D12100C6569,
-    0x7C7D3BC8784C34E2,
-    0x301F12F920B55DA1,
-    0x04A1A1D79B1AF3B6,
-    0xF19266B9246F8A86,
-    0x63C4A7520A97FA68,
-    0x3F58760406AF0767,
-    0x158C83FB11F15DF1,
-    0xA53E0E18A666F6CA,
-    0x281CD7BB1AA032A2,
-    0x5C03558216040327,
-    0xAA144C995072125A,
-    0x0A1F41F312BBA197,
-    0x52DDC9CE70DF934C,
-    0x9EFF011CF633F9AE,
-    0xBFD3ACA0EDB7D64B,
-    0x0FB612DEC3910CEB,
-    0x448F81E5ECF430BD,
-    0x49279DEBBA320DFC,
-    0x16E03B926BD987F1,
-];
-const PI_21: [u64; 25] = [
-    0x9C525AA4686BC88E,
-    0x7C7D3BC8784C34E2,
-    0x158C83FB11F15DF1,
-    0x52DDC9CE70DF934C,
-    0x16E03B926BD987F1,
-    0x316D09D142731176,
-    0xF19266B9246F8A86,
-    0x63C4A7520A97FA68,
-    0xAA144C995072125A,
-    0x448F81E5ECF430BD,
-    0x7409D953A3C86483,
-    0x301F12F920B55DA1,
-    0xA53E0E18A666F6CA,
-    0x9EFF011CF633F9AE,
-    0xBFD3ACA0EDB7D64B,
-    0x4A53E6BE81A13DFC,
-    0x55610D12100C6569,
-    0x3F58760406AF0767,
-    0x0A1F41F312BBA197,
-    0x49279DEBBA320DFC,
-    0x98831DF9D3D0E12C,
-    0x04A1A1D79B1AF3B6,
-    0x281CD7BB1AA032A2,
-    0x5C03558216040327,
-    0x0FB612DEC3910CEB,
-];
-const CHI_21: [u64; 25] = [
-    0x9DD2DA9769DA819F,
-    0x3E2C73CC1842B6EE,
-    0x11ACB1EB1AF15940,
-    0xDACF89EA70FDDB42,
-    0x76CD1ADA7BDDB391,
-    0x3329889348E3611E,
-    0x79822E30740F8A94,
-    0x274F2636A613DACD,
-    0x9B74448952711318,
-    0x841DE7CDC8F8BA3D,
-    0xF129D553258AC6C9,
-    0x2ADE13FD70A45485,
-    0x843EA2B8AFE2F08B,
-    0xDEF7504FF47BD92E,
-    0xBFC5AE08ED82CF6B,
-    0x604B94BA87023FFA,
-    0x55660CE1001CC5F9,
-    0x7E78EA0CAEAF0B0F,
-    0x084F23E7133A9197,
-    0x5C0794EBAA3E4DFD,
-    0xB09F4BD1D370E12C,
-    0x50A2A1D79F1EF2B3,
-    0x2BA8D5E7DB313E6A,
-    0xCC0258A30644E223,
-    0x0B96B2D8CB9B1E79,
-];
-const IOTA_21: [u64; 25] = [
-    0x1DD2DA9769DA011F,
-    0x3E2C73CC1842B6EE,
-    0x11ACB1EB1AF15940,
-    0xDACF89EA70FDDB42,
-    0x76CD1ADA7BDDB391,
-    0x3329889348E3611E,
-    0x79822E30740F8A94,
-    0x274F2636A613DACD,
-    0x9B74448952711318,
-    0x841DE7CDC8F8BA3D,
-    0xF129D553258AC6C9,
-    0x2ADE13FD70A45485,
-    0x843EA2B8AFE2F08B,
-    0xDEF7504FF47BD92E,
-    0xBFC5AE08ED82CF6B,
-    0x604B94BA87023FFA,
-    0x55660CE1001CC5F9,
-    0x7E78EA0CAEAF0B0F,
-    0x084F23E7133A9197,
-    0x5C0794EBAA3E4DFD,
-    0xB09F4BD1D370E12C,
-    0x50A2A1D79F1EF2B3,
-    0x2BA8D5E7DB313E6A,
-    0xCC0258A30644E223,
-    0x0B96B2D8CB9B1E79,
-];
-const THETA_22: [u64; 25] = [
-    0xD63F69D4510E2B36,
-    0xFF303EEC85BF4237,
-    0xCF1B9E0D1E08C375,
-    0x08CA693C6866B7A7,
-    0x33C04CCA19D6216D,
-    0xF8C43BD070374B37,
-    0xB89E6310E9F27E4D,
-    0xF9F809D0A2EA40F8,
-    0x4971A45F4AEA7FFD,
-    0xC110B1DDAAF328C1,
-    0x3AC466101D5EECE0,
-    0xEBC25EDDED59A05C,
-    0x5A898D5EAB1B6ABE,
-    0x0CF2B099ECE0B5CB,
-    0xFAC8F8188F895D97,
-    0xABA627F9BFD615D3,
-    0x947A41C19DE13120,
-    0xA0CFC5EAAA56913A,
-    0xDA4AC3310BA1FD72,
-    0x190AC2FBC835DF01,
-    0x7B72F892EBA4CB05,
-    0x91BEECF702E3066A,
-    0xF51FFA01DFC8A45F,
-    0x1E07B8751EDF8EC6,
-    0x4E9BE4C8A9908C85,
-];
-const RHO_22: [u64; 25] = [
-    0xD63F69D4510E2B36,
-    0xFE607DD90B7E846F,
-    0x73C6E783478230DD,
-    0xC6866B7A708CA693,
-    0x50CEB10B699E0266,
-    0x0374B37F8C43BD07,
-    0x27E4DB89E6310E9F,
-    0x7E027428BA903E3E,
-    0xFEA4B8D22FA5753F,
-    0x1DDAAF328C1C110B,
-    0xD6233080EAF76701,
-    0x097B77B5668173AF,
-    0xDB55F2D44C6AF558,
-    0x33D9C16B9619E561,
-    0xC4AECBFD647C0C47,
-    0xAC2BA7574C4FF37F,
-    0x2624128F483833BC,
-    0xE2F5552B489D5067,
-    0x6621743FAE5B4958,
-    0x0AC2FBC835DF0119,
-    0xE24BAE932C15EDCB,
-    0x46FBB3DC0B8C19AA,
-    0xFEA3FF403BF9148B,
-    0xC61E07B8751EDF8E,
-    0xF9322A64232153A6,
-];
-const PI_22: [u64; 25] = [
-    0xD63F69D4510E2B36,
-    0x27E4DB89E6310E9F,
-    0xDB55F2D44C6AF558,
-    0x6621743FAE5B4958,
-    0xF9322A64232153A6,
-    0xC6866B7A708CA693,
-    0x1DDAAF328C1C110B,
-    0xD6233080EAF76701,
-    0x2624128F483833BC,
-    0xFEA3FF403BF9148B,
-    0xFE607DD90B7E846F,
-    0x7E027428BA903E3E,
-    0x33D9C16B9619E561,
-    0x0AC2FBC835DF0119,
-    0xE24BAE932C15EDCB,
-    0x50CEB10B699E0266,
-    0x0374B37F8C43BD07,
-    0x097B77B5668173AF,
-    0xE2F5552B489D5067,
-    0xC61E07B8751EDF8E,
-    0x73C6E783478230DD,
-    0xFEA4B8D22FA5753F,
-    0xC4AECBFD647C0C47,
-    0xAC2BA7574C4FF37F,
-    0x46FBB3DC0B8C19AA,
-];
-const CHI_22: [u64; 25] = [
-    0x0E2E49805944DA76,
-    0x03C4DFA24420069F,
-    0x4247F8944D4AE7FE,
-    0x602C35AFFE556148,
-    0xD8F2B86D8510572F,
-    0x04A77BFA126FC093,
-    0x3DDEAD3D8C1401B7,
-    0x0EA0DDC0D9366302,
-    0x262012B5083C91AC,
-    0xE7FB7B40B7E90583,
-    0xFFB9FC9A0F77452E,
-    0x76004EA89B563E26,
-    0xD3D0C5789E1909A3,
-    0x16E2AA8036B5013D,
-    0xE249AEB39C95D7DB,
-    0x58C5F58B0B1E40CE,
-    0xE1F0B375845FBD47,
-    0x0D7175255383FC27,
-    0xF235E528401D5007,
-    0xC52E05CCF15F628F,
-    0x73CCA4AE07DA389D,
-    0xD6A59CD027A68607,
-    0x867EDB7567FC04C7,
-    0x9D2FE354084DD32A,
-    0xCADBAB8C23A95C88,
-];
-const IOTA_22: [u64; 25] = [
-    0x0E2E4980D944DA77,
-    0x03C4DFA24420069F,
-    0x4247F8944D4AE7FE,
-    0x602C35AFFE556148,
-    0xD8F2B86D8510572F,
-    0x04A77BFA126FC093,
-    0x3DDEAD3D8C1401B7,
-    0x0EA0DDC0D9366302,
-    0x262012B5083C91AC,
-    0xE7FB7B40B7E90583,
-    0xFFB9FC9A0F77452E,
-    0x76004EA89B563E26,
-    0xD3D0C5789E1909A3,
-    0x16E2AA8036B5013D,
-    0xE249AEB39C95D7DB,
-    0x58C5F58B0B1E40CE,
-    0xE1F0B375845FBD47,
-    0x0D7175255383FC27,
-    0xF235E528401D5007,
-    0xC52E05CCF15F628F,
-    0x73CCA4AE07DA389D,
-    0xD6A59CD027A68607,
-    0x867EDB7567FC04C7,
-    0x9D2FE354084DD32A,
-    0xCADBAB8C23A95C88,
-];
-const THETA_23: [u64; 25] = [
-    0x2205AD7B44E8659B,
-    0xF58DDC9FF08CCA78,
-    0x42E1FCCBACC90058,
-    0xD17FFC6F397A6216,
-    0x5B750C009CAC6AE8,
-    0x288C9F018FC37F7F,
-    0xCB97AE0038B8CD50,
-    0x0E06D99F38B584A4,
-    0x9773DB75CF1392F2,
-    0x647CCF2DAE553844,
-    0xD392186192DBFAC2,
-    0x80494D952FFAF2C1,
-    0xD376C1277F9AEE05,
-    0xA7B16340F19A0263,
-    0x61CE1ADE8529EA1C,
-    0x74EE117096B2FF22,
-    0x17B9B04830F371A0,
-    0x0DD7717AB2001B81,
-    0x43662CE887325359,
-    0x46A9B1A1E8E35F48,
-    0x5FE740559A768771,
-    0x20EC9FED930A4AE0,
-    0x86D8DF2A867FE361,
-    0x2C7C2A94CF62D074,
-    0x495C1FE13A15614F,
-];
-const RHO_23: [u64; 25] = [
-    0x2205AD7B44E8659B,
-    0xEB1BB93FE11994F1,
-    0x10B87F32EB324016,
-    0xF397A6216D17FFC6,
-    0x04E5635742DBA860,
-    0xFC37F7F288C9F018,
-    0x8CD50CB97AE0038B,
-    0x81B667CE2D612903,
-    0x794BB9EDBAE789C9,
-    0xF2DAE553844647CC,
-    0x9C90C30C96DFD616,
-    0x253654BFEBCB0601,
-    0xD7702E9BB6093BFC,
-    0x81E33404C74F62C6,
-    0x94F50E30E70D6F42,
-    0x65FE44E9DC22E12D,
-    0x6E3402F73609061E,
-    0xB8BD59000DC086EB,
-    0x9D10E64A6B286CC5,
-    0xA9B1A1E8E35F4846,
-    0x015669DA1DC57F9D,
-    0x83B27FB64C292B80,
-    0x30DB1BE550CFFC6C,
-    0x742C7C2A94CF62D0,
-    0x07F84E855853D257,
-];
-const PI_23: [u64; 25] = [
-    0x2205AD7B44E8659B,
-    0x8CD50CB97AE0038B,
-    0xD7702E9BB6093BFC,
-    0x9D10E64A6B286CC5,
-    0x07F84E855853D257,
-    0xF397A6216D17FFC6,
-    0xF2DAE553844647CC,
-    0x9C90C30C96DFD616,
-    0x6E3402F73609061E,
-    0x30DB1BE550CFFC6C,
-    0xEB1BB93FE11994F1,
-    0x81B667CE2D612903,
-    0x81E33404C74F62C6,
-    0xA9B1A1E8E35F4846,
-    0x015669DA1DC57F9D,
-    0x04E5635742DBA860,
-    0xFC37F7F288C9F018,
-    0x253654BFEBCB0601,
-    0xB8BD59000DC086EB,
-    0x742C7C2A94CF62D0,
-    0x10B87F32EB324016,
-    0x794BB9EDBAE789C9,
-    0x94F50E30E70D6F42,
-    0x65FE44E9DC22E12D,
-    0x83B27FB64C292B80,
-];
-const CHI_23: [u64; 25] = [
-    0x71258F79C0E15DEF,
-    0x84D5CCF933C0478A,
-    0xD598261EA65AA9EE,
-    0xBD1547306F80494D,
-    0x8B284E056253D057,
-    0xFF97A42D7F8E6FD4,
-    0x90FEE5A0A44647C4,
-    0x8C5BDA0CD6192E76,
-    0xAD30A6F71B19059C,
-    0x30935AB7D08FFC64,
-    0xEB5AA93F2317D635,
-    0xA9A6E6260D712103,
-    0x81A57C16DBCF555F,
-    0x43B831CD0347C826,
-    0x01F22F1A11A5569F,
-    0x05E5635A21D9AE61,
-    0x64BEFEF28CC970F2,
-    0x613670957BC46611,
-    0xB87C5A554FD00ECB,
-    0x8C3EE88A1CCF32C8,
-    0x940C7922AE3A2614,
-    0x1841F924A2C509E4,
-    0x16F53526E70465C2,
-    0x75F644E97F30A13B,
-    0xEAF1FF7B5CECA249,
-];
-const IOTA_23: [u64; 25] = [
-    0xF1258F7940E1DDE7,
-    0x84D5CCF933C0478A,
-    0xD598261EA65AA9EE,
-    0xBD1547306F80494D,
-    0x8B284E056253D057,
-    0xFF97A42D7F8E6FD4,
-    0x90FEE5A0A44647C4,
-    0x8C5BDA0CD6192E76,
-    0xAD30A6F71B19059C,
-    0x30935AB7D08FFC64,
-    0xEB5AA93F2317D635,
-    0xA9A6E6260D712103,
-    0x81A57C16DBCF555F,
-    0x43B831CD0347C826,
-    0x01F22F1A11A5569F,
-    0x05E5635A21D9AE61,
-    0x64BEFEF28CC970F2,
-    0x613670957BC46611,
-    0xB87C5A554FD00ECB,
-    0x8C3EE88A1CCF32C8,
-    0x940C7922AE3A2614,
-    0x1841F924A2C509E4,
-    0x16F53526E70465C2,
-    0x75F644E97F30A13B,
-    0xEAF1FF7B5CECA249,
-];
@@ -0,0 +1,606 @@
+//! Deduplicating content-addressed store of files
+//!
+//! A file is [cut](crate::cdc) into content-defined chunks, and every chunk is stored once under
+//! its address, `SHAKE128(chunk, 256)` in lowercase hexadecimal, whatever the number of files
+//! it appears in. The list of the chunks of a file is its [`Manifest`], which is stored the same
+//! way under its own address: that address names the file, and checks all of its bytes.
+//!
+//! The first byte of an address is a directory, so that no directory grows too large:
+//! ```text
+//! <root>/objects/ab/cdef…    chunks
+//! <root>/manifests/ab/cdef…  manifests
+//! <root>/tmp/                objects being written, moved into place once complete
+//! <root>/lock                locked shared by every operation, and exclusively by `gc`
+//! ```
+//! ```
+//! use TD1::{cdc, store::Store};
+//!
+//! let root = std::env::temp_dir().join(format!("store-doctest-{}", std::process::id()));
+//! let store = Store::open(&root).unwrap();
+//! let manifest = store.put(&b"backed up data"[..], &cdc::Params::default()).unwrap();
+//! let mut restored = Vec::new();
+//! store.get(&manifest, &mut restored).unwrap();
+//! assert_eq!(restored, b"backed up data");
+//! # std::fs::remove_dir_all(root).unwrap();
+//! ```
+use std::collections::HashSet;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::{
+    cdc::{self, Chunker},
+    error::ParseError,
+    hex::{self, Format},
+    kat::key_values,
+    sponge::Shake128,
+    Error, Result,
+};
+
+/// number of bytes of an address
+pub const ADDRESS_BYTES: usize = 32;
+
+pub type Address = [u8; ADDRESS_BYTES];
+
+/// the address of `data`, its SHAKE128-256 hash
+pub fn address(data: &[u8]) -> Address {
+    Shake128::digest(&[data])
+}
+
+/// makes the entries of `directory` durable, where the platform can
+fn sync_directory(directory: &Path) -> io::Result<()> {
+    #[cfg(unix)]
+    fs::File::open(directory)?.sync_all()?;
+    #[cfg(not(unix))]
+    let _ = directory;
+    Ok(())
+}
+
+/// reads an address written in hexadecimal, in either case
+pub fn parse_address(text: &str) -> Result<Address> {
+    let bytes = hex::decode(&text.to_ascii_lowercase(), Format::LOWER)?;
+    bytes.as_slice().try_into().map_err(|_| Error::WrongLength {
+        expected: ADDRESS_BYTES,
+        found: bytes.len(),
+    })
+}
+
+/// the chunks of a file, in order
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Manifest {
+    /// address and length of every chunk
+    pub chunks: Vec<(Address, u64)>,
+}
+
+impl Manifest {
+    /// number of bytes of the file
+    pub fn size(&self) -> u64 {
+        self.chunks.iter().map(|&(_, length)| length).sum()
+    }
+}
+
+/// written as [`key_values`] lines, the size first and then one line per chunk
+impl fmt::Display for Manifest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Size = {}", self.size())?;
+        for (address, length) in &self.chunks {
+            writeln!(
+                f,
+                "Chunk = {} {length}",
+                hex::encode(address, Format::LOWER)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Manifest {
+    type Err = Error;
+
+    /// reads the lines written by [`Display`](fmt::Display), and checks the size
+    fn from_str(text: &str) -> Result<Self> {
+        let mut size = None;
+        let mut chunks = Vec::new();
+        for line in key_values(text) {
+            let (line, key, value) = line?;
+            let error = |message| ParseError { line, message };
+            match (key, size) {
+                ("Size", None) if line == 1 => {
+                    size = Some(
+                        value
+                            .parse::<u64>()
+                            .map_err(|_| error(format!("`{value}` is not a number")))?,
+                    )
+                }
+                ("Chunk", Some(_)) => {
+                    let (address, length) = value.split_once(' ').ok_or_else(|| {
+                        error(format!("`{value}` is not an address and a length"))
+                    })?;
+                    let length = length
+                        .parse()
+                        .map_err(|_| error(format!("`{length}` is not a number")))?;
+                    chunks.push((parse_address(address)?, length));
+                }
+                _ => return Err(error(format!("unexpected `{key}`")).into()),
+            }
+        }
+        let manifest = Manifest { chunks };
+        match size {
+            Some(size) if size == manifest.size() => Ok(manifest),
+            Some(size) => Err(ParseError {
+                line: 1,
+                message: format!("the chunks hold {} bytes, not {size}", manifest.size()),
+            }
+            .into()),
+            None => Err(ParseError {
+                line: 1,
+                message: "missing `Size`".to_string(),
+            }
+            .into()),
+        }
+    }
+}
+
+/// what [`Store::verify`] finds wrong
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Problem {
+    /// chunk whose content does not match its address
+    CorruptChunk(Address),
+    /// manifest whose content does not match its address, or cannot be read
+    CorruptManifest(Address),
+    /// chunk of a manifest which is not in the store
+    MissingChunk { manifest: Address, chunk: Address },
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hex = |address: &Address| hex::encode(address, Format::LOWER);
+        match self {
+            Problem::CorruptChunk(chunk) => write!(f, "chunk {} is corrupt", hex(chunk)),
+            Problem::CorruptManifest(manifest) => {
+                write!(f, "manifest {} is corrupt", hex(manifest))
+            }
+            Problem::MissingChunk { manifest, chunk } => write!(
+                f,
+                "chunk {} of manifest {} is missing",
+                hex(chunk),
+                hex(manifest)
+            ),
+        }
+    }
+}
+
+/// what [`Store::gc`] removed
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Collected {
+    pub chunks: usize,
+    pub bytes: u64,
+}
+
+/// the two kinds of objects, each in its own directory
+#[derive(Clone, Copy)]
+enum Kind {
+    Chunk,
+    Manifest,
+}
+
+impl Kind {
+    fn directory(self) -> &'static str {
+        match self {
+            Kind::Chunk => "objects",
+            Kind::Manifest => "manifests",
+        }
+    }
+}
+
+/// a store in a directory
+#[derive(Clone, Debug)]
+pub struct Store {
+    root: PathBuf,
+}
+
+impl Store {
+    /// the store in `root`, which is created if needed
+    pub fn open(root: impl Into<PathBuf>) -> Result<Self> {
+        let store = Store { root: root.into() };
+        for directory in ["objects", "manifests", "tmp"] {
+            fs::create_dir_all(store.root.join(directory))?;
+        }
+        fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(store.root.join("lock"))?;
+        Ok(store)
+    }
+
+    /// the lock of the store, held until the file is dropped: shared by the operations that only
+    /// add objects or read them, and exclusive for [`Store::gc`], which must not remove the
+    /// chunks and temporary files of a `put` that has not written its manifest yet
+    fn lock(&self, exclusive: bool) -> Result<fs::File> {
+        let file = fs::OpenOptions::new()
+            .append(true)
+            .open(self.root.join("lock"))?;
+        if exclusive {
+            file.lock()?;
+        } else {
+            file.lock_shared()?;
+        }
+        Ok(file)
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn path(&self, kind: Kind, address: &Address) -> PathBuf {
+        let name = hex::encode(address, Format::LOWER);
+        self.root
+            .join(kind.directory())
+            .join(&name[..2])
+            .join(&name[2..])
+    }
+
+    /// stores `data` under its address unless it is already there, returning the address
+    fn write(&self, kind: Kind, data: &[u8]) -> Result<Address> {
+        let address = address(data);
+        let path = self.path(kind, &address);
+        if path.exists() {
+            return Ok(address);
+        }
+        // the object is complete on disk before it is renamed, and the rename before the object
+        // is used, so that a crash never leaves a partial object under its address
+        let temporary = self.root.join("tmp").join(format!(
+            "{}.{}",
+            hex::encode(&address, Format::LOWER),
+            std::process::id()
+        ));
+        let mut file = fs::File::create(&temporary)?;
+        file.write_all(data)?;
+        file.sync_all()?;
+        let directory = path.parent().expect("objects are in a directory");
+        fs::create_dir_all(directory)?;
+        fs::rename(&temporary, &path)?;
+        sync_directory(directory)?;
+        Ok(address)
+    }
+
+    /// the object stored under `address`, checked against it
+    fn read(&self, kind: Kind, address: &Address) -> Result<Vec<u8>> {
+        let name = hex::encode(address, Format::LOWER);
+        let data = match fs::read(self.path(kind, address)) {
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                return Err(Error::MissingObject(name))
+            }
+            data => data?,
+        };
+        if self::address(&data) != *address {
+            return Err(Error::CorruptObject(name));
+        }
+        Ok(data)
+    }
+
+    /// the addresses of the objects of a kind
+    fn list(&self, kind: Kind) -> Result<Vec<Address>> {
+        let mut addresses = Vec::new();
+        for directory in fs::read_dir(self.root.join(kind.directory()))? {
+            let directory = directory?;
+            let prefix = directory.file_name().to_string_lossy().into_owned();
+            for object in fs::read_dir(directory.path())? {
+                let name = object?.file_name().to_string_lossy().into_owned();
+                // anything else is not an object of the store
+                if let Ok(address) = hex::decode(&format!("{prefix}{name}"), Format::LOWER)
+                    .map_err(drop)
+                    .and_then(|bytes| Address::try_from(bytes.as_slice()).map_err(drop))
+                {
+                    addresses.push(address);
+                }
+            }
+        }
+        addresses.sort_unstable();
+        Ok(addresses)
+    }
+
+    /// stores the chunks of `reader` that are not in the store yet, then its manifest, and
+    /// returns the address of the manifest
+    pub fn put(&self, reader: impl Read, params: &cdc::Params) -> Result<Address> {
+        params.check()?;
+        let _lock = self.lock(false)?;
+        let mut manifest = Manifest::default();
+        for chunk in Chunker::new(reader, *params) {
+            let chunk = chunk?;
+            let address = self.write(Kind::Chunk, &chunk)?;
+            manifest.chunks.push((address, chunk.len() as u64));
+        }
+        self.write(Kind::Manifest, manifest.to_string().as_bytes())
+    }
+
+    /// the manifest stored under `address`
+    pub fn manifest(&self, address: &Address) -> Result<Manifest> {
+        let text = self.read(Kind::Manifest, address)?;
+        String::from_utf8(text)
+            .map_err(|_| Error::CorruptObject(hex::encode(address, Format::LOWER)))?
+            .parse()
+    }
+
+    /// writes the file of the manifest `address`, checking every chunk against its address
+    pub fn get(&self, address: &Address, mut writer: impl Write) -> Result<()> {
+        let _lock = self.lock(false)?;
+        for (chunk, length) in self.manifest(address)?.chunks {
+            let data = self.read(Kind::Chunk, &chunk)?;
+            if data.len() as u64 != length {
+                return Err(Error::CorruptObject(hex::encode(&chunk, Format::LOWER)));
+            }
+            writer.write_all(&data)?;
+        }
+        Ok(writer.flush()?)
+    }
+
+    /// the addresses of the stored manifests
+    pub fn manifests(&self) -> Result<Vec<Address>> {
+        self.list(Kind::Manifest)
+    }
+
+    /// removes the manifest `address`, whose chunks are removed by the next [`Store::gc`]
+    /// unless other manifests use them
+    pub fn forget(&self, address: &Address) -> Result<()> {
+        match fs::remove_file(self.path(Kind::Manifest, address)) {
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                Err(Error::MissingObject(hex::encode(address, Format::LOWER)))
+            }
+            result => Ok(result?),
+        }
+    }
+
+    /// checks every object against its address, and that every chunk of every manifest is
+    /// stored
+    pub fn verify(&self) -> Result<Vec<Problem>> {
+        let _lock = self.lock(false)?;
+        let mut problems = Vec::new();
+        let chunks = self.list(Kind::Chunk)?;
+        for chunk in &chunks {
+            match self.read(Kind::Chunk, chunk) {
+                Err(Error::CorruptObject(_)) => problems.push(Problem::CorruptChunk(*chunk)),
+                result => drop(result?),
+            }
+        }
+        let chunks = chunks.into_iter().collect::<HashSet<_>>();
+        for manifest in self.manifests()? {
+            let listed = match self.manifest(&manifest) {
+                Err(Error::CorruptObject(_) | Error::Parse(_) | Error::BadHex(_)) => {
+                    problems.push(Problem::CorruptManifest(manifest));
+                    continue;
+                }
+                listed => listed?,
+            };
+            for (chunk, _) in listed.chunks {
+                if !chunks.contains(&chunk) {
+                    problems.push(Problem::MissingChunk { manifest, chunk });
+                }
+            }
+        }
+        Ok(problems)
+    }
+
+    /// removes the chunks that no manifest uses, and the leftovers of interrupted writes
+    ///
+    /// Nothing is removed if a manifest cannot be read, since its chunks are not known. This
+    /// waits for the other operations on the store to end, and they wait for it.
+    pub fn gc(&self) -> Result<Collected> {
+        let _lock = self.lock(true)?;
+        let mut used = HashSet::new();
+        for manifest in self.manifests()? {
+            used.extend(
+                self.manifest(&manifest)?
+                    .chunks
+                    .into_iter()
+                    .map(|(chunk, _)| chunk),
+            );
+        }
+        let mut collected = Collected::default();
+        for chunk in self.list(Kind::Chunk)? {
+            if !used.contains(&chunk) {
+                let path = self.path(Kind::Chunk, &chunk);
+                collected.bytes += fs::metadata(&path)?.len();
+                fs::remove_file(path)?;
+                collected.chunks += 1;
+            }
+        }
+        for temporary in fs::read_dir(self.root.join("tmp"))? {
+            fs::remove_file(temporary?.path())?;
+        }
+        Ok(collected)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{kmac::cshake128, testing::TempDir};
+
+    /// an empty store in a new temporary directory, removed when dropped
+    struct TestStore {
+        store: Store,
+        _dir: TempDir,
+    }
+
+    impl TestStore {
+        fn new(name: &str) -> Self {
+            let dir = TempDir::new("store", name);
+            TestStore {
+                store: Store::open(&*dir).unwrap(),
+                _dir: dir,
+            }
+        }
+    }
+
+    impl std::ops::Deref for TestStore {
+        type Target = Store;
+
+        fn deref(&self) -> &Store {
+            &self.store
+        }
+    }
+
+    const PARAMS: cdc::Params = cdc::Params {
+        min_size: 64,
+        avg_size: 256,
+        max_size: 1024,
+    };
+
+    fn data(length: usize, seed: u8) -> Vec<u8> {
+        cshake128(b"", &[seed]).finalize().squeeze_vec(length)
+    }
+
+    fn get(store: &Store, manifest: &Address) -> Vec<u8> {
+        let mut file = Vec::new();
+        store.get(manifest, &mut file).unwrap();
+        file
+    }
+
+    #[test]
+    fn put_then_get() {
+        let store = TestStore::new("roundtrip");
+        for length in [0, 1, 63, 1024, 10_000] {
+            let file = data(length, 0);
+            let manifest = store.put(&file[..], &PARAMS).unwrap();
+            assert_eq!(get(&store, &manifest), file);
+            assert_eq!(store.manifest(&manifest).unwrap().size(), length as u64);
+        }
+        assert!(store.verify().unwrap().is_empty());
+    }
+
+    #[test]
+    fn manifest_address_is_its_hash() {
+        let store = TestStore::new("manifest");
+        let manifest = store.put(&b"some file"[..], &PARAMS).unwrap();
+        let text = format!(
+            "Size = 9\nChunk = {} 9\n",
+            hex::encode(&address(b"some file"), Format::LOWER)
+        );
+        assert_eq!(manifest, address(text.as_bytes()));
+        assert_eq!(text.parse::<Manifest>().unwrap().to_string(), text);
+        let name = hex::encode(&manifest, Format::LOWER);
+        assert!(store
+            .root()
+            .join("manifests")
+            .join(&name[..2])
+            .join(&name[2..])
+            .exists());
+    }
+
+    #[test]
+    fn similar_files_share_chunks() {
+        let store = TestStore::new("dedup");
+        let original = data(20_000, 1);
+        let mut edited = original.clone();
+        edited[10_000] ^= 1;
+        let first = store.put(&original[..], &PARAMS).unwrap();
+        let chunks = store.list(Kind::Chunk).unwrap().len();
+        let second = store.put(&edited[..], &PARAMS).unwrap();
+        let added = store.list(Kind::Chunk).unwrap().len() - chunks;
+        assert!((1..=3).contains(&added), "{added} new chunks");
+        assert_eq!(get(&store, &first), original);
+        assert_eq!(get(&store, &second), edited);
+
+        // storing the same file again adds nothing
+        assert_eq!(store.put(&original[..], &PARAMS).unwrap(), first);
+        assert_eq!(store.list(Kind::Chunk).unwrap().len(), chunks + added);
+    }
+
+    #[test]
+    fn gc_keeps_the_chunks_in_use() {
+        let store = TestStore::new("gc");
+        let kept = data(5_000, 2);
+        let forgotten = data(5_000, 3);
+        let kept_manifest = store.put(&kept[..], &PARAMS).unwrap();
+        let forgotten_manifest = store.put(&forgotten[..], &PARAMS).unwrap();
+        assert_eq!(store.gc().unwrap(), Collected::default());
+
+        store.forget(&forgotten_manifest).unwrap();
+        assert!(matches!(
+            store.forget(&forgotten_manifest),
+            Err(Error::MissingObject(_))
+        ));
+        let collected = store.gc().unwrap();
+        assert_eq!(collected.bytes, 5_000);
+        assert_eq!(
+            collected.chunks,
+            Chunker::new(&forgotten[..], PARAMS).count()
+        );
+        assert_eq!(store.manifests().unwrap(), [kept_manifest]);
+        assert_eq!(get(&store, &kept_manifest), kept);
+        assert!(matches!(
+            store.get(&forgotten_manifest, io::sink()),
+            Err(Error::MissingObject(_))
+        ));
+    }
+
+    #[test]
+    fn gc_waits_for_puts() {
+        let store = TestStore::new("lock");
+        let pending = store.root().join("tmp").join("pending");
+        fs::write(&pending, b"chunk of a put").unwrap();
+        let put = store.lock(false).unwrap();
+        std::thread::scope(|scope| {
+            let gc = scope.spawn(|| store.gc().unwrap());
+            std::thread::sleep(std::time::Duration::from_millis(100));
+            assert!(!gc.is_finished());
+            assert!(pending.exists());
+            drop(put);
+            gc.join().unwrap();
+        });
+        assert!(!pending.exists());
+    }
+
+    #[test]
+    fn verify_finds_corruption() {
+        let store = TestStore::new("verify");
+        let file = data(3_000, 4);
+        let manifest = store.put(&file[..], &PARAMS).unwrap();
+        let chunks = store.manifest(&manifest).unwrap().chunks;
+
+        let corrupt = store.path(Kind::Chunk, &chunks[0].0);
+        let mut bytes = fs::read(&corrupt).unwrap();
+        bytes[0] ^= 1;
+        fs::write(&corrupt, bytes).unwrap();
+        fs::remove_file(store.path(Kind::Chunk, &chunks[1].0)).unwrap();
+
+        assert_eq!(
+            store.verify().unwrap(),
+            [
+                Problem::CorruptChunk(chunks[0].0),
+                Problem::MissingChunk {
+                    manifest,
+                    chunk: chunks[1].0
+                }
+            ]
+        );
+        assert!(matches!(
+            store.get(&manifest, io::sink()),
+            Err(Error::CorruptObject(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_malformed_manifests() {
+        let chunk = hex::encode(&[0xAB; ADDRESS_BYTES], Format::LOWER);
+        assert!(matches!(
+            format!("Size = 4\nChunk = {chunk} 5\n").parse::<Manifest>(),
+            Err(Error::Parse(ParseError { line: 1, .. }))
+        ));
+        assert!(matches!(
+            format!("Chunk = {chunk} 5\n").parse::<Manifest>(),
+            Err(Error::Parse(ParseError { line: 1, .. }))
+        ));
+        assert!(matches!(
+            format!("Size = 5\nChunk = {chunk}\n").parse::<Manifest>(),
+            Err(Error::Parse(ParseError { line: 2, .. }))
+        ));
+        assert_eq!(
+            "Size = 0\n".parse::<Manifest>().unwrap(),
+            Manifest::default()
+        );
+    }
+}
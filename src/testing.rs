@@ -0,0 +1,40 @@
+//! Helpers shared by the unit tests
+use std::fs;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+
+/// an empty directory `shake128-<module>-<name>-<process id>` in the temporary directory, removed
+/// when dropped
+pub(crate) struct TempDir(PathBuf);
+
+impl TempDir {
+    pub(crate) fn new(module: &str, name: &str) -> Self {
+        let path =
+            std::env::temp_dir().join(format!("shake128-{module}-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).unwrap();
+        TempDir(path)
+    }
+
+    /// writes `content` to the file at `relative`, creating its parent directories
+    pub(crate) fn write(&self, relative: &str, content: &[u8]) -> PathBuf {
+        let path = self.0.join(relative);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, content).unwrap();
+        path
+    }
+}
+
+impl Deref for TempDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
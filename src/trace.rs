@@ -0,0 +1,140 @@
+//! Observing the permutation round by round and step by step
+//!
+//! [`keccak_p_observed`](crate::keccak::keccak_p_observed) calls an [`Observer`] at the start of
+//! every round and after every step mapping. `()` observes nothing, which is what the plain
+//! permutation uses, and [`HexTrace`] writes every intermediate state in the layout of the NIST
+//! example files.
+use std::fmt;
+use std::io::{self, Write};
+
+use crate::{convert::state_to_bytes_string, lane::Lane, STATE_SIZE};
+
+/// the five step mappings of a round, in the order they are applied
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Step {
+    Theta,
+    Rho,
+    Pi,
+    Chi,
+    Iota,
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Step::Theta => "theta",
+            Step::Rho => "rho",
+            Step::Pi => "pi",
+            Step::Chi => "chi",
+            Step::Iota => "iota",
+        })
+    }
+}
+
+pub trait Observer<L: Lane> {
+    /// called with the input of round index `round`
+    fn round(&mut self, _round: i64, _state: &[L; STATE_SIZE]) {}
+
+    /// called with the state right after `step` was applied in round index `round`
+    fn step(&mut self, _round: i64, _step: Step, _state: &[L; STATE_SIZE]) {}
+}
+
+impl<L: Lane> Observer<L> for () {}
+
+/// writes the state after every step, as in KeccakTools intermediate values:
+/// ```text
+/// --- Round 0 ---
+///
+/// After theta:
+/// 00 00 00 …
+///
+/// ```
+pub struct HexTrace<W: Write> {
+    out: W,
+    /// first write error, after which nothing more is written
+    result: io::Result<()>,
+}
+
+impl<W: Write> HexTrace<W> {
+    pub fn new(out: W) -> Self {
+        HexTrace {
+            out,
+            result: Ok(()),
+        }
+    }
+
+    /// returns the writer, or the first error encountered while tracing
+    pub fn finish(self) -> io::Result<W> {
+        self.result.map(|()| self.out)
+    }
+
+    pub(crate) fn write(&mut self, args: fmt::Arguments) {
+        if self.result.is_ok() {
+            self.result = self.out.write_fmt(args);
+        }
+    }
+}
+
+impl<W: Write> Observer<u64> for HexTrace<W> {
+    fn round(&mut self, round: i64, _state: &[u64; STATE_SIZE]) {
+        self.write(format_args!("--- Round {round} ---\n\n"));
+    }
+
+    fn step(&mut self, _round: i64, step: Step, state: &[u64; STATE_SIZE]) {
+        self.write(format_args!(
+            "After {step}:\n{}\n\n",
+            state_to_bytes_string(state)
+        ));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::keccak::{keccak_f, keccak_p_observed};
+
+    /// records the calls it receives
+    #[derive(Default)]
+    struct Recorder(Vec<(i64, Option<Step>)>);
+
+    impl<L: Lane> Observer<L> for Recorder {
+        fn round(&mut self, round: i64, _state: &[L; STATE_SIZE]) {
+            self.0.push((round, None));
+        }
+
+        fn step(&mut self, round: i64, step: Step, _state: &[L; STATE_SIZE]) {
+            self.0.push((round, Some(step)));
+        }
+    }
+
+    #[test]
+    fn observed_order() {
+        let mut recorder = Recorder::default();
+        let state = keccak_p_observed([0u8; STATE_SIZE], 2, &mut recorder);
+        assert_eq!(state, keccak_p_observed([0u8; STATE_SIZE], 2, &mut ()));
+
+        let mut expected = Vec::new();
+        for round in [16, 17] {
+            expected.push((round, None));
+            for step in [Step::Theta, Step::Rho, Step::Pi, Step::Chi, Step::Iota] {
+                expected.push((round, Some(step)));
+            }
+        }
+        assert_eq!(recorder.0, expected);
+    }
+
+    #[test]
+    fn hex_trace() {
+        let mut trace = HexTrace::new(Vec::new());
+        let state = keccak_p_observed([0u64; STATE_SIZE], 24, &mut trace);
+        let trace = String::from_utf8(trace.finish().unwrap()).unwrap();
+
+        assert_eq!(state, keccak_f([0u64; STATE_SIZE]));
+        assert_eq!(trace.matches("--- Round ").count(), 24);
+        assert!(trace.starts_with("--- Round 0 ---\n\nAfter theta:\n00 00 "));
+        assert!(trace.ends_with(&format!(
+            "After iota:\n{}\n\n",
+            state_to_bytes_string(&state)
+        )));
+    }
+}
@@ -0,0 +1,238 @@
+//! Runs the `digest` tests of SHAKE128 and SHA-3 on the vectors of the RustCrypto `sha3` crate
+//!
+//! The files of `tests/data` are copied from `sha3` 0.10.8. The `dev` feature of `digest` needs
+//! the `blobby` crate, which is not vendored, so the blob reader, `xof_reset_test` and
+//! `fixed_reset_test` of `digest::dev` are reproduced here, and `new_test!` is written the same
+//! way.
+#![cfg(feature = "digest")]
+
+use digest::{Digest, ExtendableOutputReset, FixedOutputReset, Mac, XofReader};
+use TD1::{
+    sha3::{Sha3_224, Sha3_256, Sha3_384, Sha3_512},
+    sponge::Shake128,
+};
+
+/// reads a variable-length quantity of `blobby`: 7 bits per byte, most significant first, with
+/// the high bit set on every byte but the last one, and each continuation adding one
+fn read_vlq(data: &mut &[u8]) -> usize {
+    let mut value = 0;
+    loop {
+        let (&byt, rest) = data.split_first().expect("truncated blob");
+        *data = rest;
+        value += (byt & 0x7F) as usize;
+        if byt & 0x80 == 0 {
+            return value;
+        }
+        value = (value + 1) << 7;
+    }
+}
+
+fn take<'a>(data: &mut &'a [u8], length: usize) -> &'a [u8] {
+    let (blob, rest) = data.split_at(length);
+    *data = rest;
+    blob
+}
+
+/// the blobs of a `blobby` file: a table of deduplicated blobs, then every blob either as an
+/// index in this table (odd header) or as its length and bytes (even header)
+fn read_blobs(mut data: &[u8]) -> Vec<&[u8]> {
+    let table = (0..read_vlq(&mut data))
+        .map(|_| {
+            let length = read_vlq(&mut data);
+            take(&mut data, length)
+        })
+        .collect::<Vec<_>>();
+    let mut blobs = Vec::new();
+    while !data.is_empty() {
+        let header = read_vlq(&mut data);
+        if header & 1 == 1 {
+            blobs.push(table[header >> 1]);
+        } else {
+            blobs.push(take(&mut data, header >> 1));
+        }
+    }
+    blobs
+}
+
+/// `digest::dev::xof_reset_test`
+fn xof_reset_test<D>(input: &[u8], output: &[u8]) -> Option<&'static str>
+where
+    D: ExtendableOutputReset + Default + Clone,
+{
+    let mut hasher = D::default();
+    let mut buf = [0u8; 1024];
+    let buf = &mut buf[..output.len()];
+    hasher.update(input);
+    let mut hasher2 = hasher.clone();
+    hasher.finalize_xof_into(buf);
+    if buf != output {
+        return Some("whole message");
+    }
+    buf.fill(0);
+
+    hasher2.reset();
+    hasher2.update(input);
+    hasher2.finalize_xof_reset_into(buf);
+    if buf != output {
+        return Some("whole message after reset");
+    }
+    buf.fill(0);
+
+    for n in 1..input.len().min(17) {
+        let mut hasher = D::default();
+        for chunk in input.chunks(n) {
+            hasher.update(chunk);
+            hasher2.update(chunk);
+        }
+        hasher.finalize_xof_into(buf);
+        if buf != output {
+            return Some("message in chunks");
+        }
+        buf.fill(0);
+
+        hasher2.finalize_xof_reset_into(buf);
+        if buf != output {
+            return Some("message in chunks");
+        }
+        buf.fill(0);
+    }
+    None
+}
+
+/// `digest::dev::fixed_reset_test`
+fn fixed_reset_test<D>(input: &[u8], output: &[u8]) -> Option<&'static str>
+where
+    D: FixedOutputReset + Digest + Clone + Default,
+{
+    let mut hasher = <D as Digest>::new();
+    Digest::update(&mut hasher, input);
+    let mut hasher2 = hasher.clone();
+    if Digest::finalize(hasher)[..] != output[..] {
+        return Some("whole message");
+    }
+
+    Digest::reset(&mut hasher2);
+    Digest::update(&mut hasher2, input);
+    if Digest::finalize_reset(&mut hasher2)[..] != output[..] {
+        return Some("whole message after reset");
+    }
+
+    for n in 1..input.len().min(17) {
+        let mut hasher = <D as Digest>::new();
+        for chunk in input.chunks(n) {
+            Digest::update(&mut hasher, chunk);
+            Digest::update(&mut hasher2, chunk);
+        }
+        if Digest::finalize(hasher)[..] != output[..] {
+            return Some("message in chunks");
+        }
+        if Digest::finalize_reset(&mut hasher2)[..] != output[..] {
+            return Some("message in chunks");
+        }
+    }
+    None
+}
+
+/// `digest::new_test!`
+macro_rules! new_test {
+    ($name:ident, $test_name:expr, $hasher:ty, $test_func:ident) => {
+        #[test]
+        fn $name() {
+            let data = include_bytes!(concat!("data/", $test_name, ".blb"));
+            let blobs = read_blobs(data);
+            assert!(!blobs.is_empty() && blobs.len().is_multiple_of(2));
+            for (i, row) in blobs.chunks_exact(2).enumerate() {
+                let (input, output) = (row[0], row[1]);
+                if let Some(desc) = $test_func::<$hasher>(input, output) {
+                    panic!("\nFailed test №{i}: {desc}\ninput:\t{input:?}\noutput:\t{output:?}\n");
+                }
+            }
+        }
+    };
+}
+
+new_test!(shake128, "shake128", Shake128, xof_reset_test);
+new_test!(sha3_224, "sha3_224", Sha3_224, fixed_reset_test);
+new_test!(sha3_256, "sha3_256", Sha3_256, fixed_reset_test);
+new_test!(sha3_384, "sha3_384", Sha3_384, fixed_reset_test);
+new_test!(sha3_512, "sha3_512", Sha3_512, fixed_reset_test);
+
+/// the `hmac` crate, given our SHA-3, agrees with [`TD1::hmac`]
+#[test]
+fn hmac_crate() {
+    for key in [&b"key"[..], &[0x42; 136], &[0x42; 200]] {
+        let mut mac = hmac::SimpleHmac::<Sha3_256>::new_from_slice(key).unwrap();
+        mac.update(b"message");
+        assert_eq!(
+            mac.finalize().into_bytes()[..],
+            TD1::hmac::hmac::<Sha3_256>(key, b"message")
+        );
+    }
+}
+
+/// the `hmac` crate agrees with [`TD1::hmac::Hmac::verify`] and
+/// [`TD1::hmac::Hmac::verify_truncated`]
+#[test]
+fn hmac_crate_verifies_alike() {
+    let tag = TD1::hmac::hmac::<Sha3_256>(b"key", b"message");
+    let ours = || {
+        let mut mac = TD1::hmac::Hmac::<Sha3_256>::new(b"key");
+        mac.update(b"message");
+        mac
+    };
+    let theirs = || {
+        let mut mac = hmac::SimpleHmac::<Sha3_256>::new_from_slice(b"key").unwrap();
+        mac.update(b"message");
+        mac
+    };
+    for length in [16, 24, 32] {
+        let mut forged = tag[..length].to_vec();
+        forged[length - 1] ^= 1;
+        for tag in [&tag[..length], &forged] {
+            assert_eq!(
+                ours().verify_truncated(tag, length),
+                theirs().verify_truncated_left(tag).is_ok()
+            );
+            assert_eq!(ours().verify(tag), theirs().verify_slice(tag).is_ok());
+        }
+    }
+}
+
+/// HKDF with SHA-3, written out with the `hmac` crate as in RFC 5869, agrees with [`TD1::hkdf`]
+#[test]
+fn hkdf_matches_hmac_crate() {
+    type HmacSha3 = hmac::SimpleHmac<Sha3_512>;
+    let (salt, ikm, info) = (b"salt", [0x0B; 22], b"info");
+    let mut extract = HmacSha3::new_from_slice(salt).unwrap();
+    extract.update(&ikm);
+    let prk = extract.finalize().into_bytes();
+
+    let mut okm = Vec::new();
+    let mut previous = Vec::new();
+    for counter in 1..=3u8 {
+        let mut expand = HmacSha3::new_from_slice(&prk).unwrap();
+        expand.update(&previous);
+        expand.update(info);
+        expand.update(&[counter]);
+        previous = expand.finalize().into_bytes().to_vec();
+        okm.extend_from_slice(&previous);
+    }
+    okm.truncate(150);
+
+    let hkdf = TD1::hkdf::Hkdf::<Sha3_512>::extract(Some(salt), &ikm);
+    assert_eq!(hkdf.prk(), &prk[..]);
+    let mut ours = vec![0; 150];
+    hkdf.expand(info, &mut ours).unwrap();
+    assert_eq!(ours, okm);
+}
+
+#[test]
+fn reader_continues_the_output() {
+    let mut reader = digest::ExtendableOutput::finalize_xof(Shake128::new());
+    let mut first = [0; 100];
+    let mut second = [0; 200];
+    reader.read(&mut first);
+    reader.read(&mut second);
+    let whole = Shake128::new().finalize().squeeze_vec(300);
+    assert_eq!([&first[..], &second[..]].concat(), whole);
+}
@@ -0,0 +1,109 @@
+use TD1::{
+    example::{parse_examples, Example},
+    keccak::{keccak_f, keccak_p_observed},
+    sponge::{Shake128, RATE_BYTES},
+    trace::{Observer, Step},
+    BITS_PER_BYTE, STATE_SIZE,
+};
+
+/// records the state after every step
+#[derive(Default)]
+struct Recorder(Vec<(i64, Step, [u64; STATE_SIZE])>);
+
+impl Observer<u64> for Recorder {
+    fn step(&mut self, round: i64, step: Step, state: &[u64; STATE_SIZE]) {
+        self.0.push((round, step, *state));
+    }
+}
+
+fn state_bytes(state: &[u64; STATE_SIZE]) -> Vec<u8> {
+    state.iter().flat_map(|lane| lane.to_le_bytes()).collect()
+}
+
+/// checks every value of `example` against the permutation and the sponge
+fn check(example: &Example) {
+    let name = &example.description;
+    assert_eq!(
+        example.message.len(),
+        example.message_bits.div_ceil(BITS_PER_BYTE),
+        "{name}"
+    );
+
+    for permutation in &example.permutations {
+        assert_eq!(keccak_f(permutation.input), permutation.output, "{name}");
+        if !permutation.rounds.is_empty() {
+            let mut recorder = Recorder::default();
+            keccak_p_observed(permutation.input, 24, &mut recorder);
+            let steps = permutation
+                .rounds
+                .iter()
+                .flat_map(|round| {
+                    round
+                        .steps
+                        .iter()
+                        .map(|&(step, state)| (round.index, step, state))
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(steps, recorder.0, "{name}");
+        }
+    }
+
+    // every block is XORed into the output of the previous permutation
+    assert!(example.permutations.len() >= example.blocks.len(), "{name}");
+    let mut state = [0; STATE_SIZE];
+    for (block, permutation) in example.blocks.iter().zip(&example.permutations) {
+        let mut input = state_bytes(&state);
+        for (byt, block_byte) in input.iter_mut().zip(block) {
+            *byt ^= block_byte;
+        }
+        assert_eq!(input, state_bytes(&permutation.input), "{name}");
+        state = permutation.output;
+    }
+
+    // then every permutation gives the next squeezed block
+    let squeezed = example.permutations[example.blocks.len() - 1..]
+        .iter()
+        .flat_map(|permutation| state_bytes(&permutation.output)[..RATE_BYTES].to_vec())
+        .collect::<Vec<u8>>();
+    assert_eq!(example.output, squeezed[..example.output.len()], "{name}");
+
+    // the sponge only hashes whole bytes
+    if example.message_bits.is_multiple_of(BITS_PER_BYTE) {
+        let mut hasher = Shake128::new();
+        hasher.update(&example.message);
+        assert_eq!(
+            hasher.finalize().squeeze_vec(example.output.len()),
+            example.output,
+            "{name}"
+        );
+    }
+}
+
+#[test]
+fn input_example() {
+    let examples = parse_examples(include_str!("../input_example.txt")).unwrap();
+    assert_eq!(examples.len(), 7);
+    examples.iter().for_each(check);
+}
+
+/// `shake128 explain --rounds 32` on the empty string
+///
+/// This file is an output of this code, so the states after every step only catch regressions:
+/// they are not checked against published intermediate values (the NIST examples or the
+/// KeccakTools `KeccakF-1600-IntermediateValues.txt`), which are not in the repository. The input
+/// and output of its permutation are those of `input_example.txt`, which `input_example` checks.
+#[test]
+fn empty_rounds() {
+    let examples = parse_examples(include_str!("empty_rounds.txt")).unwrap();
+    assert_eq!(examples.len(), 1);
+    assert_eq!(examples[0].permutations[0].rounds.len(), 24);
+    examples.iter().for_each(check);
+
+    let reference = &parse_examples(include_str!("../input_example.txt")).unwrap()[0];
+    assert_eq!(examples[0].description, reference.description);
+    let (permutation, expected) = (&examples[0].permutations[0], &reference.permutations[0]);
+    assert_eq!(
+        (permutation.input, permutation.output),
+        (expected.input, expected.output)
+    );
+}
@@ -94,3 +94,542 @@ fn binary() {
         "9B171CCF7FF6B9478CE02A54A5A558DDE55FEBC70E12F0ED402567639E404B74"
     );
 }
+
+#[test]
+fn trace() {
+    Command::new("cargo")
+        .arg("build")
+        .status()
+        .expect("Could not build shake128");
+
+    let shake128 = Command::new("./target/debug/shake128")
+        .args(["--trace", "32"])
+        .stdin(Stdio::null())
+        .output()
+        .expect("Could not execute shake128");
+
+    assert_eq!(
+        String::from_utf8(shake128.stdout)
+            .expect("Command output is not a valid UTF8 string")
+            .trim(),
+        "7F9C2BA4E88F827D616045507605853ED73B8093F6EFBC88EB1A6EACFA66EF26"
+    );
+
+    let trace = String::from_utf8(shake128.stderr).expect("Trace is not a valid UTF8 string");
+    assert_eq!(trace.matches("--- Round ").count(), 24);
+    // the state after the last iota is the one of input_example.txt after the permutation
+    assert!(trace.ends_with("After iota:\n7F 9C 2B A4 E8 8F 82 7D 61 60 45 50 76 05 85 3E D7 3B 80 93 F6 EF BC 88 EB 1A 6E AC FA 66 EF 26 3C B1 EE A9 88 00 4B 93 10 3C FB 0A EE FD 2A 68 6E 01 FA 4A 58 E8 A3 63 9C A8 A1 E3 F9 AE 57 E2 35 B8 CC 87 3C 23 DC 62 B8 D2 60 16 9A FA 2F 75 AB 91 6A 58 D9 74 91 88 35 D2 5E 6A 43 50 85 B2 BA DF D6 DF AA C3 59 A5 EF BB 7B CC 4B 59 D5 38 DF 9A 04 30 2E 10 C8 BC 1C BF 1A 0B 3A 51 20 EA 17 CD A7 CF AD 76 5F 56 23 47 4D 36 8C CC A8 AF 00 07 CD 9F 5E 4C 84 9F 16 7A 58 0B 14 AA BD EF AE E7 EE F4 7C B0 FC A9 4C CA AE BA 77 4E C2 0C FF 6A 94 85 A9 7B FC 65 AA 93 AA 4F C9 58 D1 ED B5 27 C0 2E 3A E5 7B CC \n\n"));
+}
+
+#[test]
+fn explain() {
+    Command::new("cargo")
+        .arg("build")
+        .status()
+        .expect("Could not build shake128");
+
+    let mut example = Vec::new();
+    let _ = File::open("input_example.txt")
+        .expect("Could not open file")
+        .read_to_end(&mut example)
+        .expect("Failed to read file");
+    let example = String::from_utf8(example).expect("Example is not a valid UTF8 string");
+
+    let shake128 = Command::new("./target/debug/shake128")
+        .arg("explain")
+        .stdin(Stdio::null())
+        .output()
+        .expect("Could not execute shake128");
+
+    // the empty string is the first example of the file
+    let explained =
+        String::from_utf8(shake128.stdout).expect("Command output is not a valid UTF8 string");
+    assert!(example.starts_with(&explained));
+}
+
+#[test]
+fn kat() {
+    Command::new("cargo")
+        .arg("build")
+        .status()
+        .expect("Could not build shake128");
+
+    let shake128 = Command::new("./target/debug/shake128")
+        .args(["kat", "tests/kat/SHAKE128ShortMsg.rsp"])
+        .output()
+        .expect("Could not execute shake128");
+
+    assert!(shake128.status.success());
+    assert_eq!(
+        String::from_utf8(shake128.stdout)
+            .expect("Command output is not a valid UTF8 string")
+            .trim(),
+        "tests/kat/SHAKE128ShortMsg.rsp: 169 passed, 0 failed"
+    );
+}
+
+#[test]
+fn exit_codes() {
+    Command::new("cargo")
+        .arg("build")
+        .status()
+        .expect("Could not build shake128");
+
+    let run = |args: &[&str]| {
+        Command::new("./target/debug/shake128")
+            .args(args)
+            .stdin(Stdio::null())
+            .output()
+            .expect("Could not execute shake128")
+    };
+
+    let missing_size = run(&[]);
+    assert_eq!(missing_size.status.code(), Some(2));
+    assert!(String::from_utf8(missing_size.stderr)
+        .expect("Diagnostic is not a valid UTF8 string")
+        .starts_with("shake128: no hash size provided\nusage: "));
+
+    assert_eq!(run(&["thirty-two"]).status.code(), Some(2));
+    assert_eq!(run(&["explain", "--bits", "8"]).status.code(), Some(3));
+    assert_eq!(
+        run(&["kat", "tests/kat/missing.rsp"]).status.code(),
+        Some(4)
+    );
+}
+
+#[test]
+fn formats() {
+    Command::new("cargo")
+        .arg("build")
+        .status()
+        .expect("Could not build shake128");
+
+    let run = |format: &str| {
+        Command::new("./target/debug/shake128")
+            .args(["--format", format, "32"])
+            .stdin(Stdio::null())
+            .output()
+            .expect("Could not execute shake128")
+            .stdout
+    };
+
+    assert_eq!(
+        run("hex"),
+        b"7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef26\n"
+    );
+    assert_eq!(
+        run("base64"),
+        b"f5wrpOiPgn1hYEVQdgWFPtc7gJP277yI6xpurPpm7yY=\n"
+    );
+    assert_eq!(
+        run("base32"),
+        b"P6OCXJHIR6BH2YLAIVIHMBMFH3LTXAET63X3ZCHLDJXKZ6TG54TA====\n"
+    );
+    // no newline after the raw bytes
+    assert_eq!(run("raw")[..4], [0x7F, 0x9C, 0x2B, 0xA4]);
+    assert_eq!(run("raw").len(), 32);
+}
+
+#[test]
+fn bits() {
+    Command::new("cargo")
+        .arg("build")
+        .status()
+        .expect("Could not build shake128");
+
+    let shake128 = Command::new("./target/debug/shake128")
+        .args(["--bits", "250"])
+        .stdin(Stdio::null())
+        .output()
+        .expect("Could not execute shake128");
+
+    // the 6 high bits of the last byte (26) are cleared
+    assert_eq!(
+        String::from_utf8(shake128.stdout)
+            .expect("Command output is not a valid UTF8 string")
+            .trim(),
+        "7F9C2BA4E88F827D616045507605853ED73B8093F6EFBC88EB1A6EACFA66EF02"
+    );
+
+    let run = |args: &[&str]| {
+        Command::new("./target/debug/shake128")
+            .args(args)
+            .stdin(Stdio::null())
+            .output()
+            .expect("Could not execute shake128")
+    };
+    // a size in bytes next to --bits is a usage error, not a file
+    let both = run(&["--bits", "250", "32"]);
+    assert_eq!(both.status.code(), Some(2));
+    assert!(String::from_utf8(both.stderr)
+        .expect("Command output is not a valid UTF8 string")
+        .contains("both in bytes and in bits"));
+    // after `--`, every argument is a file
+    let marked = run(&["--bits", "250", "--", "Cargo.toml"]);
+    assert_eq!(marked.status.code(), Some(0));
+    assert_eq!(marked.stdout, run(&["--bits", "250", "Cargo.toml"]).stdout);
+    assert_eq!(run(&["--bits", "250", "--", "32"]).status.code(), Some(4));
+    // a number of bytes whose number of bits does not fit is rejected, not wrapped
+    assert_eq!(run(&[&(usize::MAX / 4).to_string()]).status.code(), Some(2));
+}
+
+#[test]
+fn derive() {
+    Command::new("cargo")
+        .arg("build")
+        .status()
+        .expect("Could not build shake128");
+
+    let expected = TD1::kmac::derive_key(b"master secret", b"database", b"production", 32);
+    let shake128 = Command::new("./target/debug/shake128")
+        .args(["derive", "--key-env", "SHAKE128_TEST_MASTER_KEY"])
+        .args([
+            "--label",
+            "database",
+            "--context",
+            "production",
+            "--format",
+            "hex",
+        ])
+        .arg("32")
+        .env("SHAKE128_TEST_MASTER_KEY", "master secret")
+        .stdin(Stdio::null())
+        .output()
+        .expect("Could not execute shake128");
+    assert_eq!(
+        String::from_utf8(shake128.stdout)
+            .expect("Command output is not a valid UTF8 string")
+            .trim(),
+        TD1::hex::encode(&expected, TD1::hex::Format::LOWER)
+    );
+
+    // the master key is never read from the command line
+    let without_key = Command::new("./target/debug/shake128")
+        .args(["derive", "--label", "database", "32"])
+        .env_remove("SHAKE128_TEST_MASTER_KEY")
+        .output()
+        .expect("Could not execute shake128");
+    assert_eq!(without_key.status.code(), Some(2));
+    let unset = Command::new("./target/debug/shake128")
+        .args(["derive", "--key-env", "SHAKE128_TEST_MASTER_KEY"])
+        .args(["--label", "database", "32"])
+        .env_remove("SHAKE128_TEST_MASTER_KEY")
+        .output()
+        .expect("Could not execute shake128");
+    assert_eq!(unset.status.code(), Some(2));
+}
+
+#[test]
+fn passwd() {
+    Command::new("cargo")
+        .arg("build")
+        .status()
+        .expect("Could not build shake128");
+
+    let run = |args: &[&str], password: &[u8]| {
+        let mut shake128 = Command::new("./target/debug/shake128")
+            .arg("passwd")
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .expect("Could not execute shake128");
+        shake128
+            .stdin
+            .take()
+            .expect("Could not access stdin")
+            .write_all(password)
+            .expect("Failed to write to shake128's stdin");
+        shake128.wait_with_output().expect("shake128 did not end")
+    };
+
+    let hashed = run(
+        &["--memory", "4", "--time", "1", "--parallelism", "2"],
+        b"hunter2\n",
+    );
+    assert_eq!(hashed.status.code(), Some(0));
+    let stored =
+        String::from_utf8(hashed.stdout).expect("Command output is not a valid UTF8 string");
+    let stored = stored.trim();
+    assert!(stored.starts_with("$balloon-shake128$m=4,t=1,p=2$"));
+    assert!(TD1::balloon::verify_password(b"hunter2", stored).unwrap());
+
+    assert_eq!(
+        run(&["--verify", stored], b"hunter2").status.code(),
+        Some(0)
+    );
+    assert_eq!(
+        run(&["--verify", stored], b"hunter3\n").status.code(),
+        Some(1)
+    );
+    assert_eq!(
+        run(&["--verify", "$md5$x"], b"hunter2").status.code(),
+        Some(3)
+    );
+    assert_eq!(run(&["--memory", "0"], b"hunter2").status.code(), Some(2));
+}
+
+#[test]
+fn tree() {
+    Command::new("cargo")
+        .arg("build")
+        .status()
+        .expect("Could not build shake128");
+
+    let data = (0..10_000u32).map(|i| (i * 7) as u8).collect::<Vec<_>>();
+    let file = std::env::temp_dir().join(format!("shake128-tree-{}", std::process::id()));
+    std::fs::write(&file, &data).expect("Could not write the test file");
+    let file = file.to_str().expect("Temporary path is not UTF8");
+    let params = TD1::merkle::Params {
+        chunk_size: 1000,
+        fanout: 3,
+    };
+    let options = ["--chunk-size", "1000", "--fanout", "3"];
+
+    let root = Command::new("./target/debug/shake128")
+        .args(["tree", "root"])
+        .args(options)
+        .args(["--format", "hex", file])
+        .output()
+        .expect("Could not execute shake128");
+    let root = String::from_utf8(root.stdout).expect("Command output is not a valid UTF8 string");
+    let expected = TD1::merkle::root(&data[..], &params).unwrap();
+    assert_eq!(
+        root.trim(),
+        TD1::hex::encode(&expected, TD1::hex::Format::LOWER)
+    );
+
+    let proof = Command::new("./target/debug/shake128")
+        .args(["tree", "prove"])
+        .args(options)
+        .args([file, "7"])
+        .output()
+        .expect("Could not execute shake128");
+    assert_eq!(proof.status.code(), Some(0));
+    let proof = String::from_utf8(proof.stdout).expect("Command output is not a valid UTF8 string");
+    let parsed = proof.parse::<TD1::merkle::Proof>().unwrap();
+    assert!(parsed.verify(&params, 7, &data[7000..8000], &expected));
+
+    let proof_file = format!("{file}.proof");
+    std::fs::write(&proof_file, proof).expect("Could not write the proof");
+    let verify = |index: &str, fanout: &str, chunk: &[u8]| {
+        let mut shake128 = Command::new("./target/debug/shake128")
+            .args(["tree", "verify", "--chunk-size", "1000", "--fanout", fanout])
+            .args(["--index", index, "--root", root.trim(), &proof_file])
+            .stdin(Stdio::piped())
+            .spawn()
+            .expect("Could not execute shake128");
+        shake128
+            .stdin
+            .take()
+            .expect("Could not access stdin")
+            .write_all(chunk)
+            .expect("Failed to write to shake128's stdin");
+        shake128.wait().expect("shake128 did not end").code()
+    };
+    assert_eq!(verify("7", "3", &data[7000..8000]), Some(0));
+    assert_eq!(verify("7", "3", &data[6000..7000]), Some(1));
+    // the index and the shape of the tree are not taken from the proof
+    assert_eq!(verify("6", "3", &data[7000..8000]), Some(1));
+    assert_eq!(verify("7", "2", &data[7000..8000]), Some(1));
+    assert_eq!(verify("7", "3", &data[7000..7999]), Some(1));
+
+    let out_of_range = Command::new("./target/debug/shake128")
+        .args(["tree", "prove"])
+        .args(options)
+        .args([file, "10"])
+        .output()
+        .expect("Could not execute shake128");
+    assert_eq!(out_of_range.status.code(), Some(2));
+    std::fs::remove_file(file).ok();
+    std::fs::remove_file(proof_file).ok();
+}
+
+#[test]
+fn store() {
+    Command::new("cargo")
+        .arg("build")
+        .status()
+        .expect("Could not build shake128");
+
+    let directory = std::env::temp_dir().join(format!("shake128-store-cli-{}", std::process::id()));
+    let directory = directory.to_str().expect("Temporary path is not UTF8");
+    let run = |args: &[&str], input: &[u8]| {
+        let mut shake128 = Command::new("./target/debug/shake128")
+            .args(["store", "--dir", directory])
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .expect("Could not execute shake128");
+        shake128
+            .stdin
+            .take()
+            .expect("Could not access stdin")
+            .write_all(input)
+            .expect("Failed to write to shake128's stdin");
+        shake128.wait_with_output().expect("shake128 did not end")
+    };
+
+    let file = (0..200_000u32)
+        .map(|i| (i.wrapping_mul(2_654_435_761) >> 13) as u8)
+        .collect::<Vec<_>>();
+    let put = run(&["put"], &file);
+    assert_eq!(put.status.code(), Some(0));
+    let manifest =
+        String::from_utf8(put.stdout).expect("Command output is not a valid UTF8 string");
+    let manifest = manifest.trim();
+    assert_eq!(manifest.len(), 64);
+
+    let get = run(&["get", manifest], b"");
+    assert_eq!(get.status.code(), Some(0));
+    assert_eq!(get.stdout, file);
+    assert_eq!(run(&["verify"], b"").status.code(), Some(0));
+
+    assert_eq!(run(&["forget", manifest], b"").status.code(), Some(0));
+    let gc = run(&["gc"], b"");
+    assert!(String::from_utf8(gc.stdout)
+        .expect("Command output is not a valid UTF8 string")
+        .ends_with("(200000 bytes)\n"));
+    assert_eq!(run(&["get", manifest], b"").status.code(), Some(3));
+    assert_eq!(run(&["get", "xyz"], b"").status.code(), Some(3));
+    assert_eq!(run(&["list"], b"").status.code(), Some(2));
+    std::fs::remove_dir_all(directory).ok();
+}
+
+#[test]
+fn dir() {
+    Command::new("cargo")
+        .arg("build")
+        .status()
+        .expect("Could not build shake128");
+
+    let run = |args: &[&str]| {
+        let output = Command::new("./target/debug/shake128")
+            .arg("dir")
+            .args(args)
+            .output()
+            .expect("Could not execute shake128");
+        assert_eq!(output.status.code(), Some(0));
+        String::from_utf8(output.stdout).expect("Command output is not a valid UTF8 string")
+    };
+    let options = TD1::dir::Options {
+        ignore: vec!["kat".to_string()],
+        ..TD1::dir::Options::default()
+    };
+    let expected = TD1::dir::hash_path("tests".as_ref(), &options).unwrap();
+    assert_eq!(
+        run(&["--ignore", "kat", "--format", "hex", "tests"]).trim(),
+        TD1::hex::encode(&expected, TD1::hex::Format::LOWER)
+    );
+    assert_ne!(run(&["tests"]), run(&["--ignore", "kat", "tests"]));
+
+    let both = Command::new("./target/debug/shake128")
+        .args(["dir", "--follow-symlinks", "--skip-symlinks", "tests"])
+        .output()
+        .expect("Could not execute shake128");
+    assert_eq!(both.status.code(), Some(2));
+}
+
+#[test]
+fn files() {
+    Command::new("cargo")
+        .arg("build")
+        .status()
+        .expect("Could not build shake128");
+
+    let files = [
+        "Cargo.toml",
+        "tests/short-text.txt",
+        "README.md",
+        "Cargo.toml",
+    ];
+    let expected = files
+        .iter()
+        .map(|file| {
+            let hash = TD1::parallel::hash_file(file.as_ref(), 128).unwrap();
+            format!(
+                "{}  {file}\n",
+                TD1::hex::encode(&hash, TD1::hex::Format::UPPER)
+            )
+        })
+        .collect::<String>();
+    for jobs in ["1", "3"] {
+        let output = Command::new("./target/debug/shake128")
+            .args(["-j", jobs, "--progress", "16"])
+            .args(files)
+            .output()
+            .expect("Could not execute shake128");
+        assert_eq!(output.status.code(), Some(0));
+        assert_eq!(
+            String::from_utf8(output.stdout).expect("Command output is not a valid UTF8 string"),
+            expected
+        );
+        assert!(String::from_utf8(output.stderr)
+            .expect("Command output is not a valid UTF8 string")
+            .ends_with("4/4 files hashed\n"));
+    }
+
+    let missing = Command::new("./target/debug/shake128")
+        .args(["16", "Cargo.toml", "missing-file"])
+        .output()
+        .expect("Could not execute shake128");
+    assert_eq!(missing.status.code(), Some(4));
+    assert_eq!(
+        String::from_utf8(missing.stdout).expect("Command output is not a valid UTF8 string"),
+        expected.split_inclusive('\n').next().unwrap()
+    );
+    let no_jobs = Command::new("./target/debug/shake128")
+        .args(["-j", "0", "16", "Cargo.toml"])
+        .output()
+        .expect("Could not execute shake128");
+    assert_eq!(no_jobs.status.code(), Some(2));
+}
+
+#[test]
+fn stdin_from_file() {
+    Command::new("cargo")
+        .arg("build")
+        .status()
+        .expect("Could not build shake128");
+
+    let redirected = Command::new("./target/debug/shake128")
+        .arg("64")
+        .stdin(File::open("README.md").expect("Could not open file"))
+        .output()
+        .expect("Could not execute shake128");
+    let mut hasher = TD1::sponge::Shake128::new();
+    hasher.update(&std::fs::read("README.md").expect("Could not read file"));
+    let expected = TD1::hex::encode(&hasher.finalize().squeeze_vec(64), TD1::hex::Format::UPPER);
+    assert_eq!(
+        String::from_utf8(redirected.stdout).expect("Command output is not a valid UTF8 string"),
+        expected + "\n"
+    );
+}
+
+#[test]
+fn bench() {
+    Command::new("cargo")
+        .arg("build")
+        .status()
+        .expect("Could not build shake128");
+
+    let output = Command::new("./target/debug/shake128")
+        .args(["bench", "--size", "1000", "--time", "0"])
+        .output()
+        .expect("Could not execute shake128");
+    assert_eq!(output.status.code(), Some(0));
+    let report =
+        String::from_utf8(output.stdout).expect("Command output is not a valid UTF8 string");
+    for algorithm in TD1::bench::ALGORITHMS {
+        assert!(report
+            .lines()
+            .any(|line| line.starts_with(algorithm.name) && line.contains(" MB/s")));
+    }
+
+    let negative = Command::new("./target/debug/shake128")
+        .args(["bench", "--time", "-1"])
+        .output()
+        .expect("Could not execute shake128");
+    assert_eq!(negative.status.code(), Some(2));
+}
@@ -0,0 +1,44 @@
+use TD1::kat::check_rsp;
+
+/// runs a response file of `tests/kat`, which must pass entirely
+fn check(rsp: &str, vectors: usize) {
+    let report = check_rsp(rsp).unwrap();
+    assert_eq!(report.failed, Vec::<String>::new());
+    assert_eq!(report.passed, vectors);
+}
+
+#[test]
+fn short_msg() {
+    check(include_str!("kat/SHAKE128ShortMsg.rsp"), 169);
+}
+
+#[test]
+fn long_msg() {
+    check(include_str!("kat/SHAKE128LongMsg.rsp"), 20);
+}
+
+#[test]
+fn variable_out() {
+    check(include_str!("kat/SHAKE128VariableOut.rsp"), 125);
+}
+
+#[test]
+fn monte() {
+    check(include_str!("kat/SHAKE128Monte.rsp"), 100);
+}
+
+/// the official response files, which are not part of the repository
+#[test]
+#[ignore = "needs the official CAVP response files in $SHAKE128_CAVP_DIR"]
+fn official() {
+    let directory = std::env::var_os("SHAKE128_CAVP_DIR")
+        .expect("SHAKE128_CAVP_DIR is the directory of the official response files");
+    for name in ["ShortMsg", "LongMsg", "VariableOut", "Monte"] {
+        let path = std::path::Path::new(&directory).join(format!("SHAKE128{name}.rsp"));
+        let rsp = std::fs::read_to_string(&path)
+            .unwrap_or_else(|error| panic!("{}: {error}", path.display()));
+        let report = check_rsp(&rsp).unwrap();
+        assert_eq!(report.failed, Vec::<String>::new(), "{name}");
+        assert!(report.passed > 0, "{name}");
+    }
+}